@@ -0,0 +1,44 @@
+//! SIMD/스칼라 Base64 인코딩 처리량 벤치마크입니다.
+//!
+//! ```bash
+//! $ cargo bench -p entlib-native-base64 --bench simd_bench
+//! $ cargo bench -p entlib-native-base64 --bench simd_bench --features force_scalar
+//! ```
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use entlib_native_base64::{Base64Encoder, Config, encode};
+use entlib_native_secure_buffer::SecureBuffer;
+use std::hint::black_box;
+
+const BACKEND: &str = if cfg!(feature = "force_scalar") {
+    "scalar"
+} else {
+    "simd"
+};
+
+fn bench_encode(c: &mut Criterion) {
+    let mut group = c.benchmark_group(format!("throughput.base64.encoding.{BACKEND}"));
+    for (label, size) in [("16KiB", 16 * 1024), ("1MiB", 1024 * 1024)] {
+        let mut input = SecureBuffer::new_owned(size).unwrap();
+        for (i, b) in input.as_mut_slice().iter_mut().enumerate() {
+            *b = (i as u8).wrapping_mul(167).wrapping_add(13);
+        }
+        group.throughput(Throughput::Bytes(size as u64));
+        group.bench_with_input(BenchmarkId::new("encode", label), &input, |b, input| {
+            b.iter(|| encode(black_box(input)).unwrap())
+        });
+        // 커널: 미리 할당한 출력 버퍼에 인코딩 (출력 SecureBuffer 할당/잠금/소거 비용 제외)
+        let mut output = SecureBuffer::new_owned(size.div_ceil(3) * 4).unwrap();
+        group.bench_with_input(BenchmarkId::new("kernel", label), &input, |b, input| {
+            b.iter(|| {
+                let out = output.as_mut_slice();
+                let mut encoder = Base64Encoder::new(Config::STANDARD);
+                let len = encoder.update(black_box(input.as_slice()), out);
+                black_box(len + encoder.finish(&mut out[len..]))
+            })
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode);
+criterion_main!(benches);
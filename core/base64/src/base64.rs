@@ -1,8 +1,11 @@
+use crate::config::Alphabet;
 use entlib_native_constant_time::traits::{
     ConstantTimeEq, ConstantTimeIsNegative, ConstantTimeSelect,
 };
 
-/// 단일 6비트 값을 상수-시간으로 Base64 문자(ASCII)로 인코딩합니다.
+/// 단일 6비트 값을 상수-시간으로 표준 알파벳 Base64 문자(ASCII)로 인코딩합니다.
+///
+/// [`ct_bin_to_b64_u8_with`]에 [`Alphabet::Standard`]를 지정한 것과 같습니다.
 ///
 /// # Arguments
 /// `c`는 반드시 `0..=63` 범위의 값이어야 합니다.
@@ -13,24 +16,36 @@ use entlib_native_constant_time::traits::{
 /// - `ct_select`: CPU 분기 없는 조건부 값 선택
 #[inline(always)]
 pub fn ct_bin_to_b64_u8(c: u8) -> u8 {
+    ct_bin_to_b64_u8_with(c, Alphabet::Standard)
+}
+
+/// 단일 6비트 값을 상수-시간으로 지정한 알파벳의 Base64 문자(ASCII)로 인코딩합니다.
+///
+/// # Arguments
+/// - `c`는 반드시 `0..=63` 범위의 값이어야 합니다.
+/// - `alphabet`은 인덱스 `62`, `63`의 문자를 결정하는 공개 설정입니다.
+#[inline(always)]
+pub fn ct_bin_to_b64_u8_with(c: u8, alphabet: Alphabet) -> u8 {
     use entlib_native_constant_time::choice::Choice;
 
+    let (sym62, sym63) = alphabet.symbols();
+
     // c < 26 이면 대문자 영역 (MSB 기반 언더플로우 감지)
     let mask_upper: Choice = c.wrapping_sub(26).ct_is_negative();
     // 26 <= c < 52 이면 소문자 영역
     let mask_lower: Choice = c.wrapping_sub(52).ct_is_negative() & !mask_upper;
     // 52 <= c < 62 이면 숫자 영역
     let mask_digit: Choice = c.wrapping_sub(62).ct_is_negative() & !mask_lower & !mask_upper;
-    // c == 62 이면 '+'
+    // c == 62 이면 '+' (URL 안전: '-')
     let mask_plus: Choice = c.ct_eq(&62u8);
-    // c == 63 이면 '/'
+    // c == 63 이면 '/' (URL 안전: '_')
     let mask_slash: Choice = c.ct_eq(&63u8);
 
     let v_upper = c.wrapping_add(65); // c + 'A' (0..=25 → 'A'..='Z')
     let v_lower = c.wrapping_add(71); // c - 26 + 'a' (26..=51 → 'a'..='z')
     let v_digit = c.wrapping_sub(4); // c - 52 + '0' (52..=61 → '0'..='9')
-    let v_plus = b'+';
-    let v_slash = b'/';
+    let v_plus = sym62;
+    let v_slash = sym63;
 
     // 후순위 마스크가 낮은 것부터 적용: 최종 유효 마스크가 res를 덮어씁니다.
     let mut res = 0u8;
@@ -43,7 +58,9 @@ pub fn ct_bin_to_b64_u8(c: u8) -> u8 {
     res
 }
 
-/// 단일 Base64 문자(ASCII)를 상수-시간으로 6비트 값으로 디코딩합니다.
+/// 단일 표준 알파벳 Base64 문자(ASCII)를 상수-시간으로 6비트 값으로 디코딩합니다.
+///
+/// [`ct_b64_to_bin_u8_with`]에 [`Alphabet::Standard`]를 지정한 것과 같습니다.
 ///
 /// # Returns
 /// | 반환값      | 의미                         |
@@ -57,8 +74,19 @@ pub fn ct_bin_to_b64_u8(c: u8) -> u8 {
 /// - 모든 경로에서 동일한 수의 연산을 수행하여 타이밍 공격을 방어합니다.
 #[inline(always)]
 pub fn ct_b64_to_bin_u8(b: u8) -> u8 {
+    ct_b64_to_bin_u8_with(b, Alphabet::Standard)
+}
+
+/// 단일 Base64 문자(ASCII)를 상수-시간으로 지정한 알파벳의 6비트 값으로 디코딩합니다.
+///
+/// 반환값의 의미는 [`ct_b64_to_bin_u8`]과 같으며, 다른 알파벳의 `62`, `63` 문자(예: 표준
+/// 알파벳에서의 `'-'`, `'_'`)는 유효하지 않은 문자(`0xFF`)로 취급됩니다.
+#[inline(always)]
+pub fn ct_b64_to_bin_u8_with(b: u8, alphabet: Alphabet) -> u8 {
     use entlib_native_constant_time::choice::Choice;
 
+    let (sym62, sym63) = alphabet.symbols();
+
     // 각 문자 범위에 대한 상수-시간 마스크 생성
     // 범위 [lo, hi): !b.wrapping_sub(lo).ct_is_negative() & b.wrapping_sub(hi).ct_is_negative()
     // = b >= lo AND b < hi
@@ -68,8 +96,8 @@ pub fn ct_b64_to_bin_u8(b: u8) -> u8 {
         & b.wrapping_sub(123).ct_is_negative(); // b <  '{'
     let mask_digit: Choice = !b.wrapping_sub(48).ct_is_negative()   // b >= '0'
         & b.wrapping_sub(58).ct_is_negative(); // b <  ':'
-    let mask_plus: Choice = b.ct_eq(&b'+');
-    let mask_slash: Choice = b.ct_eq(&b'/');
+    let mask_plus: Choice = b.ct_eq(&sym62);
+    let mask_slash: Choice = b.ct_eq(&sym63);
     let mask_pad: Choice = b.ct_eq(&b'=');
     let mask_ws: Choice = b.ct_eq(&b' ') | b.ct_eq(&b'\t') | b.ct_eq(&b'\r') | b.ct_eq(&b'\n');
 
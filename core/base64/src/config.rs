@@ -0,0 +1,147 @@
+/// Base64 알파벳입니다.
+///
+/// 두 알파벳은 인덱스 `62`, `63`에 대응하는 문자만 다르며, 나머지 문자(`A-Z`, `a-z`, `0-9`)는
+/// 동일합니다. 알파벳 선택은 공개 정보이므로 상수-시간 매퍼 내부에서 비밀 데이터와 무관하게
+/// 고정된 비교 대상 문자로만 사용됩니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Alphabet {
+    /// RFC 4648 4장 표준 알파벳 (`+`, `/`)
+    Standard,
+    /// RFC 4648 5장 URL 및 파일명 안전 알파벳 (`-`, `_`)
+    UrlSafe,
+}
+
+impl Alphabet {
+    /// 인덱스 `62`, `63`에 대응하는 문자를 반환합니다.
+    #[inline(always)]
+    pub const fn symbols(self) -> (u8, u8) {
+        match self {
+            Alphabet::Standard => (b'+', b'/'),
+            Alphabet::UrlSafe => (b'-', b'_'),
+        }
+    }
+}
+
+/// 줄바꿈 출력 시 사용할 줄 끝 문자열입니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\r\n` (RFC 2045 MIME)
+    CrLf,
+    /// `\n` (RFC 7468 PEM의 일반적인 생성 형식)
+    Lf,
+}
+
+impl LineEnding {
+    /// 줄 끝 문자열의 바이트 표현을 반환합니다.
+    #[inline(always)]
+    pub const fn as_bytes(self) -> &'static [u8] {
+        match self {
+            LineEnding::CrLf => b"\r\n",
+            LineEnding::Lf => b"\n",
+        }
+    }
+}
+
+/// Base64 인/디코딩 설정입니다.
+///
+/// 알파벳, 패딩 여부, 줄바꿈(열 너비 및 줄 끝 문자열)을 지정합니다. 기본값([`Config::STANDARD`])은
+/// 표준 알파벳, 필수 패딩, 줄바꿈 없음이며 디코딩 시 엄격한 정규(canonical) 형식만 허용합니다.
+///
+/// # Decoding
+/// - 패딩 사용: 입력 길이는 4의 배수여야 하며, `'='`는 마지막 그룹의 끝 1~2자리에만 허용됩니다.
+/// - 패딩 미사용: `'='`를 허용하지 않으며, 길이를 4로 나눈 나머지가 1인 입력은 거부됩니다.
+/// - 줄바꿈 사용: 공백 문자(`' '`, `'\t'`, `'\r'`, `'\n'`)를 위치와 관계없이 무시합니다.
+///   줄바꿈이 없는 설정에서는 공백 문자도 유효하지 않은 문자로 취급됩니다.
+/// - 모든 설정에서 마지막 문자의 사용되지 않는 하위 비트가 0이 아닌 입력(예: `"Zh=="`)은 거부됩니다.
+///
+/// # Usage
+/// ```
+/// use entlib_native_base64::{Config, decode_with, encode_with};
+/// use entlib_native_secure_buffer::SecureBuffer;
+///
+/// let mut input = SecureBuffer::new_owned(2).unwrap();
+/// input.as_mut_slice().copy_from_slice(&[0xFB, 0xFF]);
+///
+/// let encoded = encode_with(&input, Config::URL_SAFE_NO_PAD).unwrap();
+/// assert_eq!(encoded.as_slice(), b"-_8");
+///
+/// let decoded = decode_with(&encoded, Config::URL_SAFE_NO_PAD).unwrap();
+/// assert_eq!(decoded.as_slice(), &[0xFB, 0xFF]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    alphabet: Alphabet,
+    padding: bool,
+    line_width: usize,
+    line_ending: LineEnding,
+}
+
+impl Config {
+    /// RFC 4648 4장 표준 알파벳, 필수 패딩
+    pub const STANDARD: Config = Config::new(Alphabet::Standard);
+    /// RFC 4648 4장 표준 알파벳, 패딩 없음
+    pub const STANDARD_NO_PAD: Config = Config::STANDARD.with_padding(false);
+    /// RFC 4648 5장 URL 안전 알파벳, 필수 패딩
+    pub const URL_SAFE: Config = Config::new(Alphabet::UrlSafe);
+    /// RFC 4648 5장 URL 안전 알파벳, 패딩 없음 (JOSE RFC 7515, COSE RFC 9052)
+    pub const URL_SAFE_NO_PAD: Config = Config::URL_SAFE.with_padding(false);
+    /// RFC 2045 MIME: 표준 알파벳, 필수 패딩, 76열 `\r\n` 줄바꿈
+    pub const MIME: Config = Config::STANDARD.with_line_wrap(76, LineEnding::CrLf);
+    /// RFC 7468 PEM: 표준 알파벳, 필수 패딩, 64열 `\n` 줄바꿈
+    pub const PEM: Config = Config::STANDARD.with_line_wrap(64, LineEnding::Lf);
+
+    /// 지정한 알파벳으로 필수 패딩, 줄바꿈 없음 설정을 생성합니다.
+    pub const fn new(alphabet: Alphabet) -> Self {
+        Self {
+            alphabet,
+            padding: true,
+            line_width: 0,
+            line_ending: LineEnding::CrLf,
+        }
+    }
+
+    /// 패딩 사용 여부를 지정합니다.
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    /// 인코딩 출력을 `width`열마다 줄바꿈하고, 디코딩 시 공백 문자를 무시하도록 지정합니다.
+    ///
+    /// `width`가 0이면 줄바꿈을 사용하지 않습니다.
+    pub const fn with_line_wrap(mut self, width: usize, ending: LineEnding) -> Self {
+        self.line_width = width;
+        self.line_ending = ending;
+        self
+    }
+
+    /// 알파벳을 반환합니다.
+    pub const fn alphabet(&self) -> Alphabet {
+        self.alphabet
+    }
+
+    /// 패딩 사용 여부를 반환합니다.
+    pub const fn padding(&self) -> bool {
+        self.padding
+    }
+
+    /// 줄바꿈 열 너비를 반환합니다. 줄바꿈을 사용하지 않으면 `None`입니다.
+    pub const fn line_width(&self) -> Option<usize> {
+        if self.line_width == 0 {
+            None
+        } else {
+            Some(self.line_width)
+        }
+    }
+
+    /// 줄 끝 문자열을 반환합니다.
+    pub const fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config::STANDARD
+    }
+}
@@ -1,7 +1,17 @@
 pub mod base64;
+mod config;
+mod simd;
+mod stream;
 
-use base64::{ct_b64_to_bin_u8, ct_bin_to_b64_u8};
+pub use config::{Alphabet, Config, LineEnding};
+pub use stream::{Base64Decoder, Base64Encoder};
+
+use base64::{ct_b64_to_bin_u8_with, ct_bin_to_b64_u8_with};
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::option::CtOption;
+use entlib_native_constant_time::traits::ConstantTimeEq;
 use entlib_native_secure_buffer::SecureBuffer;
+use simd::encode_simd;
 
 /// RFC 4648 표준 Base64 인코딩 함수입니다.
 ///
@@ -14,6 +24,8 @@ use entlib_native_secure_buffer::SecureBuffer;
 /// # Security Note
 /// - 내부적으로 상수-시간 룩업(`ct_bin_to_b64_u8`)을 사용하여 비밀 데이터의 내용에
 ///   의존적인 타이밍 변동을 제거합니다.
+/// - x86_64(AVX2, 런타임 감지)와 aarch64(NEON)에서는 동일한 범위 비교 산술을 벡터 레인 단위로
+///   수행하는 SIMD 경로가 사용되며, 분기와 메모리 룩업이 없는 성질은 그대로 유지됩니다.
 /// - 반환 버퍼는 OS의 메모리 락 시스템 콜을 통해 잠겨 디스크 스왑 유출이 방지됩니다.
 /// - 외부 크레이트 의존성 없이 순수 Rust로 구현되어 Air-Gapped 환경에서 작동합니다.
 ///
@@ -32,44 +44,134 @@ use entlib_native_secure_buffer::SecureBuffer;
 /// // input, encoded 모두 여기서 Drop되면서 내용이 자동 소거됨
 /// ```
 pub fn encode(input: &SecureBuffer) -> Result<SecureBuffer, &'static str> {
+    encode_with(input, Config::STANDARD)
+}
+
+/// 지정한 [Config]에 따라 Base64 인코딩하는 함수입니다.
+///
+/// 알파벳, 패딩 여부, 줄바꿈은 모두 공개 설정이며, 출력 길이와 줄바꿈 위치는 입력 길이에서만
+/// 결정됩니다. 줄바꿈은 마지막 줄 뒤에는 추가되지 않습니다.
+///
+/// # Arguments
+/// - `input` 인코딩할 데이터를 담은 [SecureBuffer]
+/// - `config` 인코딩 설정
+///
+/// # Panic
+/// OS 메모리 잠금(`mlock`) 실패 또는 메모리 부족 시 `Err(&'static str)`.
+///
+/// # Usage
+/// ```
+/// use entlib_native_base64::{Config, encode_with};
+/// use entlib_native_secure_buffer::SecureBuffer;
+///
+/// let mut input = SecureBuffer::new_owned(60).unwrap();
+/// input.as_mut_slice().fill(0);
+/// let encoded = encode_with(&input, Config::MIME).unwrap();
+/// assert_eq!(&encoded.as_slice()[76..78], b"\r\n");
+/// assert_eq!(encoded.len(), 80 + 2);
+/// ```
+pub fn encode_with(input: &SecureBuffer, config: Config) -> Result<SecureBuffer, &'static str> {
     let input = input.as_slice();
+    let char_len = encoded_char_len(input.len(), config.padding());
 
-    let full_groups = input.len() / 3;
-    let remaining = input.len() % 3;
-    let out_groups = full_groups + if remaining > 0 { 1 } else { 0 };
-    let output_size = out_groups * 4;
+    match config.line_width() {
+        None => {
+            // OS mlock으로 잠긴 페이지 정렬 메모리 할당 (Drop 시 Zeroize)
+            let mut buf = SecureBuffer::new_owned(char_len)?;
+            encode_chars(input, buf.as_mut_slice(), config);
+            Ok(buf)
+        }
+        Some(width) => {
+            // 줄바꿈 위치는 입력 길이(공개 정보)에서만 결정되므로 일반 분기와 복사를 사용합니다.
+            // 중간 버퍼도 SecureBuffer이므로 스코프를 벗어나면 소거됩니다.
+            let mut chars = SecureBuffer::new_owned(char_len)?;
+            encode_chars(input, chars.as_mut_slice(), config);
 
-    // OS mlock으로 잠긴 페이지 정렬 메모리 할당 (Drop 시 Zeroize)
-    let mut buf = SecureBuffer::new_owned(output_size)?;
-    let out = buf.as_mut_slice();
+            let ending = config.line_ending().as_bytes();
+            let breaks = char_len.saturating_sub(1) / width;
+            let mut buf = SecureBuffer::new_owned(char_len + breaks * ending.len())?;
+            let out = buf.as_mut_slice();
+            let mut pos = 0;
+            for (i, line) in chars.as_slice().chunks(width).enumerate() {
+                if i > 0 {
+                    out[pos..pos + ending.len()].copy_from_slice(ending);
+                    pos += ending.len();
+                }
+                out[pos..pos + line.len()].copy_from_slice(line);
+                pos += line.len();
+            }
+            Ok(buf)
+        }
+    }
+}
 
-    // 완전한 3바이트 그룹 처리
-    for i in 0..full_groups {
-        let (b0, b1, b2) = (input[i * 3], input[i * 3 + 1], input[i * 3 + 2]);
-        out[i * 4] = ct_bin_to_b64_u8(b0 >> 2);
-        out[i * 4 + 1] = ct_bin_to_b64_u8((b0 & 0x03) << 4 | b1 >> 4);
-        out[i * 4 + 2] = ct_bin_to_b64_u8((b1 & 0x0F) << 2 | b2 >> 6);
-        out[i * 4 + 3] = ct_bin_to_b64_u8(b2 & 0x3F);
+/// 줄바꿈을 제외한 인코딩 문자 수를 계산합니다.
+#[inline(always)]
+fn encoded_char_len(input_len: usize, padding: bool) -> usize {
+    let full_groups = input_len / 3;
+    match (input_len % 3, padding) {
+        (0, _) => full_groups * 4,
+        (_, true) => full_groups * 4 + 4,
+        (remaining, false) => full_groups * 4 + remaining + 1,
     }
+}
+
+/// 입력 전체를 `out`(길이 `encoded_char_len`)에 줄바꿈 없이 인코딩합니다.
+fn encode_chars(input: &[u8], out: &mut [u8], config: Config) {
+    let full_len = input.len() / 3 * 3;
+    encode_full_groups(&input[..full_len], out, config.alphabet());
+    encode_tail(&input[full_len..], &mut out[full_len / 3 * 4..], config);
+}
 
-    // 나머지 바이트 처리 (패딩 추가)
-    let base = full_groups * 4;
-    let src = full_groups * 3;
-    if remaining == 1 {
-        let b0 = input[src];
-        out[base] = ct_bin_to_b64_u8(b0 >> 2);
-        out[base + 1] = ct_bin_to_b64_u8((b0 & 0x03) << 4);
-        out[base + 2] = b'=';
-        out[base + 3] = b'=';
-    } else if remaining == 2 {
-        let (b0, b1) = (input[src], input[src + 1]);
-        out[base] = ct_bin_to_b64_u8(b0 >> 2);
-        out[base + 1] = ct_bin_to_b64_u8((b0 & 0x03) << 4 | b1 >> 4);
-        out[base + 2] = ct_bin_to_b64_u8((b1 & 0x0F) << 2);
-        out[base + 3] = b'=';
+/// 완전한 3바이트 그룹들을 인코딩합니다.
+///
+/// SIMD 경로(AVX2/NEON)가 블록 단위로 처리한 접두부 이후의 나머지 그룹만 스칼라 참조 경로로
+/// 처리합니다. `input.len()`은 3의 배수여야 합니다.
+#[inline]
+pub(crate) fn encode_full_groups(input: &[u8], out: &mut [u8], alphabet: Alphabet) {
+    let done = encode_simd(input, out, alphabet);
+    encode_groups_scalar_ct(&input[done..], &mut out[done / 3 * 4..], alphabet);
+}
+
+/// 마지막 불완전 그룹(0~2바이트)을 설정에 따라 패딩을 추가하여 인코딩하고, 기록한 문자 수를
+/// 반환합니다. 나머지 길이와 패딩 여부는 공개 정보이므로 분기를 허용합니다.
+#[inline]
+pub(crate) fn encode_tail(tail: &[u8], out: &mut [u8], config: Config) -> usize {
+    let map = |v: u8| ct_bin_to_b64_u8_with(v, config.alphabet());
+    let chars = match *tail {
+        [] => return 0,
+        [b0] => {
+            out[0] = map(b0 >> 2);
+            out[1] = map((b0 & 0x03) << 4);
+            2
+        }
+        [b0, b1] => {
+            out[0] = map(b0 >> 2);
+            out[1] = map((b0 & 0x03) << 4 | b1 >> 4);
+            out[2] = map((b1 & 0x0F) << 2);
+            3
+        }
+        _ => unreachable!("tail must be shorter than a group"),
+    };
+    if !config.padding() {
+        return chars;
     }
+    out[chars..4].fill(b'=');
+    4
+}
 
-    Ok(buf)
+/// 완전한 3바이트 그룹을 스칼라 `ct_bin_to_b64_u8_with`로 인코딩하는 참조 구현입니다.
+///
+/// SIMD 경로의 나머지 그룹 처리와 `force_scalar` 기능에서 사용됩니다.
+#[inline(always)]
+fn encode_groups_scalar_ct(input: &[u8], out: &mut [u8], alphabet: Alphabet) {
+    for (i, group) in input.chunks_exact(3).enumerate() {
+        let (b0, b1, b2) = (group[0], group[1], group[2]);
+        out[i * 4] = ct_bin_to_b64_u8_with(b0 >> 2, alphabet);
+        out[i * 4 + 1] = ct_bin_to_b64_u8_with((b0 & 0x03) << 4 | b1 >> 4, alphabet);
+        out[i * 4 + 2] = ct_bin_to_b64_u8_with((b1 & 0x0F) << 2 | b2 >> 6, alphabet);
+        out[i * 4 + 3] = ct_bin_to_b64_u8_with(b2 & 0x3F, alphabet);
+    }
 }
 
 /// RFC 4648 표준 Base64 디코딩 함수입니다.
@@ -107,91 +209,159 @@ pub fn encode(input: &SecureBuffer) -> Result<SecureBuffer, &'static str> {
 /// assert!(decode(&invalid).is_err());
 /// ```
 pub fn decode(input: &SecureBuffer) -> Result<SecureBuffer, &'static str> {
-    let input = input.as_slice();
+    decode_with(input, Config::STANDARD)
+}
 
-    if !input.len().is_multiple_of(4) {
-        return Err("invalid base64: length must be a multiple of 4");
+/// 지정한 [Config]에 따라 Base64 디코딩하는 함수입니다.
+///
+/// 허용되는 입력 형식은 [Config] 문서의 `Decoding` 절을 참고하십시오. 줄바꿈 설정에서 무시되는
+/// 공백 문자의 위치는 형식 정보(공개)로 취급되며, 그 외 모든 문자의 유효성 검사는
+/// [`decode`]와 동일하게 상수-시간으로 누산되어 `CtOption::into_option`에서만 공개됩니다.
+///
+/// # Arguments
+/// - `input` 디코딩할 Base64 문자열을 담은 [SecureBuffer]
+/// - `config` 디코딩 설정
+///
+/// # Panic
+/// - 잘못된 형식(길이, 패딩, 유효하지 않은 문자): `Err("invalid base64: ...")`
+/// - OS 메모리 잠금 실패 또는 메모리 부족: `Err("...")`
+///
+/// # Usage
+/// ```
+/// use entlib_native_base64::{Config, decode_with};
+/// use entlib_native_secure_buffer::SecureBuffer;
+///
+/// let mut input = SecureBuffer::new_owned(10).unwrap();
+/// input.as_mut_slice().copy_from_slice(b"TW\r\nFu\r\n\r\n");
+/// assert_eq!(decode_with(&input, Config::MIME).unwrap().as_slice(), b"Man");
+/// assert!(decode_with(&input, Config::STANDARD).is_err());
+/// ```
+pub fn decode_with(input: &SecureBuffer, config: Config) -> Result<SecureBuffer, &'static str> {
+    if config.line_width().is_none() {
+        return decode_chars(input.as_slice(), config);
     }
-    if input.is_empty() {
-        return SecureBuffer::new_owned(0);
+
+    // 공백 문자 제거: 공백 위치(줄 구조)는 공개 정보로 취급하며, 남은 문자는 SecureBuffer에
+    // 보관되어 스코프를 벗어나면 소거됩니다.
+    let input = input.as_slice();
+    let mut compact = SecureBuffer::new_owned(input.len())?;
+    let out = compact.as_mut_slice();
+    let mut len = 0;
+    for &b in input {
+        let is_ws = ct_b64_to_bin_u8_with(b, config.alphabet()).ct_eq(&0x80);
+        if !public_flag(is_ws) {
+            out[len] = b;
+            len += 1;
+        }
     }
+    decode_chars(&compact.as_slice()[..len], config)
+}
 
-    let num_groups = input.len() / 4;
+/// 공백 문자가 제거된 Base64 문자열을 디코딩합니다.
+fn decode_chars(input: &[u8], config: Config) -> Result<SecureBuffer, &'static str> {
+    let alphabet = config.alphabet();
 
-    // 마지막 그룹의 패딩 문자를 미리 확인하여 정확한 출력 크기 계산
-    // 패딩 위치는 입력으로부터 공개 정보이므로 분기 허용
-    let last = (num_groups - 1) * 4;
-    let pad3 = (input[last + 3] == b'=') as usize;
-    let pad2 = (input[last + 2] == b'=') as usize;
-    let last_group_bytes = 3usize.saturating_sub(pad2 + pad3);
-    let output_size = (num_groups - 1) * 3 + last_group_bytes;
+    // 유효 문자 수 계산: 길이와 패딩 위치는 입력으로부터 공개 정보이므로 분기 허용
+    // CT 유효성 누산기: 0x00 = 유효, 0이 아님 = 무효
+    // 전체 입력 처리 후에만 검사하여 타이밍 정보 유출 방지
+    let mut invalid: u8 = 0;
+    let data_len = if config.padding() {
+        if !input.len().is_multiple_of(4) {
+            return Err("invalid base64: length must be a multiple of 4");
+        }
+        if input.is_empty() {
+            return SecureBuffer::new_owned(0);
+        }
+        let (last_len, last_invalid) = padded_group_data_len(&input[input.len() - 4..]);
+        invalid |= last_invalid;
+        input.len() - 4 + last_len
+    } else {
+        if input.len() % 4 == 1 {
+            return Err("invalid base64: truncated final group");
+        }
+        input.len()
+    };
+
+    let full_len = data_len / 4 * 4;
+    let output_size = full_len / 4 * 3 + (data_len - full_len).saturating_sub(1);
 
     // OS mlock으로 잠긴 페이지 정렬 메모리 할당 (Drop 시 Zeroize)
     let mut buf = SecureBuffer::new_owned(output_size)?;
     let out = buf.as_mut_slice();
-    let mut out_pos = 0usize;
 
-    // CT 유효성 누산기: 0x00 = 유효, 0이 아님 = 무효
-    // 전체 입력 처리 후에만 검사하여 타이밍 정보 유출 방지
-    let mut invalid: u8 = 0;
+    for (g, chars) in input[..full_len].chunks_exact(4).enumerate() {
+        invalid |= decode_group(chars, &mut out[g * 3..g * 3 + 3], alphabet);
+    }
+    invalid |= decode_tail(
+        &input[full_len..data_len],
+        &mut out[full_len / 4 * 3..],
+        alphabet,
+    );
 
-    for g in 0..num_groups {
-        let idx = g * 4;
-        let d = [
-            ct_b64_to_bin_u8(input[idx]),
-            ct_b64_to_bin_u8(input[idx + 1]),
-            ct_b64_to_bin_u8(input[idx + 2]),
-            ct_b64_to_bin_u8(input[idx + 3]),
-        ];
-
-        // d[0], d[1]은 모든 그룹에서 반드시 유효한 Base64 값(0x00..=0x3F)이어야 함.
-        // 0x40 이상(0x80=공백, 0x81=패딩, 0xFF=무효)은 비트 6 또는 7이 설정됨.
-        invalid |= d[0] >> 6;
-        invalid |= d[1] >> 6;
-
-        if g < num_groups - 1 {
-            // 비-마지막 그룹: d[2], d[3]도 반드시 유효
-            invalid |= d[2] >> 6;
-            invalid |= d[3] >> 6;
-            out[out_pos] = (d[0] << 2) | (d[1] >> 4);
-            out[out_pos + 1] = (d[1] << 4) | (d[2] >> 2);
-            out[out_pos + 2] = (d[2] << 6) | d[3];
-            out_pos += 3;
-        } else {
-            // 마지막 그룹: 패딩 처리 (0x81 = '=', 0..=63 = 유효)
-            if out_pos < out.len() {
-                out[out_pos] = (d[0] << 2) | (d[1] >> 4);
-                out_pos += 1;
-            }
-            if d[2] == 0x81 {
-                // '==' 패딩: d[3]도 반드시 '='
-                if d[3] != 0x81 {
-                    invalid |= 1;
-                }
-            } else if d[2] < 64 {
-                if out_pos < out.len() {
-                    out[out_pos] = (d[1] << 4) | (d[2] >> 2);
-                    out_pos += 1;
-                }
-                if d[3] == 0x81 {
-                    // '=' 패딩 1개: 종료
-                } else if d[3] < 64 {
-                    if out_pos < out.len() {
-                        out[out_pos] = (d[2] << 6) | d[3];
-                    }
-                } else {
-                    invalid |= 1; // d[3] 무효
-                }
-            } else {
-                invalid |= 1; // d[2] 무효
-            }
+    // 유효 여부는 CtOption::into_option에서만 공개되며, 실패 시 buf는 Drop되어 자동 소거됨
+    CtOption::new(buf, invalid.ct_eq(&0))
+        .into_option()
+        .ok_or("invalid base64: illegal character or padding")
+}
+
+/// 패딩이 포함될 수 있는 마지막 4문자 그룹에서 데이터 문자 수(2~4)와 형식 오류 플래그를
+/// 계산합니다. 패딩 위치는 설계상 공개 정보입니다.
+#[inline]
+pub(crate) fn padded_group_data_len(group: &[u8]) -> (usize, u8) {
+    let pad3 = public_flag(group[3].ct_eq(&b'='));
+    let pad2 = public_flag(group[2].ct_eq(&b'='));
+    // '='가 세 번째 자리에만 있는 경우("xx=y")는 형식 오류
+    let invalid = (pad2 & !pad3) as u8;
+    (4 - pad3 as usize - (pad2 & pad3) as usize, invalid)
+}
+
+/// 완전한 4문자 그룹을 3바이트로 디코딩하고, 무효 플래그(0 = 유효)를 반환합니다.
+#[inline(always)]
+pub(crate) fn decode_group(chars: &[u8], out: &mut [u8], alphabet: Alphabet) -> u8 {
+    let map = |b: u8| ct_b64_to_bin_u8_with(b, alphabet);
+    let d = [map(chars[0]), map(chars[1]), map(chars[2]), map(chars[3])];
+
+    out[0] = (d[0] << 2) | (d[1] >> 4);
+    out[1] = (d[1] << 4) | (d[2] >> 2);
+    out[2] = (d[2] << 6) | d[3];
+
+    // 모든 데이터 문자는 유효한 Base64 값(0x00..=0x3F)이어야 함.
+    // 0x40 이상(0x80=공백, 0x81=패딩, 0xFF=무효)은 비트 6 또는 7이 설정됨.
+    (d[0] | d[1] | d[2] | d[3]) >> 6
+}
+
+/// 마지막 불완전 그룹(0, 2 또는 3문자)을 디코딩하고, 무효 플래그(0 = 유효)를 반환합니다.
+///
+/// 분기는 공개된 나머지 길이에만 의존합니다. 정규 형식 검사로 출력에 사용되지 않는 마지막
+/// 문자의 하위 비트는 0이어야 합니다.
+#[inline]
+pub(crate) fn decode_tail(chars: &[u8], out: &mut [u8], alphabet: Alphabet) -> u8 {
+    let map = |b: u8| ct_b64_to_bin_u8_with(b, alphabet);
+    match *chars {
+        [] => 0,
+        [c0, c1] => {
+            let d = [map(c0), map(c1)];
+            out[0] = (d[0] << 2) | (d[1] >> 4);
+            ((d[0] | d[1]) >> 6) | (d[1] & 0x0F)
         }
+        [c0, c1, c2] => {
+            let d = [map(c0), map(c1), map(c2)];
+            out[0] = (d[0] << 2) | (d[1] >> 4);
+            out[1] = (d[1] << 4) | (d[2] >> 2);
+            ((d[0] | d[1] | d[2]) >> 6) | (d[2] & 0x03)
+        }
+        _ => unreachable!("tail must be 0, 2 or 3 characters"),
     }
+}
 
-    if invalid != 0 {
-        // buf는 여기서 Drop되며 중간값을 포함한 내용 자동 소거
-        Err("invalid base64: illegal character or padding")
-    } else {
-        Ok(buf)
-    }
+/// 설계상 공개되는 비밀 파생 플래그(패딩 위치)를 분기에 사용할 수 있는 `bool`로 변환합니다.
+///
+/// `valgrind_taint_audit` 기능이 활성화되면 오염 표시를 해제하여 Memcheck가 이 공개 지점을
+/// 오류로 보고하지 않도록 합니다.
+#[inline(always)]
+pub(crate) fn public_flag(flag: Choice) -> bool {
+    let mask = flag.unwrap_u8();
+    #[cfg(feature = "valgrind_taint_audit")]
+    let mask = entlib_native_test_support::taint::declassified(mask);
+    mask == 0xFF
 }
@@ -0,0 +1,200 @@
+//! 상수-시간 Base64 인코딩의 SIMD 구현입니다.
+//!
+//! x86_64에서는 AVX2 지원 여부를 런타임에 감지하고, aarch64에서는 NEON을 컴파일 타임에 사용합니다.
+//! 6비트 인덱스를 ASCII로 변환할 때 LUT나 `pshufb`/`tbl` 룩업 대신 범위 비교 마스크의 누적합
+//! (`ct_bin_to_b64_u8`과 동일한 범위 경계)을 사용하므로 비밀 데이터에 의존하는 분기나 룩업이
+//! 발생하지 않습니다. 분기는 입력 길이(공개 정보)와 CPU 기능 감지 결과에만 의존합니다.
+//!
+//! | 인덱스 범위 | 누적 오프셋                 | 결과         |
+//! |-------------|-----------------------------|--------------|
+//! | `0..=25`    | `+65`                       | `'A'..='Z'`  |
+//! | `26..=51`   | `+65 +6`                    | `'a'..='z'`  |
+//! | `52..=61`   | `+65 +6 -75`                | `'0'..='9'`  |
+//! | `62`        | `+65 +6 -75 +d62`           | `'+'`, `'-'` |
+//! | `63`        | `+65 +6 -75 +d62 +d63`      | `'/'`, `'_'` |
+//!
+//! `d62`, `d63`은 [`Alphabet`](crate::Alphabet)에 따라 결정되는 공개 상수입니다
+//! (표준: `-15`, `+3`, URL 안전: `-13`, `+49`).
+
+use crate::config::Alphabet;
+
+/// 알파벳의 `62`, `63` 문자에 대응하는 누적 오프셋 `(d62, d63)`을 계산합니다.
+#[cfg(all(
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon")
+    ),
+    not(feature = "force_scalar")
+))]
+#[inline(always)]
+const fn symbol_offsets(alphabet: Alphabet) -> (u8, u8) {
+    let (sym62, sym63) = alphabet.symbols();
+    // 52..=61 구간의 누적 오프셋은 '0' - 52 = -4 입니다.
+    let d62 = sym62.wrapping_sub(62).wrapping_add(4);
+    let d63 = sym63.wrapping_sub(sym62).wrapping_sub(1);
+    (d62, d63)
+}
+
+/// 입력의 접두부를 SIMD 블록 단위로 인코딩하고, 처리한 입력 바이트 수(3의 배수)를 반환합니다.
+///
+/// 남은 완전한 그룹과 꼬리는 호출자가 스칼라 참조 경로로 처리해야 합니다.
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+#[inline]
+pub(crate) fn encode_simd(input: &[u8], output: &mut [u8], alphabet: Alphabet) -> usize {
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 지원 여부를 런타임에 확인하였습니다.
+        unsafe { avx2::encode(input, output, symbol_offsets(alphabet)) }
+    } else {
+        0
+    }
+}
+
+/// 입력의 접두부를 SIMD 블록 단위로 인코딩하고, 처리한 입력 바이트 수(3의 배수)를 반환합니다.
+///
+/// 남은 완전한 그룹과 꼬리는 호출자가 스칼라 참조 경로로 처리해야 합니다.
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "force_scalar")
+))]
+#[inline]
+pub(crate) fn encode_simd(input: &[u8], output: &mut [u8], alphabet: Alphabet) -> usize {
+    // SAFETY: NEON 지원 여부가 컴파일 타임(`target_feature = "neon"`)에 보장됩니다.
+    unsafe { neon::encode(input, output, symbol_offsets(alphabet)) }
+}
+
+/// SIMD 경로가 없는 환경(또는 `force_scalar` 기능)에서는 아무것도 처리하지 않습니다.
+#[cfg(not(all(
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon")
+    ),
+    not(feature = "force_scalar")
+)))]
+#[inline(always)]
+pub(crate) fn encode_simd(_input: &[u8], _output: &mut [u8], _alphabet: Alphabet) -> usize {
+    0
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    /// 24바이트 입력 블록을 32바이트 Base64 문자로 인코딩합니다.
+    ///
+    /// 각 128비트 레인에 16바이트를 적재하되 앞 12바이트만 사용하므로, 마지막 블록 이후에도
+    /// 4바이트를 읽을 수 있는 경우에만 블록을 처리합니다.
+    #[target_feature(enable = "avx2")]
+    pub(super) fn encode(input: &[u8], output: &mut [u8], offsets: (u8, u8)) -> usize {
+        debug_assert!(output.len() >= input.len() / 3 * 4);
+        let mut consumed = 0;
+
+        // 레인마다 3바이트 그룹을 [b1, b0, b2, b1] 순서의 32비트 워드로 재배치 (고정 인덱스)
+        #[rustfmt::skip]
+        let spread = _mm256_setr_epi8(
+            1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10,
+            1, 0, 2, 1, 4, 3, 5, 4, 7, 6, 8, 7, 10, 9, 11, 10,
+        );
+
+        while consumed + 28 <= input.len() {
+            // SAFETY: consumed + 28 <= input.len() 이므로 두 16바이트 적재 모두 범위 안입니다.
+            let v = unsafe {
+                let src = input.as_ptr().add(consumed);
+                _mm256_set_m128i(
+                    _mm_loadu_si128(src.add(12).cast()),
+                    _mm_loadu_si128(src.cast()),
+                )
+            };
+            let v = _mm256_shuffle_epi8(v, spread);
+
+            // 곱셈 기반 비트 추출: 각 32비트 워드에서 4개의 6비트 인덱스를 바이트별로 분리
+            let t0 = _mm256_and_si256(v, _mm256_set1_epi32(0x0FC0_FC00));
+            let t1 = _mm256_mulhi_epu16(t0, _mm256_set1_epi32(0x0400_0040));
+            let t2 = _mm256_and_si256(v, _mm256_set1_epi32(0x003F_03F0));
+            let t3 = _mm256_mullo_epi16(t2, _mm256_set1_epi32(0x0100_0010));
+            let ascii = index_to_ascii(_mm256_or_si256(t1, t3), offsets);
+
+            // SAFETY: consumed / 3 * 4 + 32 <= (consumed + 24) / 3 * 4 <= output.len()
+            unsafe {
+                _mm256_storeu_si256(output.as_mut_ptr().add(consumed / 3 * 4).cast(), ascii);
+            }
+            consumed += 24;
+        }
+
+        consumed
+    }
+
+    /// 0~63 인덱스 벡터를 범위 비교 마스크의 누적합으로 Base64 ASCII 벡터로 변환합니다.
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn index_to_ascii(idx: __m256i, (d62, d63): (u8, u8)) -> __m256i {
+        let offset = |bound: i8, delta: i8| {
+            _mm256_and_si256(
+                _mm256_cmpgt_epi8(idx, _mm256_set1_epi8(bound)),
+                _mm256_set1_epi8(delta),
+            )
+        };
+        let mut res = _mm256_add_epi8(idx, _mm256_set1_epi8(65));
+        res = _mm256_add_epi8(res, offset(25, 6));
+        res = _mm256_add_epi8(res, offset(51, -75));
+        res = _mm256_add_epi8(res, offset(61, d62 as i8));
+        _mm256_add_epi8(res, offset(62, d63 as i8))
+    }
+}
+
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "force_scalar")
+))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    /// 48바이트 입력 블록을 64바이트 Base64 문자로 인코딩합니다.
+    #[target_feature(enable = "neon")]
+    pub(super) fn encode(input: &[u8], output: &mut [u8], offsets: (u8, u8)) -> usize {
+        debug_assert!(output.len() >= input.len() / 3 * 4);
+        let blocks = input.len() / 48;
+
+        for i in 0..blocks {
+            // vld3q는 3바이트 그룹을 (b0, b1, b2) 세 벡터로 역교차 적재합니다.
+            // SAFETY: i * 48 + 48 <= input.len()
+            let uint8x16x3_t(b0, b1, b2) = unsafe { vld3q_u8(input.as_ptr().add(i * 48)) };
+
+            let i0 = vshrq_n_u8::<2>(b0);
+            let i1 = vorrq_u8(
+                vshlq_n_u8::<4>(vandq_u8(b0, vdupq_n_u8(0x03))),
+                vshrq_n_u8::<4>(b1),
+            );
+            let i2 = vorrq_u8(
+                vshlq_n_u8::<2>(vandq_u8(b1, vdupq_n_u8(0x0F))),
+                vshrq_n_u8::<6>(b2),
+            );
+            let i3 = vandq_u8(b2, vdupq_n_u8(0x3F));
+
+            let out = uint8x16x4_t(
+                index_to_ascii(i0, offsets),
+                index_to_ascii(i1, offsets),
+                index_to_ascii(i2, offsets),
+                index_to_ascii(i3, offsets),
+            );
+            // SAFETY: i * 64 + 64 <= output.len()
+            unsafe { vst4q_u8(output.as_mut_ptr().add(i * 64), out) };
+        }
+
+        blocks * 48
+    }
+
+    /// 0~63 인덱스 벡터를 범위 비교 마스크의 누적합으로 Base64 ASCII 벡터로 변환합니다.
+    #[inline]
+    #[target_feature(enable = "neon")]
+    fn index_to_ascii(idx: uint8x16_t, (d62, d63): (u8, u8)) -> uint8x16_t {
+        let offset =
+            |bound: u8, delta: u8| vandq_u8(vcgtq_u8(idx, vdupq_n_u8(bound)), vdupq_n_u8(delta));
+        let mut res = vaddq_u8(idx, vdupq_n_u8(65));
+        res = vaddq_u8(res, offset(25, 6));
+        res = vaddq_u8(res, offset(51, (-75i8) as u8));
+        res = vaddq_u8(res, offset(61, d62));
+        vaddq_u8(res, offset(62, d63))
+    }
+}
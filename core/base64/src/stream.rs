@@ -0,0 +1,332 @@
+//! 스트리밍(증분) Base64 인코더/디코더입니다.
+//!
+//! 전체 입력을 하나의 [`SecureBuffer`](entlib_native_secure_buffer::SecureBuffer)에 보관할 수
+//! 없는 대용량 데이터를 조각 단위로 처리합니다. 호출 사이에 남는 불완전 그룹만 내부 상태로
+//! 보관되며, 상태는 `Drop` 시점에 소거됩니다.
+//!
+//! 출력 버퍼는 호출자가 관리합니다. 비밀 데이터를 처리하는 경우 출력 버퍼 역시
+//! `SecureBuffer` 등 잠긴 메모리를 사용해야 합니다.
+
+use crate::base64::ct_b64_to_bin_u8_with;
+use crate::{
+    Config, decode_group, decode_tail, encode_full_groups, encode_tail, padded_group_data_len,
+    public_flag,
+};
+use entlib_native_constant_time::option::CtOption;
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_secure_buffer::{SecureZeroize, ZeroizeOnDrop};
+
+/// 줄바꿈 설정에서 한 번에 인코딩하는 입력 블록 크기 (64문자)
+const WRAP_CHUNK: usize = 48;
+
+/// 스트리밍 Base64 인코더입니다.
+///
+/// [`update`](Self::update)는 완성된 3바이트 그룹만 인코딩하고 남은 1~2바이트를 다음 호출로
+/// 이월합니다. [`finish`](Self::finish)는 마지막 불완전 그룹을 설정에 따라 패딩하여 출력합니다.
+/// 출력은 같은 [`Config`]의 [`encode_with`](crate::encode_with)와 바이트 단위로 동일합니다.
+///
+/// # Security Note
+/// - 문자 매핑은 [`encode_with`](crate::encode_with)와 동일한 상수-시간 경로(SIMD 포함)를 사용합니다.
+/// - 분기는 조각 길이, 이월 바이트 수, 줄바꿈 위치 등 입력 길이에서 결정되는 공개 정보에만
+///   의존합니다.
+///
+/// # Usage
+/// ```
+/// use entlib_native_base64::{Base64Encoder, Config};
+///
+/// let mut encoder = Base64Encoder::new(Config::STANDARD);
+/// let mut out = [0u8; 16];
+/// let mut len = encoder.update(b"foo", &mut out);
+/// len += encoder.update(b"ba", &mut out[len..]);
+/// len += encoder.finish(&mut out[len..]);
+/// assert_eq!(&out[..len], b"Zm9vYmE=");
+/// ```
+#[derive(SecureZeroize, ZeroizeOnDrop)]
+pub struct Base64Encoder {
+    pending: [u8; 3],
+    pending_len: usize,
+    column: usize,
+    #[zeroize(skip)]
+    config: Config,
+}
+
+impl Base64Encoder {
+    /// [`finish`](Self::finish)가 기록하는 최대 바이트 수 (4문자 + `"\r\n"`)
+    pub const MAX_FINISH_LEN: usize = 6;
+
+    /// 지정한 설정으로 인코더를 생성합니다.
+    pub fn new(config: Config) -> Self {
+        Self {
+            pending: [0; 3],
+            pending_len: 0,
+            column: 0,
+            config,
+        }
+    }
+
+    /// `input_len` 바이트에 대한 [`update`](Self::update)가 기록할 수 있는 최대 바이트 수를
+    /// 반환합니다.
+    pub fn max_update_len(&self, input_len: usize) -> usize {
+        let chars = (self.pending_len + input_len) / 3 * 4;
+        match self.config.line_width() {
+            Some(width) => chars + (chars / width + 1) * self.config.line_ending().as_bytes().len(),
+            None => chars,
+        }
+    }
+
+    /// 입력 조각을 인코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Panic
+    /// `output`이 [`max_update_len`](Self::max_update_len)보다 작으면 패닉이 발생합니다.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        assert!(
+            output.len() >= self.max_update_len(input.len()),
+            "Security Violation: Output buffer overflow"
+        );
+        let alphabet = self.config.alphabet();
+        let mut input = input;
+        let mut written = 0;
+
+        // 1. 이전 호출에서 이월된 불완전 그룹 채우기
+        if self.pending_len > 0 {
+            let take = (3 - self.pending_len).min(input.len());
+            self.pending[self.pending_len..self.pending_len + take].copy_from_slice(&input[..take]);
+            self.pending_len += take;
+            input = &input[take..];
+            if self.pending_len < 3 {
+                return 0;
+            }
+
+            let mut chars = [0u8; 4];
+            encode_full_groups(&self.pending, &mut chars, alphabet);
+            written += self.emit(&chars, &mut output[written..]);
+            chars.secure_zeroize();
+            self.pending.secure_zeroize();
+            self.pending_len = 0;
+        }
+
+        // 2. 완전한 그룹 일괄 처리
+        let full_len = input.len() / 3 * 3;
+        if self.config.line_width().is_none() {
+            encode_full_groups(&input[..full_len], &mut output[written..], alphabet);
+            written += full_len / 3 * 4;
+        } else {
+            let mut chars = [0u8; WRAP_CHUNK / 3 * 4];
+            for chunk in input[..full_len].chunks(WRAP_CHUNK) {
+                let n = chunk.len() / 3 * 4;
+                encode_full_groups(chunk, &mut chars[..n], alphabet);
+                written += self.emit(&chars[..n], &mut output[written..]);
+            }
+            chars.secure_zeroize();
+        }
+
+        // 3. 남은 1~2바이트 이월
+        let rest = &input[full_len..];
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+        written
+    }
+
+    /// 이월된 불완전 그룹을 인코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Panic
+    /// 기록할 데이터가 있을 때 `output`이 [`MAX_FINISH_LEN`](Self::MAX_FINISH_LEN)보다 작으면
+    /// 패닉이 발생할 수 있습니다.
+    pub fn finish(mut self, output: &mut [u8]) -> usize {
+        let mut chars = [0u8; 4];
+        let len = encode_tail(&self.pending[..self.pending_len], &mut chars, self.config);
+        let written = self.emit(&chars[..len], output);
+        chars.secure_zeroize();
+        written
+    }
+
+    /// 인코딩된 문자를 줄바꿈 설정에 따라 기록합니다. 마지막 줄 뒤에는 줄바꿈을 추가하지 않도록
+    /// 줄바꿈은 다음 문자가 기록되기 직전에 삽입됩니다.
+    fn emit(&mut self, chars: &[u8], output: &mut [u8]) -> usize {
+        let Some(width) = self.config.line_width() else {
+            output[..chars.len()].copy_from_slice(chars);
+            return chars.len();
+        };
+
+        let ending = self.config.line_ending().as_bytes();
+        let mut chars = chars;
+        let mut pos = 0;
+        while !chars.is_empty() {
+            if self.column == width {
+                output[pos..pos + ending.len()].copy_from_slice(ending);
+                pos += ending.len();
+                self.column = 0;
+            }
+            let n = (width - self.column).min(chars.len());
+            output[pos..pos + n].copy_from_slice(&chars[..n]);
+            pos += n;
+            self.column += n;
+            chars = &chars[n..];
+        }
+        pos
+    }
+}
+
+/// 스트리밍 Base64 디코더입니다.
+///
+/// 마지막 그룹은 패딩을 포함할 수 있으므로, [`update`](Self::update)는 뒤따르는 문자가 확인된
+/// 4문자 그룹만 디코딩하고 마지막 1~4문자를 [`finish`](Self::finish)까지 보류합니다.
+/// 허용되는 형식은 같은 [`Config`]의 [`decode_with`](crate::decode_with)와 동일합니다.
+///
+/// # Security Note
+/// - 유효하지 않은 문자는 `update`에서 보고되지 않고 상수-시간 누산기에 병합되며, 오류는
+///   `finish`에서만 공개됩니다. 무효 입력에 대해서도 `update`는 동일한 양의 출력을 기록합니다.
+/// - `finish`가 `Err`를 반환하면 그때까지 기록된 출력 전체를 폐기해야 합니다.
+/// - 줄바꿈 설정에서 무시되는 공백 문자의 위치는 형식 정보(공개)로 취급됩니다.
+///
+/// # Usage
+/// ```
+/// use entlib_native_base64::{Base64Decoder, Config};
+///
+/// let mut decoder = Base64Decoder::new(Config::STANDARD);
+/// let mut out = [0u8; 8];
+/// let mut len = decoder.update(b"Zm9vY", &mut out);
+/// len += decoder.update(b"mE=", &mut out[len..]);
+/// len += decoder.finish(&mut out[len..]).unwrap();
+/// assert_eq!(&out[..len], b"fooba");
+/// ```
+#[derive(SecureZeroize, ZeroizeOnDrop)]
+pub struct Base64Decoder {
+    pending: [u8; 4],
+    pending_len: usize,
+    invalid: u8,
+    #[zeroize(skip)]
+    config: Config,
+}
+
+impl Base64Decoder {
+    /// [`finish`](Self::finish)가 기록하는 최대 바이트 수
+    pub const MAX_FINISH_LEN: usize = 3;
+
+    /// 지정한 설정으로 디코더를 생성합니다.
+    pub fn new(config: Config) -> Self {
+        Self {
+            pending: [0; 4],
+            pending_len: 0,
+            invalid: 0,
+            config,
+        }
+    }
+
+    /// `input_len` 바이트에 대한 [`update`](Self::update)가 기록할 수 있는 최대 바이트 수를
+    /// 반환합니다.
+    pub fn max_update_len(&self, input_len: usize) -> usize {
+        (self.pending_len + input_len) / 4 * 3
+    }
+
+    /// 입력 조각을 디코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Panic
+    /// `output`이 [`max_update_len`](Self::max_update_len)보다 작으면 패닉이 발생합니다.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        assert!(
+            output.len() >= self.max_update_len(input.len()),
+            "Security Violation: Output buffer overflow"
+        );
+
+        if self.config.line_width().is_some() {
+            // 공백 위치는 공개 정보로 취급하여 문자 단위로 건너뜁니다.
+            let mut written = 0;
+            for &b in input {
+                let is_ws = ct_b64_to_bin_u8_with(b, self.config.alphabet()).ct_eq(&0x80);
+                if !public_flag(is_ws) {
+                    written += self.push(b, &mut output[written..]);
+                }
+            }
+            return written;
+        }
+
+        // 1. 보류 중인 그룹 채우기
+        let fill = (4 - self.pending_len).min(input.len());
+        self.pending[self.pending_len..self.pending_len + fill].copy_from_slice(&input[..fill]);
+        self.pending_len += fill;
+        let input = &input[fill..];
+        if input.is_empty() {
+            return 0;
+        }
+
+        // 2. 뒤따르는 문자가 있으므로 보류 중인 그룹은 마지막 그룹이 아님
+        let alphabet = self.config.alphabet();
+        self.invalid |= decode_group(&self.pending, &mut output[..3], alphabet);
+        let mut written = 3;
+
+        // 3. 마지막 1~4문자를 제외한 완전한 그룹 일괄 처리
+        let full_len = (input.len() - 1) / 4 * 4;
+        for chars in input[..full_len].chunks_exact(4) {
+            self.invalid |= decode_group(chars, &mut output[written..written + 3], alphabet);
+            written += 3;
+        }
+
+        // 4. 남은 문자 보류
+        let rest = &input[full_len..];
+        self.pending.secure_zeroize();
+        self.pending[..rest.len()].copy_from_slice(rest);
+        self.pending_len = rest.len();
+        written
+    }
+
+    /// 보류 중인 마지막 그룹을 디코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Returns
+    /// - `Ok(usize)` - 마지막 그룹에서 기록한 바이트 수
+    /// - `Err` - 길이, 패딩 또는 문자 유효성 오류 (스트림 전체에 대해 누산된 결과)
+    ///
+    /// # Panic
+    /// 기록할 데이터가 있을 때 `output`이 [`MAX_FINISH_LEN`](Self::MAX_FINISH_LEN)보다 작으면
+    /// 패닉이 발생할 수 있습니다.
+    pub fn finish(mut self, output: &mut [u8]) -> Result<usize, &'static str> {
+        let alphabet = self.config.alphabet();
+        let chars = &self.pending[..self.pending_len];
+
+        // 보류 문자 수는 입력 길이로부터 공개 정보이므로 분기 허용
+        let data_len = if self.config.padding() {
+            match chars.len() {
+                0 => 0,
+                4 => {
+                    let (len, invalid) = padded_group_data_len(chars);
+                    self.invalid |= invalid;
+                    len
+                }
+                _ => return Err("invalid base64: length must be a multiple of 4"),
+            }
+        } else {
+            if chars.len() == 1 {
+                return Err("invalid base64: truncated final group");
+            }
+            chars.len()
+        };
+
+        let written = if data_len == 4 {
+            self.invalid |= decode_group(chars, &mut output[..3], alphabet);
+            3
+        } else {
+            self.invalid |= decode_tail(&chars[..data_len], output, alphabet);
+            data_len.saturating_sub(1)
+        };
+
+        // 유효 여부는 CtOption::into_option에서만 공개됩니다.
+        CtOption::new(written, self.invalid.ct_eq(&0))
+            .into_option()
+            .ok_or("invalid base64: illegal character or padding")
+    }
+
+    /// 공백이 제거된 문자 하나를 보류 그룹에 추가하고, 기록한 바이트 수를 반환합니다.
+    #[inline]
+    fn push(&mut self, b: u8, output: &mut [u8]) -> usize {
+        let mut written = 0;
+        if self.pending_len == 4 {
+            // 뒤따르는 문자가 있으므로 보류 중인 그룹은 마지막 그룹이 아님
+            self.invalid |= decode_group(&self.pending, &mut output[..3], self.config.alphabet());
+            self.pending_len = 0;
+            written = 3;
+        }
+        self.pending[self.pending_len] = b;
+        self.pending_len += 1;
+        written
+    }
+}
@@ -1,4 +1,12 @@
 use entlib_native_base64::base64::{ct_b64_to_bin_u8, ct_bin_to_b64_u8};
+use entlib_native_base64::{decode, encode};
+use entlib_native_secure_buffer::SecureBuffer;
+
+fn buffer(data: &[u8]) -> SecureBuffer {
+    let mut buf = SecureBuffer::new_owned(data.len()).unwrap();
+    buf.as_mut_slice().copy_from_slice(data);
+    buf
+}
 
 #[cfg(test)]
 mod base64_constant_time_tests {
@@ -89,3 +97,296 @@ mod base64_constant_time_tests {
         }
     }
 }
+
+#[cfg(test)]
+mod base64_codec_tests {
+    use super::*;
+
+    /// RFC 4648 10장 테스트 벡터
+    const VECTORS: [(&[u8], &[u8]); 7] = [
+        (b"", b""),
+        (b"f", b"Zg=="),
+        (b"fo", b"Zm8="),
+        (b"foo", b"Zm9v"),
+        (b"foob", b"Zm9vYg=="),
+        (b"fooba", b"Zm9vYmE="),
+        (b"foobar", b"Zm9vYmFy"),
+    ];
+
+    #[test]
+    fn test_rfc4648_vectors() {
+        for (plain, encoded) in VECTORS {
+            assert_eq!(encode(&buffer(plain)).unwrap().as_slice(), encoded);
+            assert_eq!(decode(&buffer(encoded)).unwrap().as_slice(), plain);
+        }
+    }
+
+    #[test]
+    fn test_roundtrip_all_lengths() {
+        let data: Vec<u8> = (0..=255u8).collect();
+        for len in 0..data.len() {
+            let encoded = encode(&buffer(&data[..len])).unwrap();
+            assert_eq!(decode(&encoded).unwrap().as_slice(), &data[..len]);
+        }
+    }
+
+    #[test]
+    fn test_encode_matches_scalar_reference() {
+        // SIMD 블록(AVX2 24바이트, NEON 48바이트) 경계와 나머지 그룹을 모두 지나는 길이
+        let data: Vec<u8> = (0..400u32).map(|i| (i * 197 + 11) as u8).collect();
+        for len in 0..data.len() {
+            let mut expected = Vec::new();
+            for group in data[..len].chunks(3) {
+                let mut g = [0u8; 3];
+                g[..group.len()].copy_from_slice(group);
+                let idx = [
+                    g[0] >> 2,
+                    (g[0] & 0x03) << 4 | g[1] >> 4,
+                    (g[1] & 0x0F) << 2 | g[2] >> 6,
+                    g[2] & 0x3F,
+                ];
+                for (k, &i) in idx.iter().enumerate() {
+                    expected.push(if k <= group.len() {
+                        ct_bin_to_b64_u8(i)
+                    } else {
+                        b'='
+                    });
+                }
+            }
+            let encoded = encode(&buffer(&data[..len])).unwrap();
+            assert_eq!(encoded.as_slice(), expected.as_slice(), "length {len}");
+        }
+    }
+
+    #[test]
+    fn test_rejects_malformed_input() {
+        let malformed: [&[u8]; 8] = [
+            b"Zg=",      // 길이가 4의 배수가 아님
+            b"Zm=v",     // 세 번째 자리에만 패딩
+            b"Z===",     // 두 번째 자리 패딩
+            b"Zm9v!A==", // 무효 문자
+            b"Zg==Zm9v", // 마지막 그룹이 아닌 곳의 패딩
+            b"Zm9 ",     // 공백
+            b"Zm-v",     // URL-safe 알파벳
+            b"Zm9vY===", // 길이 오류 + 과도한 패딩
+        ];
+        for input in malformed {
+            assert!(decode(&buffer(input)).is_err(), "accepted {input:?}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod base64_config_tests {
+    use super::*;
+    use entlib_native_base64::base64::{ct_b64_to_bin_u8_with, ct_bin_to_b64_u8_with};
+    use entlib_native_base64::{Alphabet, Config, LineEnding, decode_with, encode_with};
+
+    #[test]
+    fn test_url_safe_mapping_exhaustive() {
+        for i in 0..64u8 {
+            let std = ct_bin_to_b64_u8_with(i, Alphabet::Standard);
+            let url = ct_bin_to_b64_u8_with(i, Alphabet::UrlSafe);
+            let expected = match std {
+                b'+' => b'-',
+                b'/' => b'_',
+                c => c,
+            };
+            assert_eq!(url, expected, "index {i}");
+            assert_eq!(ct_b64_to_bin_u8_with(url, Alphabet::UrlSafe), i);
+        }
+        // 다른 알파벳의 62, 63 문자는 무효
+        assert_eq!(ct_b64_to_bin_u8_with(b'+', Alphabet::UrlSafe), 0xFF);
+        assert_eq!(ct_b64_to_bin_u8_with(b'/', Alphabet::UrlSafe), 0xFF);
+        assert_eq!(ct_b64_to_bin_u8_with(b'-', Alphabet::Standard), 0xFF);
+        assert_eq!(ct_b64_to_bin_u8_with(b'_', Alphabet::Standard), 0xFF);
+    }
+
+    #[test]
+    fn test_variant_vectors() {
+        let data = [0xFBu8, 0xFF, 0xBF, 0xFE];
+        let cases: [(Config, &[u8]); 4] = [
+            (Config::STANDARD, b"+/+//g=="),
+            (Config::STANDARD_NO_PAD, b"+/+//g"),
+            (Config::URL_SAFE, b"-_-__g=="),
+            (Config::URL_SAFE_NO_PAD, b"-_-__g"),
+        ];
+        for (config, encoded) in cases {
+            assert_eq!(
+                encode_with(&buffer(&data), config).unwrap().as_slice(),
+                encoded
+            );
+            assert_eq!(
+                decode_with(&buffer(encoded), config).unwrap().as_slice(),
+                &data
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_pad_roundtrip_all_lengths() {
+        let data: Vec<u8> = (0..=255u8).rev().collect();
+        for config in [Config::STANDARD_NO_PAD, Config::URL_SAFE_NO_PAD] {
+            for len in 0..data.len() {
+                let encoded = encode_with(&buffer(&data[..len]), config).unwrap();
+                assert_eq!(encoded.len(), (len * 4).div_ceil(3));
+                assert_eq!(
+                    decode_with(&encoded, config).unwrap().as_slice(),
+                    &data[..len]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_line_wrapped_output() {
+        let data: Vec<u8> = (0..200u8).collect();
+        for (config, width, ending) in [
+            (Config::MIME, 76, &b"\r\n"[..]),
+            (Config::PEM, 64, &b"\n"[..]),
+        ] {
+            for len in [0, 1, 56, 57, 58, 114, 200] {
+                let encoded = encode_with(&buffer(&data[..len]), config).unwrap();
+                let unwrapped = encode(&buffer(&data[..len])).unwrap();
+                let expected: Vec<u8> = unwrapped
+                    .as_slice()
+                    .chunks(width)
+                    .collect::<Vec<_>>()
+                    .join(ending);
+                assert_eq!(encoded.as_slice(), expected.as_slice(), "length {len}");
+                assert_eq!(
+                    decode_with(&encoded, config).unwrap().as_slice(),
+                    &data[..len]
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_wrapped_decoding_tolerates_whitespace() {
+        let config = Config::STANDARD.with_line_wrap(4, LineEnding::Lf);
+        let input = b" Zm9v\r\n\tYmFy \n";
+        assert_eq!(
+            decode_with(&buffer(input), config).unwrap().as_slice(),
+            b"foobar"
+        );
+        // 공백 이외의 무효 문자는 여전히 거부
+        assert!(decode_with(&buffer(b"Zm9v\n!mFy"), Config::MIME).is_err());
+        // 줄바꿈이 없는 설정은 공백을 거부
+        assert!(decode_with(&buffer(b"Zm9v\r\nYmFy"), Config::STANDARD).is_err());
+    }
+
+    #[test]
+    fn test_rejects_non_canonical_input() {
+        let cases: [(Config, &[u8]); 7] = [
+            (Config::STANDARD, b"Zh=="),         // 사용되지 않는 하위 비트가 0이 아님
+            (Config::STANDARD, b"Zm9="),         // 사용되지 않는 하위 비트가 0이 아님
+            (Config::STANDARD, b"Zg"),           // 필수 패딩 누락
+            (Config::STANDARD_NO_PAD, b"Zg=="),  // 패딩 없는 설정에서의 패딩
+            (Config::STANDARD_NO_PAD, b"Zh"),    // 사용되지 않는 하위 비트가 0이 아님
+            (Config::URL_SAFE_NO_PAD, b"Zm9vY"), // 나머지 1문자
+            (Config::URL_SAFE_NO_PAD, b"+/8"),   // 다른 알파벳
+        ];
+        for (config, input) in cases {
+            assert!(
+                decode_with(&buffer(input), config).is_err(),
+                "accepted {input:?} with {config:?}"
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod base64_stream_tests {
+    use super::*;
+    use entlib_native_base64::{
+        Base64Decoder, Base64Encoder, Config, LineEnding, decode_with, encode_with,
+    };
+
+    const CONFIGS: [Config; 5] = [
+        Config::STANDARD,
+        Config::URL_SAFE_NO_PAD,
+        Config::MIME,
+        Config::PEM,
+        Config::URL_SAFE.with_line_wrap(5, LineEnding::CrLf),
+    ];
+
+    fn stream_encode(config: Config, data: &[u8], chunk: usize) -> Vec<u8> {
+        let mut encoder = Base64Encoder::new(config);
+        let mut out = Vec::new();
+        for part in data.chunks(chunk) {
+            let start = out.len();
+            out.resize(start + encoder.max_update_len(part.len()), 0);
+            let n = encoder.update(part, &mut out[start..]);
+            out.truncate(start + n);
+        }
+        let start = out.len();
+        out.resize(start + Base64Encoder::MAX_FINISH_LEN, 0);
+        let n = encoder.finish(&mut out[start..]);
+        out.truncate(start + n);
+        out
+    }
+
+    fn stream_decode(config: Config, data: &[u8], chunk: usize) -> Result<Vec<u8>, &'static str> {
+        let mut decoder = Base64Decoder::new(config);
+        let mut out = Vec::new();
+        for part in data.chunks(chunk) {
+            let start = out.len();
+            out.resize(start + decoder.max_update_len(part.len()), 0);
+            let n = decoder.update(part, &mut out[start..]);
+            out.truncate(start + n);
+        }
+        let start = out.len();
+        out.resize(start + Base64Decoder::MAX_FINISH_LEN, 0);
+        let n = decoder.finish(&mut out[start..])?;
+        out.truncate(start + n);
+        Ok(out)
+    }
+
+    #[test]
+    fn test_stream_matches_one_shot() {
+        let data: Vec<u8> = (0..200u32).map(|i| (i * 73 + 5) as u8).collect();
+        for config in CONFIGS {
+            for len in [0, 1, 2, 3, 4, 5, 57, 58, 100, 200] {
+                let expected = encode_with(&buffer(&data[..len]), config).unwrap();
+                for chunk in [1, 2, 3, 4, 5, 7, 31, 64, 256] {
+                    let encoded = stream_encode(config, &data[..len], chunk);
+                    assert_eq!(
+                        encoded,
+                        expected.as_slice(),
+                        "{config:?} len {len} chunk {chunk}"
+                    );
+
+                    let decoded = stream_decode(config, &encoded, chunk).unwrap();
+                    assert_eq!(decoded, &data[..len], "{config:?} len {len} chunk {chunk}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_stream_decoder_matches_one_shot_acceptance() {
+        let cases: [(Config, &[u8]); 10] = [
+            (Config::STANDARD, b"Zm9vYmE="),
+            (Config::STANDARD, b"Zm9vYg=="),
+            (Config::STANDARD, b"Zm9vYmE"),
+            (Config::STANDARD, b"Zm=vYmFy"),
+            (Config::STANDARD, b"Zh=="),
+            (Config::STANDARD, b"Zg==Zm9v"),
+            (Config::URL_SAFE_NO_PAD, b"Zm9vY"),
+            (Config::URL_SAFE_NO_PAD, b"Zm9vYg"),
+            (Config::MIME, b" Zm9v\r\nYmE=\r\n"),
+            (Config::MIME, b"Zm9v\r\n!mE="),
+        ];
+        for (config, input) in cases {
+            let one_shot = decode_with(&buffer(input), config).map(|b| b.as_slice().to_vec());
+            for chunk in 1..=input.len() {
+                assert_eq!(
+                    stream_decode(config, input, chunk).ok(),
+                    one_shot.clone().ok(),
+                    "{input:?} chunk {chunk}"
+                );
+            }
+        }
+    }
+}
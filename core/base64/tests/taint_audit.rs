@@ -0,0 +1,96 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-base64 --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_base64::{
+    Base64Decoder, Base64Encoder, Config, decode, decode_with, encode, encode_with,
+};
+use entlib_native_secure_buffer::SecureBuffer;
+use entlib_native_test_support::taint::{classify_secret, declassify};
+
+fn secret_buffer(data: &[u8]) -> SecureBuffer {
+    let mut buf = SecureBuffer::new_owned(data.len()).unwrap();
+    buf.as_mut_slice().copy_from_slice(data);
+    classify_secret(buf.as_slice());
+    buf
+}
+
+#[test]
+fn audit_taint_flow_base64_encode() {
+    for (plain, expected) in [(&b"foobar"[..], &b"Zm9vYmFy"[..]), (b"fooba", b"Zm9vYmE=")] {
+        let encoded = encode(&secret_buffer(plain)).unwrap();
+        declassify(encoded.as_slice());
+        assert_eq!(encoded.as_slice(), expected);
+    }
+}
+
+#[test]
+fn audit_taint_flow_base64_decode() {
+    // 패딩 없음, '=' 1개, '=' 2개 (패딩 위치만 공개)
+    for (encoded, expected) in [
+        (&b"Zm9vYmFy"[..], &b"foobar"[..]),
+        (b"Zm9vYmE=", b"fooba"),
+        (b"Zm9vYg==", b"foob"),
+    ] {
+        let decoded = decode(&secret_buffer(encoded)).unwrap();
+        declassify(decoded.as_slice());
+        assert_eq!(decoded.as_slice(), expected);
+    }
+}
+
+#[test]
+fn audit_taint_flow_base64_decode_rejects_invalid() {
+    assert!(decode(&secret_buffer(b"Zm9v!A==")).is_err());
+}
+
+#[test]
+fn audit_taint_flow_base64_config_variants() {
+    // 공백 위치와 패딩 위치만 공개되며, 알파벳 매핑과 정규 형식 검사는 상수-시간으로 수행
+    let plain = [0xFBu8, 0xFF, 0xBF, 0xFE];
+    for (config, expected) in [
+        (Config::URL_SAFE_NO_PAD, &b"-_-__g"[..]),
+        (Config::PEM, b"+/+//g=="),
+    ] {
+        let encoded = encode_with(&secret_buffer(&plain), config).unwrap();
+        declassify(encoded.as_slice());
+        assert_eq!(encoded.as_slice(), expected);
+
+        let decoded = decode_with(&secret_buffer(expected), config).unwrap();
+        declassify(decoded.as_slice());
+        assert_eq!(decoded.as_slice(), &plain);
+    }
+
+    let decoded = decode_with(&secret_buffer(b"Zm9v\r\nYmFy\r\n"), Config::MIME).unwrap();
+    declassify(decoded.as_slice());
+    assert_eq!(decoded.as_slice(), b"foobar");
+}
+
+#[test]
+fn audit_taint_flow_base64_stream() {
+    // 조각 경계에서 이월되는 불완전 그룹과 보류된 마지막 그룹(패딩 위치만 공개)
+    let plain = secret_buffer(b"foobar!");
+    let mut encoder = Base64Encoder::new(Config::MIME);
+    let mut encoded = [0u8; 16];
+    let mut len = 0;
+    for part in plain.as_slice().chunks(2) {
+        len += encoder.update(part, &mut encoded[len..]);
+    }
+    len += encoder.finish(&mut encoded[len..]);
+    declassify(&encoded[..len]);
+    assert_eq!(&encoded[..len], b"Zm9vYmFyIQ==");
+
+    let input = secret_buffer(b"Zm9vYmFyIQ==");
+    let mut decoder = Base64Decoder::new(Config::STANDARD);
+    let mut decoded = [0u8; 9];
+    let mut len = 0;
+    for part in input.as_slice().chunks(5) {
+        len += decoder.update(part, &mut decoded[len..]);
+    }
+    len += decoder.finish(&mut decoded[len..]).unwrap();
+    declassify(&decoded[..len]);
+    assert_eq!(&decoded[..len], b"foobar!");
+}
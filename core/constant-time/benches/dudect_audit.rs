@@ -39,7 +39,7 @@ fn bench_ct_eq(runner: &mut CtRunner, rng: &mut BenchRng) {
         }
     }
 
-    for (class, (a, b)) in classes.into_iter().zip(inputs.into_iter()) {
+    for (class, (a, b)) in classes.into_iter().zip(inputs) {
         runner.run_one(class, || {
             // black_box를 통해 컴파일러 DCE 최적화 억제
             let _ =
@@ -68,7 +68,7 @@ fn bench_ct_select(runner: &mut CtRunner, rng: &mut BenchRng) {
         }
     }
 
-    for (class, (a, b, mask)) in classes.into_iter().zip(inputs.into_iter()) {
+    for (class, (a, b, mask)) in classes.into_iter().zip(inputs) {
         // Choice 구조체의 내부 필드는 private이라서 벤치마크 환경에 한하여
         // 메모리 transmute를 통해 강제로 마스크 값 주입
         let choice = unsafe { core::mem::transmute::<u8, Choice>(mask) };
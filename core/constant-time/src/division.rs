@@ -0,0 +1,44 @@
+//! 공개 상수로 나누는 상수-시간 나눗셈 모듈입니다.
+//!
+//! 하드웨어 나눗셈 명령어(`div`, `udiv`)는 다수의 프로세서에서 피연산자 값에 따라 소요 사이클이
+//! 달라지므로 비밀 값에 사용할 수 없습니다. ML-DSA의 `Decompose`/`HighBits`나 ML-KEM의
+//! `Compress`처럼 비밀 계수를 공개 상수로 나누는 연산은 본 모듈의 Barrett 방식 함수를 사용해야 합니다.
+
+use crate::traits::{ConstantTimeOrd, ConstantTimeSelect};
+
+/// 비밀 값 `a`를 공개 상수 `D`로 나눈 (몫, 나머지)를 상수-시간으로 계산합니다.
+///
+/// Barrett 환원을 사용합니다. 컴파일 시점에 `m = ⌊2^32 / D⌋`를 계산해 두고,
+/// 몫의 추정값 `⌊a·m / 2^32⌋`을 곱셈과 시프트만으로 구합니다. `a < 2^32`이면 추정값은
+/// 실제 몫보다 최대 1 작으므로, 한 번의 `ct_select` 보정으로 정확한 결과를 얻습니다.
+///
+/// 중간 곱 `a·m`은 `2^64` 미만이므로 64비트 곱셈 하나로 충분하며, 나눗셈 명령어는 생성되지 않습니다.
+///
+/// # Panics
+/// `D`가 0이면 컴파일 오류가 발생합니다.
+///
+/// # Examples
+/// ```
+/// use entlib_native_constant_time::division::ct_div_rem_by_const;
+///
+/// // ML-DSA-65의 2·γ2 = (q - 1) / 16
+/// assert_eq!(ct_div_rem_by_const::<523776>(8380416), (16, 0));
+/// ```
+#[inline(always)]
+pub fn ct_div_rem_by_const<const D: u32>(a: u32) -> (u32, u32) {
+    let m: u64 = const {
+        assert!(D != 0, "ct_div_rem_by_const requires a non-zero divisor");
+        (1u64 << 32) / D as u64
+    };
+
+    // 추정 몫은 실제 몫 q 또는 q - 1
+    let quotient = ((a as u64 * m) >> 32) as u32;
+    let remainder = a.wrapping_sub(quotient.wrapping_mul(D));
+
+    // 나머지가 D 이상이면 (추정 몫이 1 작은 경우) 분기 없이 보정
+    let needs_fix = remainder.ct_is_ge(&D);
+    (
+        u32::ct_select(&quotient.wrapping_add(1), &quotient, needs_fix),
+        u32::ct_select(&remainder.wrapping_sub(D), &remainder, needs_fix),
+    )
+}
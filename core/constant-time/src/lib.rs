@@ -1,15 +1,22 @@
 #![no_std]
 
 pub mod choice;
+pub mod division;
+pub mod option;
+pub mod ordering;
 pub mod traits;
+pub mod uint;
+pub mod util;
+
+mod slice;
 
 #[cfg(feature = "audit_mode")]
 mod wrapper;
 
 use choice::Choice;
 use traits::{
-    ConstantTimeEq, ConstantTimeIsNegative, ConstantTimeIsZero, ConstantTimeSelect,
-    ConstantTimeSwap,
+    ConstantTimeEq, ConstantTimeIsNegative, ConstantTimeIsZero, ConstantTimeOrd,
+    ConstantTimeSelect, ConstantTimeSwap,
 };
 
 /// 기본 부호 없는 정수형(Unsigned Integers)에 대한 상수-시간 연산을
@@ -36,7 +43,9 @@ macro_rules! impl_constant_time_for_uint {
 
                 Choice::from_mask_normalized(mask)
             }
+        }
 
+        impl ConstantTimeOrd for $t {
             #[inline(always)]
             fn ct_is_ge(&self, other: &Self) -> Choice {
                 // 부호 없는 정수의 대소 비교(self >= other)를 상수-시간으로 판별하기 위해
@@ -131,7 +140,9 @@ macro_rules! impl_constant_time_for_sint {
                 // 기 검증된 Unsigned의 상수-시간 동일성 비교 로직으로 위임
                 a.ct_eq(&b)
             }
+        }
 
+        impl ConstantTimeOrd for $s_type {
             #[inline(always)]
             fn ct_is_ge(&self, other: &Self) -> Choice {
                 // 부호 있는 정수의 대소 비교 시 타이밍 공격 방지
@@ -0,0 +1,109 @@
+use crate::choice::Choice;
+use crate::traits::ConstantTimeSelect;
+
+/// 유효 여부를 `Choice` 마스크로 보관하는 상수-시간 [`Option`] 대응 타입입니다.
+///
+/// 디코딩, 스칼라 파싱, 서명 검증처럼 실패 여부 자체가 비밀 정보가 될 수 있는 연산에서
+/// `(값, Choice)` 튜플 대신 사용합니다. 값은 유효하지 않은 경우에도 항상 존재하며(더미 값),
+/// 모든 조합 연산은 유효 여부와 무관하게 동일한 연산을 수행합니다.
+///
+/// # Security Note
+/// 유효 여부를 실제 분기로 공개하는 지점은 [`into_option`](Self::into_option) 하나뿐입니다.
+/// 호출자는 결과가 공개되어도 되는 마지막 단계에서만 이를 호출해야 합니다. 같은 이유로 `Debug`
+/// 출력은 값과 유효 여부를 모두 가립니다.
+#[derive(Clone, Copy)]
+pub struct CtOption<T> {
+    value: T,
+    is_some: Choice,
+}
+
+impl<T> core::fmt::Debug for CtOption<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("CtOption([REDACTED])")
+    }
+}
+
+impl<T> CtOption<T> {
+    /// 값과 유효 여부 마스크로부터 `CtOption`을 구성합니다.
+    ///
+    /// `is_some`이 거짓이어도 `value`는 보관되며, 이후 연산에서 더미 값으로 사용됩니다.
+    #[inline(always)]
+    pub fn new(value: T, is_some: Choice) -> Self {
+        Self { value, is_some }
+    }
+
+    /// 값이 유효하면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_some(&self) -> Choice {
+        self.is_some
+    }
+
+    /// 값이 유효하지 않으면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_none(&self) -> Choice {
+        !self.is_some
+    }
+
+    /// 값이 유효하면 `value`를, 그렇지 않으면 `default`를 분기 없이 선택합니다.
+    #[inline]
+    pub fn unwrap_or(self, default: T) -> T
+    where
+        T: ConstantTimeSelect,
+    {
+        T::ct_select(&self.value, &default, self.is_some)
+    }
+
+    /// 보관된 값에 `f`를 적용합니다.
+    ///
+    /// `f`는 유효 여부와 관계없이 항상 호출되므로(유효하지 않으면 더미 값으로 호출),
+    /// 입력 값에 따라 실행 시간이 달라지지 않는 연산이어야 합니다. 유효 여부는 그대로 유지됩니다.
+    #[inline]
+    pub fn ct_map<U, F>(self, f: F) -> CtOption<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        CtOption::new(f(self.value), self.is_some)
+    }
+
+    /// 보관된 값에 실패할 수 있는 연산 `f`를 적용하고 두 유효 여부를 결합합니다.
+    ///
+    /// [`ct_map`](Self::ct_map)과 마찬가지로 `f`는 항상 호출되며,
+    /// 결과는 `self`와 `f`의 결과가 모두 유효한 경우에만 유효합니다.
+    #[inline]
+    pub fn ct_and_then<U, F>(self, f: F) -> CtOption<U>
+    where
+        F: FnOnce(T) -> CtOption<U>,
+    {
+        let inner = f(self.value);
+        CtOption::new(inner.value, self.is_some & inner.is_some)
+    }
+
+    /// 유효 여부를 공개(Declassify)하여 [`Option`]으로 변환합니다.
+    ///
+    /// 이 함수는 유효 여부에 따른 실제 분기가 발생하는 유일한 지점입니다.
+    /// 결과가 공개되어도 되는 경우에만 사용해야 합니다.
+    ///
+    /// `valgrind_taint_audit` 기능이 활성화되면 분기 직전에 유효 여부의 오염 표시를 해제하므로,
+    /// Memcheck는 이 의도된 공개 지점을 오류로 보고하지 않습니다.
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        let is_some = self.is_some.unwrap_u8();
+        #[cfg(feature = "valgrind_taint_audit")]
+        let is_some = entlib_native_test_support::taint::declassified(is_some);
+
+        if is_some == 0xFF {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// 두 `CtOption`의 값과 유효 여부를 함께 조건부 선택합니다.
+impl<T: ConstantTimeSelect> ConstantTimeSelect for CtOption<T> {
+    #[inline]
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let is_some = (a.is_some & choice) | (b.is_some & !choice);
+        CtOption::new(T::ct_select(&a.value, &b.value, choice), is_some)
+    }
+}
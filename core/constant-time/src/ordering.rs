@@ -0,0 +1,94 @@
+use crate::choice::Choice;
+use crate::traits::ConstantTimeOrd;
+
+/// 상수-시간 비교의 결과를 표현하는 불투명(Opaque) 순서 타입입니다.
+///
+/// [`core::cmp::Ordering`]과 달리 비교 결과를 `Choice` 마스크로 보관하므로,
+/// 결과를 조건 분기 없이 후속 상수-시간 연산(`ct_select` 등)에 그대로 연결할 수 있습니다.
+///
+/// # Security Note
+/// 내부 불변 조건: `less`와 `greater`는 동시에 참(`0xFF`)이 될 수 없습니다.
+/// 두 값이 모두 거짓(`0x00`)이면 동일(Equal)을 의미합니다.
+#[derive(Clone, Copy, Debug)]
+pub struct CtOrdering {
+    less: Choice,
+    greater: Choice,
+}
+
+impl CtOrdering {
+    /// 두 마스크로부터 순서 값을 구성합니다. 호출자는 불변 조건을 보장해야 합니다.
+    #[inline(always)]
+    pub(crate) fn from_choices(less: Choice, greater: Choice) -> Self {
+        Self { less, greater }
+    }
+
+    /// `self < other`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_lt(self) -> Choice {
+        self.less
+    }
+
+    /// `self == other`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_eq(self) -> Choice {
+        !(self.less | self.greater)
+    }
+
+    /// `self > other`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_gt(self) -> Choice {
+        self.greater
+    }
+
+    /// `self <= other`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_le(self) -> Choice {
+        !self.greater
+    }
+
+    /// `self >= other`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_ge(self) -> Choice {
+        !self.less
+    }
+
+    /// 비교 결과를 공개(Declassify)하여 [`core::cmp::Ordering`]으로 변환합니다.
+    ///
+    /// 변환 자체는 분기 없이 수행되지만, 반환 값에 대한 `match` 등은 비밀 의존 분기가 되므로
+    /// 결과가 공개되어도 되는 경우에만 사용해야 합니다.
+    #[inline(always)]
+    pub fn declassify(self) -> core::cmp::Ordering {
+        // greater - less: -1(Less), 0(Equal), 1(Greater)
+        let value = (self.greater.unwrap_u8() & 1) as i8 - (self.less.unwrap_u8() & 1) as i8;
+        value.cmp(&0)
+    }
+}
+
+/// 두 슬라이스를 사전식(Lexicographic)으로 상수-시간 비교합니다.
+///
+/// 공통 길이 구간 전체를 항상 끝까지 스캔하며, 최초로 다른 원소의 대소가 결과를 결정합니다.
+/// 공통 구간이 모두 같으면 길이가 짧은 쪽이 작습니다. 길이는 공개 정보로 취급됩니다.
+///
+/// 바이트 슬라이스의 경우 결과는 빅엔디안 부호 없는 정수로서의 대소 비교와 같습니다(길이가 같을 때).
+pub fn ct_cmp<T: ConstantTimeOrd>(a: &[T], b: &[T]) -> CtOrdering {
+    let mut decided = Choice::from_mask_normalized(0);
+    let mut less = Choice::from_mask_normalized(0);
+    let mut greater = Choice::from_mask_normalized(0);
+
+    for (x, y) in a.iter().zip(b) {
+        let differs = x.ct_ne(y);
+        let take = !decided & differs;
+        let is_ge = x.ct_is_ge(y);
+        less = less | (take & !is_ge);
+        greater = greater | (take & is_ge);
+        decided = decided | differs;
+    }
+
+    // 공통 구간이 동일한 경우에만 공개된 길이 차이를 반영
+    let len_less = Choice::from_mask_normalized((a.len() < b.len()) as u8);
+    let len_greater = Choice::from_mask_normalized((a.len() > b.len()) as u8);
+    CtOrdering::from_choices(
+        less | (!decided & len_less),
+        greater | (!decided & len_greater),
+    )
+}
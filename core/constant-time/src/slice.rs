@@ -0,0 +1,76 @@
+use crate::choice::Choice;
+use crate::ordering::ct_cmp;
+use crate::traits::{ConstantTimeEq, ConstantTimeOrd, ConstantTimeSelect, ConstantTimeSwap};
+
+/// 슬라이스에 대한 상수-시간 비교 구현입니다.
+///
+/// 길이는 공개 정보로 취급하여 일반 분기로 처리하며, 내용 비교는 길이가 같은 경우
+/// 전체 원소를 누적 AND로 스캔하므로 불일치 위치에 따른 조기 종료가 없습니다.
+impl<T: ConstantTimeEq> ConstantTimeEq for [T] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // 길이는 공개 정보이므로 일반 분기 허용
+        if self.len() != other.len() {
+            return Choice::from_mask_normalized(0);
+        }
+
+        let mut is_equal = Choice::from_mask_normalized(0xFF);
+        for (x, y) in self.iter().zip(other) {
+            is_equal = is_equal & x.ct_eq(y);
+        }
+        is_equal
+    }
+}
+
+impl<T: ConstantTimeOrd> ConstantTimeOrd for [T] {
+    /// 사전식(Lexicographic) 순서로 `self >= other`를 판별합니다. [`ct_cmp`] 참조
+    #[inline]
+    fn ct_is_ge(&self, other: &Self) -> Choice {
+        ct_cmp(self, other).is_ge()
+    }
+}
+
+/// 슬라이스의 조건부 교환입니다.
+///
+/// 두 슬라이스의 길이가 다르면 교환이 정의되지 않으므로 패닉을 발생시킵니다. (길이는 공개 정보)
+impl<T: ConstantTimeSwap> ConstantTimeSwap for [T] {
+    #[inline]
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        assert_eq!(a.len(), b.len(), "ct_swap requires slices of equal length");
+        for (x, y) in a.iter_mut().zip(b.iter_mut()) {
+            T::ct_swap(x, y, choice);
+        }
+    }
+}
+
+impl<T: ConstantTimeEq, const N: usize> ConstantTimeEq for [T; N] {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.as_slice().ct_eq(other.as_slice())
+    }
+}
+
+impl<T: ConstantTimeOrd, const N: usize> ConstantTimeOrd for [T; N] {
+    #[inline]
+    fn ct_is_ge(&self, other: &Self) -> Choice {
+        self.as_slice().ct_is_ge(other.as_slice())
+    }
+}
+
+/// 고정 길이 배열의 조건부 선택입니다.
+///
+/// `ct_select`는 결과를 값으로 반환하므로 크기가 정해지지 않은 `[T]`에는 구현할 수 없으며,
+/// 가변 길이 데이터는 [`ConstantTimeSwap`]으로 제자리에서 처리합니다.
+impl<T: ConstantTimeSelect, const N: usize> ConstantTimeSelect for [T; N] {
+    #[inline]
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        core::array::from_fn(|i| T::ct_select(&a[i], &b[i], choice))
+    }
+}
+
+impl<T: ConstantTimeSwap, const N: usize> ConstantTimeSwap for [T; N] {
+    #[inline]
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        <[T]>::ct_swap(a.as_mut_slice(), b.as_mut_slice(), choice);
+    }
+}
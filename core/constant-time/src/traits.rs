@@ -11,8 +11,51 @@ pub trait ConstantTimeEq {
     fn ct_ne(&self, other: &Self) -> Choice {
         self.ct_eq(other).choice_not()
     }
+}
 
+/// 두 데이터의 대소 관계를 상수-시간으로 판별합니다.
+///
+/// 구현체는 `ct_is_ge`만 제공하면 되며, 나머지 비교와 `ct_min`/`ct_max`는 이를 조합한
+/// 기본 구현을 사용합니다. 모든 결과는 분기 없이 `Choice`로 반환됩니다.
+pub trait ConstantTimeOrd: ConstantTimeEq {
+    /// `self >= other`이면 Choice(0xFF)를 반환합니다.
     fn ct_is_ge(&self, other: &Self) -> Choice;
+
+    /// `self > other`이면 Choice(0xFF)를 반환합니다.
+    #[inline(always)]
+    fn ct_is_gt(&self, other: &Self) -> Choice {
+        other.ct_is_ge(self).choice_not()
+    }
+
+    /// `self < other`이면 Choice(0xFF)를 반환합니다.
+    #[inline(always)]
+    fn ct_is_lt(&self, other: &Self) -> Choice {
+        self.ct_is_ge(other).choice_not()
+    }
+
+    /// `self <= other`이면 Choice(0xFF)를 반환합니다.
+    #[inline(always)]
+    fn ct_is_le(&self, other: &Self) -> Choice {
+        other.ct_is_ge(self)
+    }
+
+    /// 두 값 중 작은 값을 분기 없이 반환합니다.
+    #[inline(always)]
+    fn ct_min(&self, other: &Self) -> Self
+    where
+        Self: ConstantTimeSelect,
+    {
+        Self::ct_select(self, other, self.ct_is_le(other))
+    }
+
+    /// 두 값 중 큰 값을 분기 없이 반환합니다.
+    #[inline(always)]
+    fn ct_max(&self, other: &Self) -> Self
+    where
+        Self: ConstantTimeSelect,
+    {
+        Self::ct_select(self, other, self.ct_is_ge(other))
+    }
 }
 
 /// 조건에 따라 두 값 중 하나를 상수-시간으로 선택합니다.
@@ -24,7 +67,9 @@ pub trait ConstantTimeSelect: Sized {
 
 /// 조건에 따라 두 변수의 값을 상수-시간으로 교환(Swap)합니다.
 /// Montgomery Ladder와 같은 암호화 알고리즘에 필수적입니다.
-pub trait ConstantTimeSwap: Sized {
+///
+/// 값을 제자리에서 교환하므로 슬라이스(`[T]`)처럼 크기가 정해지지 않은 타입에도 구현될 수 있습니다.
+pub trait ConstantTimeSwap {
     /// choice가 True(0xFF)이면 `a`와 `b`의 값을 교환하고, False(0x00)이면 그대로 둡니다.
     fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice);
 }
@@ -0,0 +1,138 @@
+use super::Uint;
+use crate::choice::Choice;
+use crate::traits::ConstantTimeSelect;
+
+/// 림 단위 덧셈 `a + b + carry`를 수행하여 (합, 올림)을 반환합니다. 올림은 0 또는 1입니다.
+#[inline(always)]
+pub(crate) const fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+/// 림 단위 뺄셈 `a - b - borrow`를 수행하여 (차, 빌림)을 반환합니다. 빌림은 0 또는 1입니다.
+#[inline(always)]
+pub(crate) const fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+/// 림 단위 곱셈-누산 `a + b * c + carry`를 수행하여 (하위 림, 상위 림)을 반환합니다.
+///
+/// 결과는 최대 `2^128 - 1`이므로 128비트 중간값에서 넘치지 않습니다.
+#[inline(always)]
+pub(crate) const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + (b as u128) * (c as u128) + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// `self + rhs + carry`를 계산하여 (합, 올림)을 반환합니다. `carry`는 0 또는 1이어야 합니다.
+    #[inline]
+    pub const fn adc(&self, rhs: &Self, mut carry: u64) -> (Self, u64) {
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], carry) = adc(self.limbs[i], rhs.limbs[i], carry);
+            i += 1;
+        }
+        (Self { limbs }, carry)
+    }
+
+    /// `self - rhs - borrow`를 계산하여 (차, 빌림)을 반환합니다. `borrow`는 0 또는 1이어야 합니다.
+    ///
+    /// 최종 빌림이 1이면 `self < rhs + borrow`를 의미합니다.
+    #[inline]
+    pub const fn sbb(&self, rhs: &Self, mut borrow: u64) -> (Self, u64) {
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            (limbs[i], borrow) = sbb(self.limbs[i], rhs.limbs[i], borrow);
+            i += 1;
+        }
+        (Self { limbs }, borrow)
+    }
+
+    /// `2^BITS`를 법으로 하는 덧셈입니다.
+    #[inline]
+    pub const fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.adc(rhs, 0).0
+    }
+
+    /// `2^BITS`를 법으로 하는 뺄셈입니다.
+    #[inline]
+    pub const fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.sbb(rhs, 0).0
+    }
+
+    /// `(self + rhs) mod modulus`를 계산합니다.
+    ///
+    /// # Preconditions
+    /// `self`와 `rhs`는 모두 `modulus`보다 작아야 합니다.
+    #[inline]
+    pub fn add_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let (sum, carry) = self.adc(rhs, 0);
+        let (reduced, borrow) = sum.sbb(modulus, 0);
+        // 합이 2^BITS를 넘었거나(carry) 법 이상이면(!borrow) 감산 결과를 선택
+        let use_reduced = Choice::from_mask_normalized((carry | (borrow ^ 1)) as u8);
+        Self::ct_select(&reduced, &sum, use_reduced)
+    }
+
+    /// `(self - rhs) mod modulus`를 계산합니다.
+    ///
+    /// # Preconditions
+    /// `self`와 `rhs`는 모두 `modulus`보다 작아야 합니다.
+    #[inline]
+    pub fn sub_mod(&self, rhs: &Self, modulus: &Self) -> Self {
+        let (diff, borrow) = self.sbb(rhs, 0);
+        // 빌림이 발생한 경우에만 법을 다시 더함 (마스크 연산)
+        diff.wrapping_add(&modulus.mask(borrow))
+    }
+
+    /// `-self mod modulus`를 계산합니다.
+    ///
+    /// # Preconditions
+    /// `self`는 `modulus`보다 작아야 합니다.
+    #[inline]
+    pub fn neg_mod(&self, modulus: &Self) -> Self {
+        Self::ZERO.sub_mod(self, modulus)
+    }
+
+    /// 홀수 법에서 `self / 2 mod modulus`를 계산합니다.
+    ///
+    /// `self`가 홀수이면 법을 더한 뒤 이동하며, 덧셈의 올림은 최상위 비트로 되돌려 넣습니다.
+    #[inline]
+    pub(crate) fn halve_mod(&self, modulus: &Self) -> Self {
+        let (sum, carry) = self.adc(&modulus.mask(self.limbs[0] & 1), 0);
+        sum.shr1(carry)
+    }
+
+    /// `top`(0 또는 1)을 최상위 비트로 채우며 오른쪽으로 한 비트 이동합니다.
+    #[inline]
+    pub(crate) const fn shr1(&self, top: u64) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            let next = if i + 1 < LIMBS {
+                self.limbs[i + 1]
+            } else {
+                top
+            };
+            limbs[i] = (self.limbs[i] >> 1) | (next << 63);
+            i += 1;
+        }
+        Self { limbs }
+    }
+
+    /// `bit`(0 또는 1)가 1이면 `self`를, 0이면 0을 반환합니다.
+    #[inline(always)]
+    pub(crate) const fn mask(&self, bit: u64) -> Self {
+        let mask = bit.wrapping_neg();
+        let mut limbs = [0u64; LIMBS];
+        let mut i = 0;
+        while i < LIMBS {
+            limbs[i] = self.limbs[i] & mask;
+            i += 1;
+        }
+        Self { limbs }
+    }
+}
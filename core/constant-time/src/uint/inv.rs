@@ -0,0 +1,124 @@
+use super::Uint;
+use super::arith::{adc, sbb};
+use crate::choice::Choice;
+use crate::option::CtOption;
+use crate::traits::{ConstantTimeEq, ConstantTimeIsNegative, ConstantTimeSelect, ConstantTimeSwap};
+
+/// divstep 상태를 담는 `(64·LIMBS + 64)`비트 2의 보수 부호 있는 정수입니다.
+///
+/// divstep 과정에서 `|f|`, `|g|`는 초기값의 최댓값을 넘지 않으므로 부호용 림 하나로 충분합니다.
+#[derive(Clone, Copy)]
+struct Signed<const LIMBS: usize> {
+    lo: Uint<LIMBS>,
+    hi: u64,
+}
+
+impl<const LIMBS: usize> Signed<LIMBS> {
+    #[inline(always)]
+    fn from_uint(value: &Uint<LIMBS>) -> Self {
+        Self { lo: *value, hi: 0 }
+    }
+
+    #[inline(always)]
+    fn add(&self, rhs: &Self) -> Self {
+        let (lo, carry) = self.lo.adc(&rhs.lo, 0);
+        let (hi, _) = adc(self.hi, rhs.hi, carry);
+        Self { lo, hi }
+    }
+
+    #[inline(always)]
+    fn neg(&self) -> Self {
+        let (lo, borrow) = Uint::ZERO.sbb(&self.lo, 0);
+        let (hi, _) = sbb(0, self.hi, borrow);
+        Self { lo, hi }
+    }
+
+    /// 산술 오른쪽 시프트 (부호 유지)
+    #[inline(always)]
+    fn shr1(&self) -> Self {
+        Self {
+            lo: self.lo.shr1(self.hi & 1),
+            hi: ((self.hi as i64) >> 1) as u64,
+        }
+    }
+
+    #[inline(always)]
+    fn is_negative(&self) -> Choice {
+        self.hi.ct_is_negative()
+    }
+
+    /// 값이 `1` 또는 `-1`이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    fn is_unit(&self) -> Choice {
+        let is_one = self.lo.ct_eq(&Uint::ONE) & self.hi.ct_eq(&0);
+        let is_minus_one = self.lo.ct_eq(&Uint::MAX) & self.hi.ct_eq(&u64::MAX);
+        is_one | is_minus_one
+    }
+
+    #[inline(always)]
+    fn select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            lo: Uint::ct_select(&a.lo, &b.lo, choice),
+            hi: u64::ct_select(&a.hi, &b.hi, choice),
+        }
+    }
+
+    #[inline(always)]
+    fn swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        Uint::ct_swap(&mut a.lo, &mut b.lo, choice);
+        u64::ct_swap(&mut a.hi, &mut b.hi, choice);
+    }
+}
+
+/// `f`, `g`가 모두 `2^bits` 미만일 때 `g = 0`에 도달하기 위한 divstep 반복 횟수의 상한입니다.
+///
+/// Bernstein–Yang, "Fast constant-time gcd computation and modular inversion" 정리 11.2
+const fn divstep_iterations(bits: usize) -> usize {
+    if bits < 46 {
+        (49 * bits + 80) / 17
+    } else {
+        (49 * bits + 57) / 17
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Bernstein–Yang divstep 알고리즘으로 모듈러 역원 `self^{-1} mod modulus`를 계산합니다.
+    ///
+    /// 반복 횟수는 비트 폭으로부터 정해지는 상한으로 고정되며, 각 단계의 분기는
+    /// 조건부 교환과 마스크 덧셈으로 대체되어 입력 값에 따른 타이밍 차이가 없습니다.
+    /// 본 구현은 divstep을 한 단계씩 전체 폭으로 수행하며, 여러 단계를 행렬로 묶는 배치 최적화는
+    /// 적용하지 않았습니다.
+    ///
+    /// # Returns
+    /// 법이 1보다 큰 홀수이고 `gcd(self, modulus) = 1`인 경우에만 유효한 `CtOption`을 반환합니다.
+    /// `self`는 법보다 커도 됩니다.
+    pub fn inv_mod(&self, modulus: &Self) -> CtOption<Self> {
+        // 불변 조건: f ≡ d·self, g ≡ e·self (mod modulus)
+        let mut f = Signed::from_uint(modulus);
+        let mut g = Signed::from_uint(self);
+        let mut d = Self::ZERO;
+        let mut e = Self::ONE;
+        let mut delta: i64 = 1;
+
+        for _ in 0..divstep_iterations(Self::BITS) {
+            // δ > 0 이고 g가 홀수이면 (f, g, δ) ← (g, -f, -δ), (d, e) ← (e, -d)
+            let swap = delta.wrapping_neg().ct_is_negative() & g.lo.is_odd();
+            Signed::swap(&mut f, &mut g, swap);
+            g = Signed::select(&g.neg(), &g, swap);
+            Self::ct_swap(&mut d, &mut e, swap);
+            e = Self::ct_select(&e.neg_mod(modulus), &e, swap);
+            delta = i64::ct_select(&delta.wrapping_neg(), &delta, swap);
+
+            // g ← (g + [g 홀수]·f) / 2, e ← (e + [g 홀수]·d) / 2, δ ← 1 + δ
+            let g_odd = g.lo.is_odd();
+            g = Signed::select(&g.add(&f), &g, g_odd).shr1();
+            e = Self::ct_select(&e.add_mod(&d, modulus), &e, g_odd).halve_mod(modulus);
+            delta = delta.wrapping_add(1);
+        }
+
+        // 종료 시 g = 0, f = ±gcd 이므로 역원은 sign(f)·d
+        let inverse = Self::ct_select(&d.neg_mod(modulus), &d, f.is_negative());
+        let is_some = modulus.is_odd() & !modulus.ct_eq(&Self::ONE) & f.is_unit();
+        CtOption::new(inverse, is_some)
+    }
+}
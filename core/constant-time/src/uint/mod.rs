@@ -0,0 +1,155 @@
+//! 고정 폭 다중 정밀도(Multi-Precision) 부호 없는 정수 모듈입니다.
+//!
+//! RSA, ECC, 격자 기반 알고리즘의 공통 기반으로, 모든 연산은 피연산자의 값과 무관하게
+//! 동일한 명령어 흐름을 따릅니다. 분기와 반복 횟수는 공개 정보인 림(limb) 개수에만 의존합니다.
+
+mod arith;
+mod inv;
+mod monty;
+mod mul;
+
+pub use monty::MontyParams;
+pub use mul::KARATSUBA_THRESHOLD;
+
+use crate::choice::Choice;
+use crate::traits::{
+    ConstantTimeEq, ConstantTimeIsZero, ConstantTimeOrd, ConstantTimeSelect, ConstantTimeSwap,
+};
+
+/// 64비트 림 `LIMBS`개로 구성된 고정 폭 부호 없는 정수입니다.
+///
+/// 림은 리틀엔디안 순서(`limbs[0]`이 최하위)로 저장되며, `LIMBS`는 1 이상이어야 합니다.
+///
+/// # Security Note
+/// 값 비교와 선택은 [`ConstantTimeEq`], [`ConstantTimeSelect`]로만 수행해야 합니다.
+/// `Debug` 출력은 림 값을 가리며, 값 확인이 필요하면 [`as_limbs`](Self::as_limbs)를 사용합니다.
+#[derive(Clone, Copy)]
+pub struct Uint<const LIMBS: usize> {
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> core::fmt::Debug for Uint<LIMBS> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Uint<{}>([REDACTED])", LIMBS)
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 전체 비트 수
+    pub const BITS: usize = 64 * LIMBS;
+    /// 전체 바이트 수
+    pub const BYTES: usize = 8 * LIMBS;
+    /// 0
+    pub const ZERO: Self = Self::from_u64(0);
+    /// 1
+    pub const ONE: Self = Self::from_u64(1);
+    /// 표현 가능한 최댓값 (`2^BITS - 1`)
+    pub const MAX: Self = Self {
+        limbs: [u64::MAX; LIMBS],
+    };
+
+    /// 리틀엔디안 림 배열로부터 정수를 구성합니다.
+    #[inline(always)]
+    pub const fn from_limbs(limbs: [u64; LIMBS]) -> Self {
+        Self { limbs }
+    }
+
+    /// 단일 `u64` 값으로부터 정수를 구성합니다.
+    #[inline(always)]
+    pub const fn from_u64(value: u64) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        limbs[0] = value;
+        Self { limbs }
+    }
+
+    /// 리틀엔디안 림 배열을 반환합니다.
+    #[inline(always)]
+    pub const fn as_limbs(&self) -> &[u64; LIMBS] {
+        &self.limbs
+    }
+
+    /// 빅엔디안 바이트열로부터 정수를 구성합니다.
+    ///
+    /// # Panics
+    /// `bytes`의 길이가 [`Self::BYTES`]와 다르면 패닉이 발생합니다. (길이는 공개 정보)
+    pub fn from_be_slice(bytes: &[u8]) -> Self {
+        assert_eq!(bytes.len(), Self::BYTES, "Uint byte length mismatch");
+        let mut limbs = [0u64; LIMBS];
+        for (limb, chunk) in limbs.iter_mut().zip(bytes.rchunks_exact(8)) {
+            let mut word = [0u8; 8];
+            word.copy_from_slice(chunk);
+            *limb = u64::from_be_bytes(word);
+        }
+        Self { limbs }
+    }
+
+    /// 정수를 빅엔디안 바이트열로 `out`에 기록합니다.
+    ///
+    /// # Panics
+    /// `out`의 길이가 [`Self::BYTES`]와 다르면 패닉이 발생합니다. (길이는 공개 정보)
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), Self::BYTES, "Uint byte length mismatch");
+        for (limb, chunk) in self.limbs.iter().zip(out.rchunks_exact_mut(8)) {
+            chunk.copy_from_slice(&limb.to_be_bytes());
+        }
+    }
+
+    /// `index`번째 비트(0이 최하위)가 1이면 `Choice(0xFF)`를 반환합니다.
+    ///
+    /// 비트 위치는 공개 정보로 취급합니다.
+    ///
+    /// # Panics
+    /// `index`가 [`Self::BITS`] 이상이면 패닉이 발생합니다.
+    #[inline(always)]
+    pub fn bit(&self, index: usize) -> Choice {
+        let bit = (self.limbs[index / 64] >> (index % 64)) & 1;
+        Choice::from_mask_normalized(bit as u8)
+    }
+
+    /// 값이 홀수이면 `Choice(0xFF)`를 반환합니다.
+    #[inline(always)]
+    pub fn is_odd(&self) -> Choice {
+        self.bit(0)
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeEq for Uint<LIMBS> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        let mut diff = 0u64;
+        for (a, b) in self.limbs.iter().zip(&other.limbs) {
+            diff |= a ^ b;
+        }
+        diff.ct_eq(&0)
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeOrd for Uint<LIMBS> {
+    /// 정수 전체의 대소 비교(`self >= other`)를 뺄셈의 최종 빌림(Borrow)으로 판별합니다.
+    #[inline]
+    fn ct_is_ge(&self, other: &Self) -> Choice {
+        let (_, borrow) = self.sbb(other, 0);
+        borrow.ct_eq(&0)
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeSelect for Uint<LIMBS> {
+    #[inline]
+    fn ct_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::from_limbs(<[u64; LIMBS]>::ct_select(&a.limbs, &b.limbs, choice))
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeSwap for Uint<LIMBS> {
+    #[inline]
+    fn ct_swap(a: &mut Self, b: &mut Self, choice: Choice) {
+        <[u64; LIMBS]>::ct_swap(&mut a.limbs, &mut b.limbs, choice);
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeIsZero for Uint<LIMBS> {
+    #[inline]
+    fn ct_is_zero(&self) -> Choice {
+        self.ct_eq(&Self::ZERO)
+    }
+}
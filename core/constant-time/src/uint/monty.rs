@@ -0,0 +1,159 @@
+use super::Uint;
+use super::arith::{adc, mac};
+use crate::choice::Choice;
+use crate::option::CtOption;
+use crate::traits::{ConstantTimeEq, ConstantTimeSelect};
+
+/// 홀수 법 `n`에 대한 Montgomery 연산 매개변수입니다.
+///
+/// `R = 2^BITS`일 때 Montgomery 형식은 `x·R mod n`이며, 곱셈 결과의 환원(REDC)을
+/// 나눗셈 없이 림 단위 곱셈-누산과 한 번의 조건부 감산으로 수행합니다.
+#[derive(Clone, Copy, Debug)]
+pub struct MontyParams<const LIMBS: usize> {
+    modulus: Uint<LIMBS>,
+    /// `R mod n` (Montgomery 형식의 1)
+    one: Uint<LIMBS>,
+    /// `R^2 mod n` (Montgomery 형식 변환용)
+    r2: Uint<LIMBS>,
+    /// `-n^{-1} mod 2^64`
+    n_inv: u64,
+}
+
+impl<const LIMBS: usize> MontyParams<LIMBS> {
+    /// 법 `modulus`에 대한 매개변수를 계산합니다.
+    ///
+    /// 법이 홀수이고 1보다 큰 경우에만 유효한 `CtOption`을 반환합니다.
+    /// 유효하지 않은 법에서도 동일한 연산을 수행하며 패닉은 발생하지 않습니다.
+    pub fn new(modulus: &Uint<LIMBS>) -> CtOption<Self> {
+        let is_valid = modulus.is_odd() & !modulus.ct_eq(&Uint::ONE);
+
+        // R mod n과 R^2 mod n을 2배 모듈러 덧셈의 반복으로 계산 (반복 횟수는 공개된 비트 수)
+        let mut x = Uint::ONE;
+        for _ in 0..Uint::<LIMBS>::BITS {
+            x = x.add_mod(&x, modulus);
+        }
+        let one = x;
+        for _ in 0..Uint::<LIMBS>::BITS {
+            x = x.add_mod(&x, modulus);
+        }
+
+        let params = Self {
+            modulus: *modulus,
+            one,
+            r2: x,
+            n_inv: inv_mod_word(modulus.as_limbs()[0]).wrapping_neg(),
+        };
+        CtOption::new(params, is_valid)
+    }
+
+    /// 법을 반환합니다.
+    #[inline(always)]
+    pub fn modulus(&self) -> &Uint<LIMBS> {
+        &self.modulus
+    }
+
+    /// Montgomery 형식의 1(`R mod n`)을 반환합니다.
+    #[inline(always)]
+    pub fn one(&self) -> &Uint<LIMBS> {
+        &self.one
+    }
+
+    /// `x`를 Montgomery 형식 `x·R mod n`으로 변환합니다. `x`는 법보다 커도 됩니다.
+    #[inline]
+    pub fn to_monty(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let (lo, hi) = x.widening_mul(&self.r2);
+        self.reduce(&lo, &hi)
+    }
+
+    /// Montgomery 형식 `x`를 일반 형식 `x·R^{-1} mod n`으로 되돌립니다.
+    #[inline]
+    pub fn from_monty(&self, x: &Uint<LIMBS>) -> Uint<LIMBS> {
+        self.reduce(x, &Uint::ZERO)
+    }
+
+    /// 두 Montgomery 형식 값의 곱 `a·b·R^{-1} mod n`을 계산합니다.
+    ///
+    /// # Preconditions
+    /// `a`와 `b`는 법보다 작아야 합니다.
+    #[inline]
+    pub fn monty_mul(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let (lo, hi) = a.widening_mul(b);
+        self.reduce(&lo, &hi)
+    }
+
+    /// 일반 형식 두 값의 모듈러 곱 `a·b mod n`을 계산합니다.
+    ///
+    /// # Preconditions
+    /// `a`와 `b`는 법보다 작아야 합니다.
+    #[inline]
+    pub fn mul_mod(&self, a: &Uint<LIMBS>, b: &Uint<LIMBS>) -> Uint<LIMBS> {
+        // REDC(a·b) = a·b·R^{-1}, 이후 R^2를 곱해 환원하면 a·b
+        self.monty_mul(&self.monty_mul(a, b), &self.r2)
+    }
+
+    /// 모듈러 거듭제곱 `base^exp mod n`을 계산합니다.
+    ///
+    /// 지수의 모든 비트에 대해 제곱과 곱셈을 항상 수행하고 결과를 `ct_select`로 고르므로,
+    /// 실행 시간은 지수의 값이나 해밍 가중치가 아닌 지수의 폭(`EXP_LIMBS`)에만 의존합니다.
+    /// `base`는 법보다 커도 됩니다.
+    pub fn pow<const EXP_LIMBS: usize>(
+        &self,
+        base: &Uint<LIMBS>,
+        exp: &Uint<EXP_LIMBS>,
+    ) -> Uint<LIMBS> {
+        let base = self.to_monty(base);
+        let mut acc = self.one;
+        for i in (0..Uint::<EXP_LIMBS>::BITS).rev() {
+            acc = self.monty_mul(&acc, &acc);
+            let product = self.monty_mul(&acc, &base);
+            acc = Uint::ct_select(&product, &acc, exp.bit(i));
+        }
+        self.from_monty(&acc)
+    }
+
+    /// Montgomery 환원(REDC): `(hi·2^BITS + lo)·R^{-1} mod n`을 계산합니다.
+    ///
+    /// # Preconditions
+    /// 입력은 `n·R`보다 작아야 합니다.
+    pub fn reduce(&self, lo: &Uint<LIMBS>, hi: &Uint<LIMBS>) -> Uint<LIMBS> {
+        let n = self.modulus.as_limbs();
+        let mut lower = *lo.as_limbs();
+        let mut upper = *hi.as_limbs();
+        let mut meta_carry = 0;
+
+        for i in 0..LIMBS {
+            // 최하위 림을 0으로 만드는 배수 u를 더함
+            let u = lower[i].wrapping_mul(self.n_inv);
+            let (_, mut carry) = mac(lower[i], u, n[0], 0);
+            for (j, &n_j) in n.iter().enumerate().skip(1) {
+                let idx = i + j;
+                // 인덱스는 공개 정보이므로 일반 분기 허용
+                if idx < LIMBS {
+                    (lower[idx], carry) = mac(lower[idx], u, n_j, carry);
+                } else {
+                    (upper[idx - LIMBS], carry) = mac(upper[idx - LIMBS], u, n_j, carry);
+                }
+            }
+            (upper[i], meta_carry) = adc(upper[i], carry, meta_carry);
+        }
+
+        // 결과는 2n 미만이므로 한 번의 조건부 감산으로 [0, n) 범위에 맞춤
+        let result = Uint::from_limbs(upper);
+        let (reduced, borrow) = result.sbb(&self.modulus, 0);
+        let use_reduced = Choice::from_mask_normalized((meta_carry | (borrow ^ 1)) as u8);
+        Uint::ct_select(&reduced, &result, use_reduced)
+    }
+}
+
+/// 홀수 `x`의 `2^64`에 대한 역원을 Newton 반복으로 계산합니다.
+///
+/// `x·x ≡ 1 (mod 8)`에서 출발하여 반복마다 정밀도가 두 배가 되므로 다섯 번이면 64비트에 도달합니다.
+const fn inv_mod_word(x: u64) -> u64 {
+    let mut inv = x;
+    let mut i = 0;
+    while i < 5 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(x.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv
+}
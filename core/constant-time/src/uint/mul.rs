@@ -0,0 +1,136 @@
+use super::Uint;
+use super::arith::{adc, mac, sbb};
+
+/// Karatsuba 곱셈으로 전환하는 최소 림 개수입니다.
+///
+/// 이보다 작거나 홀수 길이의 피연산자는 스쿨북(Schoolbook) 곱셈으로 처리합니다.
+/// 전환 여부는 공개 정보인 림 개수로만 결정되므로 타이밍에 비밀 정보가 반영되지 않습니다.
+pub const KARATSUBA_THRESHOLD: usize = 8;
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// 전체 곱 `self * rhs`를 (하위 절반, 상위 절반)으로 반환합니다.
+    ///
+    /// 림 개수가 [`KARATSUBA_THRESHOLD`] 이상이면 Karatsuba 곱셈을, 그 외에는 스쿨북 곱셈을 사용합니다.
+    #[inline]
+    pub fn widening_mul(&self, rhs: &Self) -> (Self, Self) {
+        if LIMBS >= KARATSUBA_THRESHOLD {
+            self.karatsuba_mul(rhs)
+        } else {
+            self.schoolbook_mul(rhs)
+        }
+    }
+
+    /// `2^BITS`를 법으로 하는 곱셈입니다.
+    #[inline]
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.widening_mul(rhs).0
+    }
+
+    /// 스쿨북 곱셈으로 전체 곱을 (하위 절반, 상위 절반)으로 반환합니다.
+    pub fn schoolbook_mul(&self, rhs: &Self) -> (Self, Self) {
+        let mut out = [[0u64; LIMBS]; 2];
+        schoolbook(&self.limbs, &rhs.limbs, out.as_flattened_mut());
+        (Self::from_limbs(out[0]), Self::from_limbs(out[1]))
+    }
+
+    /// Karatsuba 곱셈으로 전체 곱을 (하위 절반, 상위 절반)으로 반환합니다.
+    ///
+    /// 반으로 나눈 부분 곱 세 개를 재귀적으로 계산하며, 중간 합의 올림은 마스크로 보정하여
+    /// 분기 없이 처리합니다. 작업 공간은 스택에 `5 * LIMBS` 림만큼 확보합니다.
+    pub fn karatsuba_mul(&self, rhs: &Self) -> (Self, Self) {
+        let mut out = [[0u64; LIMBS]; 2];
+        let mut scratch = [[0u64; LIMBS]; 5];
+        karatsuba(
+            &self.limbs,
+            &rhs.limbs,
+            out.as_flattened_mut(),
+            scratch.as_flattened_mut(),
+        );
+        (Self::from_limbs(out[0]), Self::from_limbs(out[1]))
+    }
+}
+
+/// `out = a * b`를 스쿨북 방식으로 계산합니다. `out`의 길이는 `a.len() + b.len()`입니다.
+fn schoolbook(a: &[u64], b: &[u64], out: &mut [u64]) {
+    out.fill(0);
+    for (i, &x) in a.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &y) in b.iter().enumerate() {
+            (out[i + j], carry) = mac(out[i + j], x, y, carry);
+        }
+        out[i + b.len()] = carry;
+    }
+}
+
+/// `out = a * b`를 Karatsuba 방식으로 계산합니다.
+///
+/// `a`, `b`는 길이 `n`, `out`은 길이 `2n`이며, `scratch`는 최소 `4n + log2(n)` 림이어야 합니다.
+fn karatsuba(a: &[u64], b: &[u64], out: &mut [u64], scratch: &mut [u64]) {
+    let n = a.len();
+    if n < KARATSUBA_THRESHOLD || !n.is_multiple_of(2) {
+        schoolbook(a, b, out);
+        return;
+    }
+
+    let h = n / 2;
+    let (a0, a1) = a.split_at(h);
+    let (b0, b1) = b.split_at(h);
+
+    // z0 = a0 * b0, z2 = a1 * b1
+    {
+        let (z0, z2) = out.split_at_mut(n);
+        karatsuba(a0, b0, z0, scratch);
+        karatsuba(a1, b1, z2, scratch);
+    }
+
+    // (a0 + a1), (b0 + b1)와 각 올림
+    let (sums, rest) = scratch.split_at_mut(n);
+    let (sa, sb) = sums.split_at_mut(h);
+    let ca = add_into(a0, a1, sa);
+    let cb = add_into(b0, b1, sb);
+
+    // mid = (sa + ca·B^h)(sb + cb·B^h)를 n + 1 림으로 계산 (B = 2^64)
+    let (mid, rest) = rest.split_at_mut(n + 1);
+    karatsuba(sa, sb, &mut mid[..n], rest);
+    mid[n] = ca & cb;
+    add_masked_at(mid, h, sb, ca.wrapping_neg());
+    add_masked_at(mid, h, sa, cb.wrapping_neg());
+
+    // z1 = mid - z0 - z2 (항상 0 이상)
+    sub_assign(mid, &out[..n]);
+    sub_assign(mid, &out[n..]);
+
+    // out += z1 · B^h (곱 전체가 2n 림에 들어가므로 최종 올림은 항상 0)
+    let mut carry = 0;
+    for (i, limb) in out[h..].iter_mut().enumerate() {
+        let addend = if i < mid.len() { mid[i] } else { 0 };
+        (*limb, carry) = adc(*limb, addend, carry);
+    }
+}
+
+/// `out = x + y`를 계산하고 올림(0 또는 1)을 반환합니다.
+fn add_into(x: &[u64], y: &[u64], out: &mut [u64]) -> u64 {
+    let mut carry = 0;
+    for ((o, &a), &b) in out.iter_mut().zip(x).zip(y) {
+        (*o, carry) = adc(a, b, carry);
+    }
+    carry
+}
+
+/// `acc += (x & mask) · B^offset`를 계산합니다. 올림은 `acc`의 끝까지 전파됩니다.
+fn add_masked_at(acc: &mut [u64], offset: usize, x: &[u64], mask: u64) {
+    let mut carry = 0;
+    for (i, limb) in acc[offset..].iter_mut().enumerate() {
+        let addend = if i < x.len() { x[i] & mask } else { 0 };
+        (*limb, carry) = adc(*limb, addend, carry);
+    }
+}
+
+/// `acc -= x`를 계산합니다. 빌림은 `acc`의 끝까지 전파됩니다.
+fn sub_assign(acc: &mut [u64], x: &[u64]) {
+    let mut borrow = 0;
+    for (i, limb) in acc.iter_mut().enumerate() {
+        let subtrahend = if i < x.len() { x[i] } else { 0 };
+        (*limb, borrow) = sbb(*limb, subtrahend, borrow);
+    }
+}
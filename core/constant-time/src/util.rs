@@ -0,0 +1,60 @@
+//! 비밀 인덱스 테이블 조회 및 조건부 복사/소거 유틸리티입니다.
+//!
+//! AES, ARIA의 S-box나 ECC의 사전 계산 점 테이블처럼 비밀 값으로 메모리를 참조해야 하는 경우,
+//! 직접 인덱싱(`table[secret]`)은 캐시 타이밍으로 인덱스를 노출합니다. 본 모듈의 함수는
+//! 항상 테이블 또는 버퍼 전체를 접근하고 선택을 `ct_select`로 수행합니다.
+
+use crate::choice::Choice;
+use crate::option::CtOption;
+use crate::traits::{ConstantTimeEq, ConstantTimeSelect};
+
+/// `table[secret_index]`를 상수-시간으로 조회합니다.
+///
+/// 인덱스와 무관하게 테이블의 모든 원소를 순서대로 읽고 `ct_select`로 누적하므로,
+/// 메모리 접근 패턴과 실행 시간이 인덱스에 의존하지 않습니다. 테이블 길이는 공개 정보입니다.
+///
+/// # Returns
+/// 인덱스가 범위를 벗어나면 무효 상태의 `CtOption`을 반환하며, 이 경우에도 전체 스캔은 동일하게 수행됩니다.
+///
+/// # Panics
+/// 테이블이 비어 있으면 패닉이 발생합니다.
+pub fn ct_lookup<T: ConstantTimeSelect + Clone>(table: &[T], secret_index: usize) -> CtOption<T> {
+    assert!(!table.is_empty(), "ct_lookup requires a non-empty table");
+
+    let mut result = table[0].clone();
+    let mut found = Choice::from_mask_normalized(0);
+    for (i, entry) in table.iter().enumerate() {
+        let hit = i.ct_eq(&secret_index);
+        result = T::ct_select(entry, &result, hit);
+        found = found | hit;
+    }
+    CtOption::new(result, found)
+}
+
+/// `choice`가 참이면 `src`를 `dst`에 복사하고, 거짓이면 `dst`를 그대로 둡니다.
+///
+/// 두 경우 모두 `dst`의 모든 원소에 동일한 쓰기가 발생합니다.
+///
+/// # Panics
+/// `dst`와 `src`의 길이가 다르면 패닉이 발생합니다. (길이는 공개 정보)
+pub fn ct_copy_if<T: ConstantTimeSelect>(choice: Choice, dst: &mut [T], src: &[T]) {
+    assert_eq!(
+        dst.len(),
+        src.len(),
+        "ct_copy_if requires slices of equal length"
+    );
+    for (d, s) in dst.iter_mut().zip(src) {
+        *d = T::ct_select(s, d, choice);
+    }
+}
+
+/// `choice`가 참이면 `dst`를 기본값(정수형의 경우 0)으로 채우고, 거짓이면 그대로 둡니다.
+///
+/// 실패한 복호화 결과처럼 조건에 따라 폐기해야 하는 버퍼를 분기 없이 소거하는 용도입니다.
+/// 무조건적인 메모리 파기는 `entlib-native-secure-buffer`의 `SecureZeroize`를 사용해야 합니다.
+pub fn ct_memzero_if<T: ConstantTimeSelect + Default>(choice: Choice, dst: &mut [T]) {
+    let zero = T::default();
+    for d in dst.iter_mut() {
+        *d = T::ct_select(&zero, d, choice);
+    }
+}
@@ -1,5 +1,8 @@
 use crate::choice::Choice;
+use crate::division::ct_div_rem_by_const;
+use crate::ordering::{CtOrdering, ct_cmp};
 use crate::traits::*;
+use crate::util::{ct_copy_if, ct_lookup, ct_memzero_if};
 
 #[unsafe(no_mangle)]
 #[inline(never)]
@@ -13,6 +16,36 @@ pub fn audit_verify_u64_ct_is_ge(a: &u64, b: &u64) -> Choice {
     a.ct_is_ge(b)
 }
 
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_u64_ct_is_gt(a: &u64, b: &u64) -> Choice {
+    a.ct_is_gt(b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_i64_ct_is_lt(a: &i64, b: &i64) -> Choice {
+    a.ct_is_lt(b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_u64_ct_min(a: &u64, b: &u64) -> u64 {
+    a.ct_min(b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_i64_ct_max(a: &i64, b: &i64) -> i64 {
+    a.ct_max(b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_u32_ct_div_rem_by_mldsa_gamma2(a: u32) -> (u32, u32) {
+    ct_div_rem_by_const::<523776>(a)
+}
+
 #[unsafe(no_mangle)]
 #[inline(never)]
 pub fn audit_verify_u64_ct_is_negative(a: &u64) -> Choice {
@@ -60,3 +93,39 @@ pub fn audit_verify_choice_not(choice: Choice) -> Choice {
 pub fn audit_verify_choice_unwrap_u8(choice: Choice) -> u8 {
     choice.unwrap_u8()
 }
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_bytes32_ct_eq(a: &[u8; 32], b: &[u8; 32]) -> Choice {
+    a.ct_eq(b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_bytes32_ct_select(a: &[u8; 32], b: &[u8; 32], choice: Choice) -> [u8; 32] {
+    <[u8; 32]>::ct_select(a, b, choice)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_bytes_ct_cmp(a: &[u8], b: &[u8]) -> CtOrdering {
+    ct_cmp(a, b)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_table16_ct_lookup(table: &[u8; 16], index: usize) -> u8 {
+    ct_lookup(table, index).unwrap_or(0)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_bytes16_ct_copy_if(choice: Choice, dst: &mut [u8; 16], src: &[u8; 16]) {
+    ct_copy_if(choice, dst, src)
+}
+
+#[unsafe(no_mangle)]
+#[inline(never)]
+pub fn audit_verify_bytes16_ct_memzero_if(choice: Choice, dst: &mut [u8; 16]) {
+    ct_memzero_if(choice, dst)
+}
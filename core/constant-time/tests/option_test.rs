@@ -0,0 +1,90 @@
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::option::CtOption;
+use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeSelect};
+
+fn truthy() -> Choice {
+    0u8.ct_eq(&0)
+}
+
+fn falsy() -> Choice {
+    0u8.ct_eq(&1)
+}
+
+#[test]
+fn some_and_none_states() {
+    let some = CtOption::new(7u32, truthy());
+    assert_eq!(some.is_some().unwrap_u8(), 0xFF);
+    assert_eq!(some.is_none().unwrap_u8(), 0x00);
+    assert_eq!(some.into_option(), Some(7));
+
+    let none = CtOption::new(7u32, falsy());
+    assert_eq!(none.is_some().unwrap_u8(), 0x00);
+    assert_eq!(none.is_none().unwrap_u8(), 0xFF);
+    assert_eq!(none.into_option(), None);
+}
+
+#[test]
+fn unwrap_or_selects_default_only_when_none() {
+    assert_eq!(CtOption::new(5u8, truthy()).unwrap_or(9), 5);
+    assert_eq!(CtOption::new(5u8, falsy()).unwrap_or(9), 9);
+}
+
+#[test]
+fn ct_map_always_runs_and_keeps_validity() {
+    let mut calls = 0;
+    let none = CtOption::new(3u16, falsy()).ct_map(|v| {
+        calls += 1;
+        v * 2
+    });
+    assert_eq!(calls, 1);
+    assert_eq!(none.into_option(), None);
+
+    let some = CtOption::new(3u16, truthy()).ct_map(|v| v * 2);
+    assert_eq!(some.into_option(), Some(6));
+}
+
+#[test]
+fn ct_and_then_combines_validity() {
+    let checked_half = |v: u8| CtOption::new(v / 2, (v & 1).ct_eq(&0));
+
+    assert_eq!(
+        CtOption::new(8u8, truthy())
+            .ct_and_then(checked_half)
+            .into_option(),
+        Some(4)
+    );
+    assert_eq!(
+        CtOption::new(7u8, truthy())
+            .ct_and_then(checked_half)
+            .into_option(),
+        None
+    );
+    assert_eq!(
+        CtOption::new(8u8, falsy())
+            .ct_and_then(checked_half)
+            .into_option(),
+        None
+    );
+}
+
+#[test]
+fn select_carries_value_and_validity() {
+    let a = CtOption::new([1u8, 2], truthy());
+    let b = CtOption::new([3u8, 4], falsy());
+
+    let picked_a = CtOption::ct_select(&a, &b, truthy());
+    assert_eq!(picked_a.into_option(), Some([1, 2]));
+
+    let picked_b = CtOption::ct_select(&a, &b, falsy());
+    assert_eq!(picked_b.is_none().unwrap_u8(), 0xFF);
+    assert_eq!(picked_b.unwrap_or([0, 0]), [0, 0]);
+}
+
+#[test]
+fn debug_output_redacts_value_and_validity() {
+    let some = CtOption::new(0xDEAD_BEEFu32, truthy());
+    let none = CtOption::new(0xDEAD_BEEFu32, falsy());
+    assert_eq!(format!("{:?}", some), "CtOption([REDACTED])");
+    assert_eq!(format!("{:?}", some), format!("{:?}", none));
+    assert!(!format!("{:?}", some).contains("3735928559"));
+}
@@ -0,0 +1,76 @@
+use entlib_native_constant_time::division::ct_div_rem_by_const;
+use entlib_native_constant_time::traits::ConstantTimeOrd;
+
+macro_rules! check_ord {
+    ($t:ty, $values:expr) => {{
+        let values: &[$t] = $values;
+        for &a in values {
+            for &b in values {
+                assert_eq!(a.ct_is_ge(&b).unwrap_u8() == 0xFF, a >= b, "{} >= {}", a, b);
+                assert_eq!(a.ct_is_gt(&b).unwrap_u8() == 0xFF, a > b, "{} > {}", a, b);
+                assert_eq!(a.ct_is_lt(&b).unwrap_u8() == 0xFF, a < b, "{} < {}", a, b);
+                assert_eq!(a.ct_is_le(&b).unwrap_u8() == 0xFF, a <= b, "{} <= {}", a, b);
+                assert_eq!(a.ct_min(&b), a.min(b));
+                assert_eq!(a.ct_max(&b), a.max(b));
+            }
+        }
+    }};
+}
+
+#[test]
+fn unsigned_ordering_matches_std() {
+    check_ord!(u8, &[0, 1, 0x7F, 0x80, 0xFE, u8::MAX]);
+    check_ord!(u16, &[0, 1, 0x7FFF, 0x8000, u16::MAX]);
+    check_ord!(u32, &[0, 1, 0x7FFF_FFFF, 0x8000_0000, u32::MAX]);
+    check_ord!(u64, &[0, 1, i64::MAX as u64, 1 << 63, u64::MAX]);
+    check_ord!(u128, &[0, 1, i128::MAX as u128, 1 << 127, u128::MAX]);
+    check_ord!(usize, &[0, 1, isize::MAX as usize, usize::MAX]);
+}
+
+#[test]
+fn signed_ordering_matches_std() {
+    check_ord!(i8, &[i8::MIN, -1, 0, 1, i8::MAX]);
+    check_ord!(i16, &[i16::MIN, -1, 0, 1, i16::MAX]);
+    check_ord!(i32, &[i32::MIN, -2, -1, 0, 1, i32::MAX]);
+    check_ord!(i64, &[i64::MIN, -1, 0, 1, i64::MAX]);
+    check_ord!(i128, &[i128::MIN, -1, 0, 1, i128::MAX]);
+    check_ord!(isize, &[isize::MIN, -1, 0, 1, isize::MAX]);
+}
+
+#[test]
+fn slice_ordering_uses_lexicographic_order() {
+    let a = [1u8, 2, 3];
+    let b = [1u8, 3, 0];
+    assert_eq!(a.ct_is_lt(&b).unwrap_u8(), 0xFF);
+    assert_eq!(b.ct_is_gt(&a).unwrap_u8(), 0xFF);
+    assert_eq!(a.ct_is_le(&a).unwrap_u8(), 0xFF);
+    assert_eq!(a.ct_max(&b), b);
+}
+
+fn check_div<const D: u32>() {
+    let mut samples = vec![0, 1, D - 1, D, D.wrapping_add(1), u32::MAX, u32::MAX - 1];
+    let mut x = 0x1234_5678u32;
+    for _ in 0..2000 {
+        x ^= x << 13;
+        x ^= x >> 17;
+        x ^= x << 5;
+        samples.push(x);
+        samples.push(x % (D.saturating_mul(16)).max(1));
+    }
+    for a in samples {
+        assert_eq!(ct_div_rem_by_const::<D>(a), (a / D, a % D), "{} / {}", a, D);
+    }
+}
+
+#[test]
+fn div_rem_by_const_matches_hardware_division() {
+    check_div::<1>();
+    check_div::<2>();
+    check_div::<3>();
+    check_div::<3329>();
+    check_div::<190464>();
+    check_div::<523776>();
+    check_div::<8380417>();
+    check_div::<0x8000_0001>();
+    check_div::<{ u32::MAX }>();
+}
@@ -0,0 +1,111 @@
+use core::cmp::Ordering;
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::ordering::ct_cmp;
+use entlib_native_constant_time::traits::{
+    ConstantTimeEq, ConstantTimeOrd, ConstantTimeSelect, ConstantTimeSwap,
+};
+
+fn truthy() -> Choice {
+    0u8.ct_eq(&0)
+}
+
+fn falsy() -> Choice {
+    0u8.ct_eq(&1)
+}
+
+#[test]
+fn slice_eq_checks_length_and_content() {
+    let a: &[u8] = &[1, 2, 3];
+    assert_eq!(a.ct_eq(&[1, 2, 3]).unwrap_u8(), 0xFF);
+    assert_eq!(a.ct_eq(&[1, 2, 4]).unwrap_u8(), 0x00);
+    assert_eq!(a.ct_eq(&[1, 2]).unwrap_u8(), 0x00);
+    assert_eq!(a.ct_ne(&[9, 2, 3]).unwrap_u8(), 0xFF);
+
+    let empty: &[u64] = &[];
+    assert_eq!(empty.ct_eq(&[]).unwrap_u8(), 0xFF);
+}
+
+#[test]
+fn array_eq_and_ge_are_big_endian() {
+    let a = [0x01u8, 0x00, 0xFF];
+    let b = [0x00u8, 0xFF, 0xFF];
+    assert_eq!(a.ct_eq(&a).unwrap_u8(), 0xFF);
+    assert_eq!(a.ct_eq(&b).unwrap_u8(), 0x00);
+    assert_eq!(a.ct_is_ge(&b).unwrap_u8(), 0xFF);
+    assert_eq!(b.ct_is_ge(&a).unwrap_u8(), 0x00);
+    assert_eq!(a.ct_is_ge(&a).unwrap_u8(), 0xFF);
+
+    let signed = [-1i32, 5];
+    assert_eq!(signed.ct_is_ge(&[-2, 9]).unwrap_u8(), 0xFF);
+}
+
+#[test]
+fn ct_cmp_matches_lexicographic_order() {
+    let cases: &[(&[u8], &[u8])] = &[
+        (&[], &[]),
+        (&[1], &[]),
+        (&[], &[0]),
+        (&[1, 2, 3], &[1, 2, 3]),
+        (&[1, 2, 3], &[1, 3, 0]),
+        (&[2, 0], &[1, 9, 9]),
+        (&[1, 2], &[1, 2, 0]),
+        (&[0xFF, 0x00], &[0x00, 0xFF]),
+    ];
+
+    for &(a, b) in cases {
+        let ordering = ct_cmp(a, b);
+        let expected = a.cmp(b);
+        assert_eq!(ordering.declassify(), expected, "{:?} vs {:?}", a, b);
+        assert_eq!(
+            ordering.is_lt().unwrap_u8() == 0xFF,
+            expected == Ordering::Less
+        );
+        assert_eq!(
+            ordering.is_eq().unwrap_u8() == 0xFF,
+            expected == Ordering::Equal
+        );
+        assert_eq!(
+            ordering.is_gt().unwrap_u8() == 0xFF,
+            expected == Ordering::Greater
+        );
+        assert_eq!(
+            ordering.is_le().unwrap_u8() == 0xFF,
+            expected != Ordering::Greater
+        );
+        assert_eq!(
+            ordering.is_ge().unwrap_u8() == 0xFF,
+            expected != Ordering::Less
+        );
+    }
+}
+
+#[test]
+fn array_select_and_swap() {
+    let a = [1u16, 2, 3];
+    let b = [7u16, 8, 9];
+    assert_eq!(<[u16; 3]>::ct_select(&a, &b, truthy()), a);
+    assert_eq!(<[u16; 3]>::ct_select(&a, &b, falsy()), b);
+
+    let (mut x, mut y) = (a, b);
+    <[u16; 3]>::ct_swap(&mut x, &mut y, falsy());
+    assert_eq!((x, y), (a, b));
+    <[u16; 3]>::ct_swap(&mut x, &mut y, truthy());
+    assert_eq!((x, y), (b, a));
+}
+
+#[test]
+fn slice_swap() {
+    let mut x = [1u8, 2, 3, 4];
+    let mut y = [5u8, 6, 7, 8];
+    <[u8]>::ct_swap(&mut x[1..3], &mut y[1..3], truthy());
+    assert_eq!(x, [1, 6, 7, 4]);
+    assert_eq!(y, [5, 2, 3, 8]);
+}
+
+#[test]
+#[should_panic(expected = "equal length")]
+fn slice_swap_rejects_length_mismatch() {
+    let mut x = [0u8; 2];
+    let mut y = [0u8; 3];
+    <[u8]>::ct_swap(&mut x, &mut y, truthy());
+}
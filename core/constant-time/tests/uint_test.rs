@@ -0,0 +1,233 @@
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::traits::{
+    ConstantTimeEq, ConstantTimeIsZero, ConstantTimeOrd, ConstantTimeSelect,
+};
+use entlib_native_constant_time::uint::{MontyParams, Uint};
+use num_bigint::BigUint;
+
+/// 재현 가능한 테스트 입력을 위한 SplitMix64 생성기
+struct SplitMix(u64);
+
+impl SplitMix {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn uint<const LIMBS: usize>(&mut self) -> Uint<LIMBS> {
+        Uint::from_limbs(core::array::from_fn(|_| self.next()))
+    }
+
+    fn odd_uint<const LIMBS: usize>(&mut self) -> Uint<LIMBS> {
+        let mut limbs: [u64; LIMBS] = core::array::from_fn(|_| self.next());
+        limbs[0] |= 1;
+        Uint::from_limbs(limbs)
+    }
+}
+
+fn big<const LIMBS: usize>(x: &Uint<LIMBS>) -> BigUint {
+    let mut bytes = vec![0u8; Uint::<LIMBS>::BYTES];
+    x.write_be_bytes(&mut bytes);
+    BigUint::from_bytes_be(&bytes)
+}
+
+fn wide<const LIMBS: usize>(lo: &Uint<LIMBS>, hi: &Uint<LIMBS>) -> BigUint {
+    (big(hi) << Uint::<LIMBS>::BITS) + big(lo)
+}
+
+fn truthy() -> Choice {
+    0u8.ct_eq(&0)
+}
+
+fn falsy() -> Choice {
+    0u8.ct_eq(&1)
+}
+
+#[test]
+fn byte_conversion_roundtrip() {
+    let bytes: Vec<u8> = (1..=32).collect();
+    let x = Uint::<4>::from_be_slice(&bytes);
+    assert_eq!(x.as_limbs()[3], 0x0102_0304_0506_0708);
+    assert_eq!(x.as_limbs()[0], 0x191A_1B1C_1D1E_1F20);
+
+    let mut out = [0u8; 32];
+    x.write_be_bytes(&mut out);
+    assert_eq!(out.as_slice(), bytes.as_slice());
+    assert_eq!(big(&x), BigUint::from_bytes_be(&bytes));
+}
+
+#[test]
+fn debug_output_redacts_limbs() {
+    let x = Uint::<2>::from_limbs([0xDEAD_BEEF, 0x1234_5678]);
+    let printed = format!("{:?}", x);
+    assert_eq!(printed, "Uint<2>([REDACTED])");
+    assert!(!printed.contains("3735928559"));
+}
+
+#[test]
+fn add_sub_with_carry_match_reference() {
+    let mut rng = SplitMix(1);
+    let modulus = BigUint::from(1u8) << Uint::<4>::BITS;
+    for _ in 0..200 {
+        let a = rng.uint::<4>();
+        let b = rng.uint::<4>();
+
+        let (sum, carry) = a.adc(&b, 1);
+        let expected = big(&a) + big(&b) + 1u8;
+        assert_eq!(big(&sum), &expected % &modulus);
+        assert_eq!(carry == 1, expected >= modulus);
+
+        let (diff, borrow) = a.sbb(&b, 0);
+        assert_eq!(borrow == 1, big(&a) < big(&b));
+        assert_eq!(big(&diff), (big(&a) + &modulus - big(&b)) % &modulus);
+    }
+
+    let (max_plus_one, carry) = Uint::<4>::MAX.adc(&Uint::ONE, 0);
+    assert_eq!(max_plus_one.ct_is_zero().unwrap_u8(), 0xFF);
+    assert_eq!(carry, 1);
+}
+
+#[test]
+fn comparison_and_select() {
+    let mut rng = SplitMix(2);
+    for _ in 0..200 {
+        let a = rng.uint::<3>();
+        let b = rng.uint::<3>();
+        assert_eq!(a.ct_eq(&a).unwrap_u8(), 0xFF);
+        assert_eq!(a.ct_eq(&b).unwrap_u8() == 0xFF, big(&a) == big(&b));
+        assert_eq!(a.ct_is_ge(&b).unwrap_u8() == 0xFF, big(&a) >= big(&b));
+        assert_eq!(b.ct_is_ge(&a).unwrap_u8() == 0xFF, big(&b) >= big(&a));
+
+        assert_eq!(big(&Uint::ct_select(&a, &b, truthy())), big(&a));
+        assert_eq!(big(&Uint::ct_select(&a, &b, falsy())), big(&b));
+    }
+}
+
+fn check_mul<const LIMBS: usize>(seed: u64) {
+    let mut rng = SplitMix(seed);
+    for _ in 0..50 {
+        let a = rng.uint::<LIMBS>();
+        let b = rng.uint::<LIMBS>();
+        let expected = big(&a) * big(&b);
+
+        let (lo, hi) = a.schoolbook_mul(&b);
+        assert_eq!(wide(&lo, &hi), expected);
+        let (lo, hi) = a.karatsuba_mul(&b);
+        assert_eq!(wide(&lo, &hi), expected);
+        let (lo, hi) = a.widening_mul(&b);
+        assert_eq!(wide(&lo, &hi), expected);
+    }
+
+    // 모든 중간 합에서 올림이 발생하는 경계값
+    let (lo, hi) = Uint::<LIMBS>::MAX.karatsuba_mul(&Uint::MAX);
+    assert_eq!(wide(&lo, &hi), big(&Uint::<LIMBS>::MAX).pow(2));
+}
+
+#[test]
+fn multiplication_matches_reference() {
+    check_mul::<1>(3);
+    check_mul::<4>(4);
+    check_mul::<8>(5);
+    check_mul::<12>(6);
+    check_mul::<16>(7);
+    check_mul::<32>(8);
+}
+
+fn check_monty<const LIMBS: usize>(seed: u64) {
+    let mut rng = SplitMix(seed);
+    for _ in 0..10 {
+        let modulus = rng.odd_uint::<LIMBS>();
+        let params = MontyParams::new(&modulus).into_option().unwrap();
+        let n = big(&modulus);
+
+        let a = rng.uint::<LIMBS>();
+        let b = rng.uint::<LIMBS>();
+        let exp = rng.uint::<2>();
+
+        let a_mod = params.from_monty(&params.to_monty(&a));
+        assert_eq!(big(&a_mod), big(&a) % &n);
+
+        let b_mod = params.from_monty(&params.to_monty(&b));
+        assert_eq!(big(&params.mul_mod(&a_mod, &b_mod)), big(&a) * big(&b) % &n);
+
+        assert_eq!(big(&params.pow(&a, &exp)), big(&a).modpow(&big(&exp), &n));
+    }
+}
+
+#[test]
+fn montgomery_arithmetic_matches_reference() {
+    check_monty::<1>(9);
+    check_monty::<4>(10);
+    check_monty::<8>(11);
+}
+
+#[test]
+fn montgomery_rejects_invalid_modulus() {
+    assert!(
+        MontyParams::new(&Uint::<4>::from_u64(10))
+            .into_option()
+            .is_none()
+    );
+    assert!(MontyParams::new(&Uint::<4>::ONE).into_option().is_none());
+    assert!(MontyParams::new(&Uint::<4>::ZERO).into_option().is_none());
+
+    let params = MontyParams::new(&Uint::<4>::from_u64(13))
+        .into_option()
+        .unwrap();
+    let exp = Uint::<1>::ZERO;
+    assert_eq!(
+        big(&params.pow(&Uint::from_u64(5), &exp)),
+        BigUint::from(1u8)
+    );
+}
+
+fn check_inv<const LIMBS: usize>(seed: u64) {
+    let mut rng = SplitMix(seed);
+    for _ in 0..20 {
+        let modulus = rng.odd_uint::<LIMBS>();
+        let x = rng.uint::<LIMBS>();
+        let n = big(&modulus);
+        let inverse = x.inv_mod(&modulus).into_option();
+
+        let gcd_is_one = {
+            let (mut a, mut b) = (big(&x) % &n, n.clone());
+            while a != BigUint::ZERO {
+                let r = &b % &a;
+                b = a;
+                a = r;
+            }
+            b == BigUint::from(1u8)
+        };
+        assert_eq!(inverse.is_some(), gcd_is_one);
+        if let Some(inverse) = inverse {
+            assert!(big(&inverse) < n);
+            assert_eq!(big(&inverse) * big(&x) % &n, BigUint::from(1u8));
+        }
+    }
+}
+
+#[test]
+fn modular_inversion_matches_reference() {
+    check_inv::<1>(12);
+    check_inv::<4>(13);
+    check_inv::<8>(14);
+}
+
+#[test]
+fn modular_inversion_rejects_non_units() {
+    let m = Uint::<2>::from_u64(15);
+    assert!(Uint::<2>::from_u64(5).inv_mod(&m).into_option().is_none());
+    assert!(Uint::<2>::ZERO.inv_mod(&m).into_option().is_none());
+    assert!(
+        Uint::<2>::from_u64(3)
+            .inv_mod(&Uint::from_u64(8))
+            .into_option()
+            .is_none()
+    );
+
+    let inverse = Uint::<2>::from_u64(7).inv_mod(&m).into_option().unwrap();
+    assert_eq!(inverse.as_limbs()[0], 13);
+}
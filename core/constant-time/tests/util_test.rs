@@ -0,0 +1,67 @@
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_constant_time::util::{ct_copy_if, ct_lookup, ct_memzero_if};
+
+fn truthy() -> Choice {
+    0u8.ct_eq(&0)
+}
+
+fn falsy() -> Choice {
+    0u8.ct_eq(&1)
+}
+
+#[test]
+fn lookup_returns_every_entry() {
+    let table: [u8; 256] = core::array::from_fn(|i| (i as u8).wrapping_mul(7) ^ 0x63);
+    for (i, &expected) in table.iter().enumerate() {
+        assert_eq!(ct_lookup(&table, i).into_option(), Some(expected));
+    }
+}
+
+#[test]
+fn lookup_supports_composite_entries() {
+    let points = [[1u64, 2], [3, 4], [5, 6]];
+    assert_eq!(ct_lookup(&points, 1).into_option(), Some([3, 4]));
+}
+
+#[test]
+fn lookup_out_of_range_is_none() {
+    let table = [10u32, 20, 30];
+    let result = ct_lookup(&table, 3);
+    assert_eq!(result.is_none().unwrap_u8(), 0xFF);
+    assert_eq!(result.unwrap_or(0), 0);
+    assert!(ct_lookup(&table, usize::MAX).into_option().is_none());
+}
+
+#[test]
+#[should_panic(expected = "non-empty")]
+fn lookup_rejects_empty_table() {
+    let table: [u8; 0] = [];
+    let _ = ct_lookup(&table, 0);
+}
+
+#[test]
+fn copy_if_follows_choice() {
+    let src = [9u8; 5];
+    let mut dst = [1u8, 2, 3, 4, 5];
+    ct_copy_if(falsy(), &mut dst, &src);
+    assert_eq!(dst, [1, 2, 3, 4, 5]);
+    ct_copy_if(truthy(), &mut dst, &src);
+    assert_eq!(dst, src);
+}
+
+#[test]
+#[should_panic(expected = "equal length")]
+fn copy_if_rejects_length_mismatch() {
+    let mut dst = [0u8; 4];
+    ct_copy_if(truthy(), &mut dst, &[0u8; 3]);
+}
+
+#[test]
+fn memzero_if_follows_choice() {
+    let mut buf = [0xAAu16; 8];
+    ct_memzero_if(falsy(), &mut buf);
+    assert_eq!(buf, [0xAA; 8]);
+    ct_memzero_if(truthy(), &mut buf);
+    assert_eq!(buf, [0; 8]);
+}
@@ -1,4 +1,6 @@
+use crate::simd::encode_hex_simd;
 use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::option::CtOption;
 use entlib_native_constant_time::traits::{
     ConstantTimeEq, ConstantTimeIsNegative, ConstantTimeSelect,
 };
@@ -36,6 +38,14 @@ pub(crate) fn encode_hex_core_ct(input: &[u8], output: &mut [u8]) {
         "Security Violation: Output buffer overflow"
     );
 
+    // SIMD 경로(AVX2/NEON)가 블록 단위로 처리한 접두부 이후의 꼬리만 스칼라 경로로 처리합니다.
+    let done = encode_hex_simd(input, output);
+    encode_hex_scalar_ct(&input[done..], &mut output[done * 2..]);
+}
+
+/// 스칼라 참조 구현입니다. SIMD 경로의 꼬리 처리와 `force_scalar` 기능에서 사용됩니다.
+#[inline(always)]
+fn encode_hex_scalar_ct(input: &[u8], output: &mut [u8]) {
     for (i, &byte) in input.iter().enumerate() {
         let high = (byte >> 4) & 0x0F;
         let low = byte & 0x0F;
@@ -48,11 +58,11 @@ pub(crate) fn encode_hex_core_ct(input: &[u8], output: &mut [u8]) {
 /// 단일 ASCII 문자를 상수-시간으로 0~15 사이의 니블(Nibble)로 디코딩합니다.
 ///
 /// # Returns
-/// (디코딩된 값, 유효성 여부를 나타내는 Choice) 튜플을 반환합니다.
-/// 문자가 유효하지 않더라도 연산 시간은 동일하며, 반환되는 Choice는 거짓(0x00)이 됩니다.
+/// 디코딩된 값을 담은 `CtOption`을 반환합니다.
+/// 문자가 유효하지 않더라도 연산 시간은 동일하며, 반환되는 `CtOption`은 무효(None) 상태가 됩니다.
 #[inline(always)]
 #[allow(non_snake_case)] // for a, A, f, F
-fn decode_nibble_ct(c: u8) -> (u8, Choice) {
+pub(crate) fn decode_nibble_ct(c: u8) -> CtOption<u8> {
     // 1. '0' ~ '9' (48 ~ 57) 판별
     // c < 48 이면 MSB가 1, 57 < c 이면 MSB가 1이 됨 (언더플로우 활용)
     let is_lt_0 = c.wrapping_sub(b'0').ct_is_negative();
@@ -82,11 +92,11 @@ fn decode_nibble_ct(c: u8) -> (u8, Choice) {
     // 6. 상수 시간 선택 (Constant-Time Select)
     // 기본값 0에서 시작하여 조건이 참(0xFF)일 때만 해당 값을 덮어씁니다.
     let mut result = 0u8;
-    result = u8::ct_select(&result, &val_digit, is_digit);
-    result = u8::ct_select(&result, &val_lower, is_lower_hex);
-    result = u8::ct_select(&result, &val_upper, is_upper_hex);
+    result = u8::ct_select(&val_digit, &result, is_digit);
+    result = u8::ct_select(&val_lower, &result, is_lower_hex);
+    result = u8::ct_select(&val_upper, &result, is_upper_hex);
 
-    (result, is_valid)
+    CtOption::new(result, is_valid)
 }
 
 /// 주어진 Hex 인코딩 슬라이스를 상수-시간으로 디코딩하여 출력 슬라이스에 작성합니다.
@@ -113,18 +123,14 @@ pub(crate) fn decode_hex_core_ct(input: &[u8], output: &mut [u8]) -> Choice {
     // 실제 디코딩 루프 (입력 길이의 절반만큼 무조건 수행)
     let iter_count = input.len() / 2;
     for i in 0..iter_count {
-        let (high_nibble, high_valid) = decode_nibble_ct(input[i * 2]);
-        let (low_nibble, low_valid) = decode_nibble_ct(input[i * 2 + 1]);
-
-        // & 연산자는 Choice에 대해 상수-시간 AND 연산을 수행합니다.
-        let byte_valid = high_valid & low_valid;
-        all_valid = all_valid & byte_valid;
-
-        // 바이트 조합
-        let decoded_byte = (high_nibble << 4) | low_nibble;
+        // 두 니블의 유효성은 ct_and_then을 통해 분기 없이 결합됩니다.
+        let low = decode_nibble_ct(input[i * 2 + 1]);
+        let decoded_byte =
+            decode_nibble_ct(input[i * 2]).ct_and_then(|high| low.ct_map(|low| (high << 4) | low));
+        all_valid = all_valid & decoded_byte.is_some();
 
         // 유효하지 않은 바이트인 경우 출력 버퍼에 0을 기록하여 쓰레기값 생성을 방지합니다.
-        output[i] = u8::ct_select(&0, &decoded_byte, byte_valid);
+        output[i] = decoded_byte.unwrap_or(0);
     }
 
     all_valid
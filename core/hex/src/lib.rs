@@ -1,6 +1,11 @@
 mod hex;
+mod simd;
+mod stream;
+
+pub use stream::{HexDecoder, HexEncoder};
 
 use crate::hex::{decode_hex_core_ct, encode_hex_core_ct};
+use entlib_native_constant_time::option::CtOption;
 use entlib_native_secure_buffer::SecureBuffer;
 
 /// 군사급 보안 요구사항을 충족하는 상수 시간 Hex 인코딩 함수입니다.
@@ -58,15 +63,14 @@ pub fn decode(input: &SecureBuffer) -> Result<SecureBuffer, &'static str> {
     let is_valid = decode_hex_core_ct(input_slice, output_buffer.as_mut_slice());
 
     // 4. 디코딩 성공 여부 검증 (상수-시간 영역 -> 일반 제어 흐름 영역)
-    // Choice::unwrap_u8()을 호출하여 결과가 0xFF(True)인지 확인합니다.
-    if is_valid.unwrap_u8() == 0xFF {
-        Ok(output_buffer)
-    } else {
-        // 5. 에러 발생 시의 안티 포렌식(Anti-Forensics) 및 물리적 파기
-        // 함수가 Err를 반환하며 스코프를 벗어날 때, output_buffer의 Drop 로직이 자동으로 호출됩니다.
-        // 이때 할당된 전체 capacity에 대해 Zeroizer::zeroize_raw가 수행되어 불완전한 데이터가 물리적으로 소거됩니다.
-
-        // 타이밍/패딩 오라클 공격 방지를 위해 에러 원인(위치, 발생한 문자 등)을 상세히 밝히지 않고 균일한 메시지를 반환합니다.
-        Err("Security Violation: Invalid hex encoding detected.")
-    }
+    // CtOption::into_option()이 유효성을 공개하는 유일한 분기 지점입니다.
+    //
+    // 5. 에러 발생 시의 안티 포렌식(Anti-Forensics) 및 물리적 파기
+    // 함수가 Err를 반환하며 스코프를 벗어날 때, output_buffer의 Drop 로직이 자동으로 호출됩니다.
+    // 이때 할당된 전체 capacity에 대해 Zeroizer::zeroize_raw가 수행되어 불완전한 데이터가 물리적으로 소거됩니다.
+    //
+    // 타이밍/패딩 오라클 공격 방지를 위해 에러 원인(위치, 발생한 문자 등)을 상세히 밝히지 않고 균일한 메시지를 반환합니다.
+    CtOption::new(output_buffer, is_valid)
+        .into_option()
+        .ok_or("Security Violation: Invalid hex encoding detected.")
 }
@@ -0,0 +1,131 @@
+//! 상수-시간 Hex 인코딩의 SIMD 구현입니다.
+//!
+//! x86_64에서는 AVX2 지원 여부를 런타임에 감지하고, aarch64에서는 NEON을 컴파일 타임에 사용합니다.
+//! 각 레인은 스칼라 `encode_nibble_ct`와 동일한 산술(`v + '0' + ((v > 9) & 39)`)을 수행하므로
+//! 비밀 데이터에 의존하는 분기나 메모리 룩업(LUT)이 발생하지 않습니다.
+//! 분기는 입력 길이(공개 정보)와 CPU 기능 감지 결과에만 의존합니다.
+
+/// 입력의 접두부를 SIMD 블록 단위로 인코딩하고, 처리한 입력 바이트 수를 반환합니다.
+///
+/// 남은 꼬리는 호출자가 스칼라 참조 경로로 처리해야 합니다.
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+#[inline]
+pub(crate) fn encode_hex_simd(input: &[u8], output: &mut [u8]) -> usize {
+    if std::is_x86_feature_detected!("avx2") {
+        // SAFETY: AVX2 지원 여부를 런타임에 확인하였습니다.
+        unsafe { avx2::encode(input, output) }
+    } else {
+        0
+    }
+}
+
+/// 입력의 접두부를 SIMD 블록 단위로 인코딩하고, 처리한 입력 바이트 수를 반환합니다.
+///
+/// 남은 꼬리는 호출자가 스칼라 참조 경로로 처리해야 합니다.
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "force_scalar")
+))]
+#[inline]
+pub(crate) fn encode_hex_simd(input: &[u8], output: &mut [u8]) -> usize {
+    // SAFETY: NEON 지원 여부가 컴파일 타임(`target_feature = "neon"`)에 보장됩니다.
+    unsafe { neon::encode(input, output) }
+}
+
+/// SIMD 경로가 없는 환경(또는 `force_scalar` 기능)에서는 아무것도 처리하지 않습니다.
+#[cfg(not(all(
+    any(
+        target_arch = "x86_64",
+        all(target_arch = "aarch64", target_feature = "neon")
+    ),
+    not(feature = "force_scalar")
+)))]
+#[inline(always)]
+pub(crate) fn encode_hex_simd(_input: &[u8], _output: &mut [u8]) -> usize {
+    0
+}
+
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+mod avx2 {
+    use core::arch::x86_64::*;
+
+    /// 32바이트 입력 블록을 64바이트 Hex 문자로 인코딩합니다.
+    #[target_feature(enable = "avx2")]
+    pub(super) fn encode(input: &[u8], output: &mut [u8]) -> usize {
+        debug_assert!(output.len() >= input.len() * 2);
+        let blocks = input.len() / 32;
+        let nibble_mask = _mm256_set1_epi8(0x0F);
+
+        for i in 0..blocks {
+            // SAFETY: i * 32 + 32 <= input.len() 이며, 출력 길이는 입력의 2배 이상입니다.
+            let x = unsafe { _mm256_loadu_si256(input.as_ptr().add(i * 32).cast()) };
+
+            // 16비트 시프트 후 마스킹하면 바이트 경계를 넘은 비트가 제거됩니다.
+            let hi = nibble_to_ascii(_mm256_and_si256(_mm256_srli_epi16::<4>(x), nibble_mask));
+            let lo = nibble_to_ascii(_mm256_and_si256(x, nibble_mask));
+
+            // unpack은 128비트 레인 단위로 동작하므로 permute로 레인 순서를 복원합니다.
+            let a = _mm256_unpacklo_epi8(hi, lo);
+            let b = _mm256_unpackhi_epi8(hi, lo);
+            let out0 = _mm256_permute2x128_si256::<0x20>(a, b);
+            let out1 = _mm256_permute2x128_si256::<0x31>(a, b);
+
+            // SAFETY: i * 64 + 64 <= output.len()
+            unsafe {
+                let dst = output.as_mut_ptr().add(i * 64);
+                _mm256_storeu_si256(dst.cast(), out0);
+                _mm256_storeu_si256(dst.add(32).cast(), out1);
+            }
+        }
+
+        blocks * 32
+    }
+
+    /// 0~15 니블 벡터를 Hex ASCII 벡터로 변환합니다: `v + '0' + ((v > 9) & ('a' - '0' - 10))`
+    #[inline]
+    #[target_feature(enable = "avx2")]
+    fn nibble_to_ascii(v: __m256i) -> __m256i {
+        let is_alpha = _mm256_cmpgt_epi8(v, _mm256_set1_epi8(9));
+        let gap = _mm256_and_si256(is_alpha, _mm256_set1_epi8(39));
+        _mm256_add_epi8(_mm256_add_epi8(v, _mm256_set1_epi8(b'0' as i8)), gap)
+    }
+}
+
+#[cfg(all(
+    target_arch = "aarch64",
+    target_feature = "neon",
+    not(feature = "force_scalar")
+))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    /// 16바이트 입력 블록을 32바이트 Hex 문자로 인코딩합니다.
+    #[target_feature(enable = "neon")]
+    pub(super) fn encode(input: &[u8], output: &mut [u8]) -> usize {
+        debug_assert!(output.len() >= input.len() * 2);
+        let blocks = input.len() / 16;
+        let nibble_mask = vdupq_n_u8(0x0F);
+
+        for i in 0..blocks {
+            // SAFETY: i * 16 + 16 <= input.len()
+            let x = unsafe { vld1q_u8(input.as_ptr().add(i * 16)) };
+            let hi = nibble_to_ascii(vshrq_n_u8::<4>(x));
+            let lo = nibble_to_ascii(vandq_u8(x, nibble_mask));
+
+            // vst2q는 두 벡터를 바이트 단위로 교차 저장합니다 (hi0, lo0, hi1, lo1, ...).
+            // SAFETY: i * 32 + 32 <= output.len()
+            unsafe { vst2q_u8(output.as_mut_ptr().add(i * 32), uint8x16x2_t(hi, lo)) };
+        }
+
+        blocks * 16
+    }
+
+    /// 0~15 니블 벡터를 Hex ASCII 벡터로 변환합니다: `v + '0' + ((v > 9) & ('a' - '0' - 10))`
+    #[inline]
+    #[target_feature(enable = "neon")]
+    fn nibble_to_ascii(v: uint8x16_t) -> uint8x16_t {
+        let gap = vandq_u8(vcgtq_u8(v, vdupq_n_u8(9)), vdupq_n_u8(39));
+        vaddq_u8(vaddq_u8(v, vdupq_n_u8(b'0')), gap)
+    }
+}
@@ -0,0 +1,164 @@
+//! 스트리밍(증분) Hex 인코더/디코더입니다.
+//!
+//! 전체 입력을 하나의 [`SecureBuffer`](entlib_native_secure_buffer::SecureBuffer)에 보관할 수
+//! 없는 대용량 데이터를 조각 단위로 처리합니다. 출력 버퍼는 호출자가 관리하며, 비밀 데이터를
+//! 처리하는 경우 출력 버퍼 역시 잠긴 메모리를 사용해야 합니다.
+
+use crate::hex::{decode_hex_core_ct, decode_nibble_ct, encode_hex_core_ct};
+use entlib_native_constant_time::option::CtOption;
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_secure_buffer::{SecureZeroize, ZeroizeOnDrop};
+
+/// 스트리밍 Hex 인코더입니다.
+///
+/// Hex 인코딩은 바이트 단위로 완결되므로 호출 사이에 이월되는 상태가 없으며,
+/// [`finish`](Self::finish)는 다른 스트리밍 코덱과의 일관성을 위해 제공됩니다.
+///
+/// # Usage
+/// ```
+/// use entlib_native_hex::HexEncoder;
+///
+/// let mut encoder = HexEncoder::new();
+/// let mut out = [0u8; 8];
+/// let mut len = encoder.update(&[0xDE, 0xAD], &mut out);
+/// len += encoder.update(&[0xBE, 0xEF], &mut out[len..]);
+/// len += encoder.finish(&mut out[len..]);
+/// assert_eq!(&out[..len], b"deadbeef");
+/// ```
+#[derive(Debug, Default)]
+pub struct HexEncoder {
+    _private: (),
+}
+
+impl HexEncoder {
+    /// 인코더를 생성합니다.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `input_len` 바이트에 대한 [`update`](Self::update)가 기록하는 바이트 수를 반환합니다.
+    pub fn max_update_len(&self, input_len: usize) -> usize {
+        input_len * 2
+    }
+
+    /// 입력 조각을 상수-시간으로 인코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Panic
+    /// `output`이 입력 길이의 2배보다 작으면 패닉이 발생합니다.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        encode_hex_core_ct(input, output);
+        input.len() * 2
+    }
+
+    /// 스트림을 종료합니다. 이월된 데이터가 없으므로 항상 0을 반환합니다.
+    pub fn finish(self, _output: &mut [u8]) -> usize {
+        0
+    }
+}
+
+/// 스트리밍 Hex 디코더입니다.
+///
+/// 조각 경계에서 잘린 니블 문자 하나를 다음 호출로 이월합니다.
+///
+/// # Security Note
+/// - 유효하지 않은 문자와 홀수 길이는 `update`에서 보고되지 않고 상수-시간 누산기에 병합되며,
+///   `decode_hex_core_ct`와 동일하게 오류는 [`finish`](Self::finish)에서만 공개됩니다.
+///   유효하지 않은 바이트 위치에는 0이 기록됩니다.
+/// - `finish`가 `Err`를 반환하면 그때까지 기록된 출력 전체를 폐기해야 합니다.
+///
+/// # Usage
+/// ```
+/// use entlib_native_hex::HexDecoder;
+///
+/// let mut decoder = HexDecoder::new();
+/// let mut out = [0u8; 4];
+/// let mut len = decoder.update(b"deA", &mut out);
+/// len += decoder.update(b"DbeeF", &mut out[len..]);
+/// len += decoder.finish(&mut out[len..]).unwrap();
+/// assert_eq!(&out[..len], &[0xDE, 0xAD, 0xBE, 0xEF]);
+/// ```
+#[derive(SecureZeroize, ZeroizeOnDrop)]
+pub struct HexDecoder {
+    pending: u8,
+    has_pending: bool,
+    valid: u8,
+}
+
+impl Default for HexDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl HexDecoder {
+    /// 디코더를 생성합니다.
+    pub fn new() -> Self {
+        Self {
+            pending: 0,
+            has_pending: false,
+            valid: 0xFF,
+        }
+    }
+
+    /// `input_len` 바이트에 대한 [`update`](Self::update)가 기록할 수 있는 최대 바이트 수를
+    /// 반환합니다.
+    pub fn max_update_len(&self, input_len: usize) -> usize {
+        (self.has_pending as usize + input_len) / 2
+    }
+
+    /// 입력 조각을 상수-시간으로 디코딩하여 `output`에 기록하고, 기록한 바이트 수를 반환합니다.
+    ///
+    /// # Panic
+    /// `output`이 [`max_update_len`](Self::max_update_len)보다 작으면 패닉이 발생합니다.
+    pub fn update(&mut self, input: &[u8], output: &mut [u8]) -> usize {
+        assert!(
+            output.len() >= self.max_update_len(input.len()),
+            "Security Violation: Output buffer overflow"
+        );
+        let mut input = input;
+        let mut written = 0;
+
+        // 1. 이전 조각에서 이월된 상위 니블과 현재 조각의 첫 문자를 결합
+        // 이월 여부는 조각 길이로부터 공개 정보이므로 분기 허용
+        if self.has_pending && !input.is_empty() {
+            let low = decode_nibble_ct(input[0]);
+            let byte = decode_nibble_ct(self.pending)
+                .ct_and_then(|high| low.ct_map(|low| (high << 4) | low));
+            self.valid &= byte.is_some().unwrap_u8();
+            output[0] = byte.unwrap_or(0);
+            self.pending.secure_zeroize();
+            self.has_pending = false;
+            input = &input[1..];
+            written = 1;
+        }
+
+        // 2. 완전한 문자 쌍 일괄 처리
+        let pairs_len = input.len() / 2 * 2;
+        let valid = decode_hex_core_ct(&input[..pairs_len], &mut output[written..]);
+        self.valid &= valid.unwrap_u8();
+        written += pairs_len / 2;
+
+        // 3. 남은 문자 하나 이월
+        if pairs_len < input.len() {
+            self.pending = input[pairs_len];
+            self.has_pending = true;
+        }
+        written
+    }
+
+    /// 스트림을 종료하고 누산된 유효성을 공개합니다.
+    ///
+    /// # Returns
+    /// - `Ok(0)` - 스트림 전체가 유효한 Hex 문자열인 경우 (추가로 기록하는 데이터 없음)
+    /// - `Err` - 유효하지 않은 문자 또는 홀수 길이 입력
+    pub fn finish(self, _output: &mut [u8]) -> Result<usize, &'static str> {
+        // 홀수 길이 에러도 통합 에러 상태에 병합
+        let is_even_len = (self.has_pending as u8).ct_eq(&0);
+        let is_valid = self.valid.ct_eq(&0xFF) & is_even_len;
+
+        // 타이밍/패딩 오라클 공격 방지를 위해 에러 원인을 밝히지 않고 균일한 메시지를 반환합니다.
+        CtOption::new(0, is_valid)
+            .into_option()
+            .ok_or("Security Violation: Invalid hex encoding detected.")
+    }
+}
@@ -0,0 +1,105 @@
+use entlib_native_hex::{decode, encode};
+use entlib_native_secure_buffer::SecureBuffer;
+
+fn buffer_from(data: &[u8]) -> SecureBuffer {
+    let mut buf = SecureBuffer::new_owned(data.len()).unwrap();
+    buf.as_mut_slice().copy_from_slice(data);
+    buf
+}
+
+#[test]
+fn encode_decode_roundtrip() {
+    let data = [0x00u8, 0x01, 0x7F, 0x80, 0xAB, 0xFF];
+    let encoded = encode(&buffer_from(&data)).unwrap();
+    assert_eq!(encoded.as_slice(), b"00017f80abff");
+
+    let decoded = decode(&encoded).unwrap();
+    assert_eq!(decoded.as_slice(), &data);
+}
+
+#[test]
+fn decode_accepts_upper_case() {
+    let decoded = decode(&buffer_from(b"DEADbeef09")).unwrap();
+    assert_eq!(decoded.as_slice(), &[0xDE, 0xAD, 0xBE, 0xEF, 0x09]);
+}
+
+/// 모든 유효 문자가 자기 니블 값으로 디코딩되어야 함 (`ct_select` 인자 순서 회귀 방지)
+#[test]
+fn decode_maps_every_hex_digit_to_its_value() {
+    let digits = b"0123456789abcdef";
+    for (value, &c) in digits.iter().enumerate() {
+        for c in [c, c.to_ascii_uppercase()] {
+            let decoded = decode(&buffer_from(&[c, c])).unwrap();
+            let expected = ((value as u8) << 4) | value as u8;
+            assert_eq!(decoded.as_slice(), &[expected], "digit {}", c as char);
+        }
+    }
+}
+
+#[test]
+fn decode_rejects_invalid_input() {
+    assert!(decode(&buffer_from(b"0g")).is_err());
+    assert!(decode(&buffer_from(b"abc")).is_err());
+    assert!(decode(&buffer_from(b"@0")).is_err());
+}
+
+#[test]
+fn encode_matches_scalar_reference_across_block_boundaries() {
+    // SIMD 블록(AVX2 32바이트, NEON 16바이트) 경계와 꼬리를 모두 지나는 길이
+    let data: Vec<u8> = (0..300u32).map(|i| (i * 131 + 7) as u8).collect();
+    for len in 0..data.len() {
+        let expected: String = data[..len].iter().map(|b| format!("{b:02x}")).collect();
+        let encoded = encode(&buffer_from(&data[..len])).unwrap();
+        assert_eq!(encoded.as_slice(), expected.as_bytes(), "length {len}");
+    }
+}
+
+#[test]
+fn stream_matches_one_shot_for_all_chunk_sizes() {
+    use entlib_native_hex::{HexDecoder, HexEncoder};
+
+    let data: Vec<u8> = (0..150u32).map(|i| (i * 89 + 3) as u8).collect();
+    let expected = encode(&buffer_from(&data)).unwrap();
+    for chunk in 1..=70 {
+        let mut encoder = HexEncoder::new();
+        let mut encoded = vec![0u8; data.len() * 2];
+        let mut len = 0;
+        for part in data.chunks(chunk) {
+            len += encoder.update(part, &mut encoded[len..]);
+        }
+        len += encoder.finish(&mut encoded[len..]);
+        assert_eq!(&encoded[..len], expected.as_slice(), "chunk {chunk}");
+
+        let mut decoder = HexDecoder::new();
+        let mut decoded = vec![0u8; data.len()];
+        let mut len = 0;
+        for part in expected.as_slice().chunks(chunk) {
+            len += decoder.update(part, &mut decoded[len..]);
+        }
+        len += decoder.finish(&mut decoded[len..]).unwrap();
+        assert_eq!(&decoded[..len], &data, "chunk {chunk}");
+    }
+}
+
+#[test]
+fn stream_decoder_defers_errors_to_finish() {
+    use entlib_native_hex::HexDecoder;
+
+    // 무효 문자 (조각 경계에 걸친 경우 포함)
+    for parts in [
+        &[&b"de"[..], b"g0", b"ad"][..],
+        &[b"d", b"g", b"ad"],
+        &[b"dead", b"b"],
+    ] {
+        let mut decoder = HexDecoder::new();
+        let mut out = [0u8; 8];
+        let mut len = 0;
+        for part in parts {
+            len += decoder.update(part, &mut out[len..]);
+        }
+        assert!(
+            decoder.finish(&mut out[len..]).is_err(),
+            "accepted {parts:?}"
+        );
+    }
+}
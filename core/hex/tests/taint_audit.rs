@@ -0,0 +1,72 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-hex --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_hex::{HexDecoder, HexEncoder, decode, encode};
+use entlib_native_secure_buffer::SecureBuffer;
+use entlib_native_test_support::taint::{classify_secret, declassify};
+
+fn secret_buffer(data: &[u8]) -> SecureBuffer {
+    let mut buf = SecureBuffer::new_owned(data.len()).unwrap();
+    buf.as_mut_slice().copy_from_slice(data);
+    classify_secret(buf.as_slice());
+    buf
+}
+
+#[test]
+fn audit_taint_flow_hex_encode() {
+    let input = secret_buffer(&[0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x7F, 0x80, 0xFF]);
+
+    let encoded = encode(&input).unwrap();
+    declassify(encoded.as_slice());
+    assert_eq!(encoded.as_slice(), b"deadbeef007f80ff");
+}
+
+#[test]
+fn audit_taint_flow_hex_decode() {
+    let input = secret_buffer(b"DeadBEEF007f80Ff");
+
+    let decoded = decode(&input).unwrap();
+    declassify(decoded.as_slice());
+    assert_eq!(
+        decoded.as_slice(),
+        &[0xDE, 0xAD, 0xBE, 0xEF, 0x00, 0x7F, 0x80, 0xFF]
+    );
+}
+
+#[test]
+fn audit_taint_flow_hex_decode_rejects_invalid() {
+    // 무효 문자의 위치는 공개되지 않으며, 실패 여부만 into_option에서 공개됨
+    let input = secret_buffer(b"deadbeeg");
+    assert!(decode(&input).is_err());
+}
+
+#[test]
+fn audit_taint_flow_hex_stream() {
+    // 조각 경계에서 이월되는 니블을 포함하여 오염된 값으로 분기하지 않아야 함
+    let plain = secret_buffer(&[0xDE, 0xAD, 0xBE, 0xEF, 0x01]);
+    let mut encoder = HexEncoder::new();
+    let mut encoded = [0u8; 10];
+    let mut len = 0;
+    for part in plain.as_slice().chunks(3) {
+        len += encoder.update(part, &mut encoded[len..]);
+    }
+    len += encoder.finish(&mut encoded[len..]);
+    declassify(&encoded[..len]);
+    assert_eq!(&encoded[..len], b"deadbeef01");
+
+    let input = secret_buffer(b"DeadBEEF01");
+    let mut decoder = HexDecoder::new();
+    let mut decoded = [0u8; 5];
+    let mut len = 0;
+    for part in input.as_slice().chunks(3) {
+        len += decoder.update(part, &mut decoded[len..]);
+    }
+    len += decoder.finish(&mut decoded[len..]).unwrap();
+    declassify(&decoded[..len]);
+    assert_eq!(&decoded[..len], &[0xDE, 0xAD, 0xBE, 0xEF, 0x01]);
+}
@@ -0,0 +1,257 @@
+use crate::buffer::SecureBuffer;
+use crate::memory::{AllocationBackend, ProtectionStatus, SecureMemoryBlock};
+use crate::zeroize::Zeroizer;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// 아레나 영역 하나의 목표 크기 (바이트). 실제 크기는 페이지 배수로 올림 처리됩니다.
+const REGION_SIZE: usize = 64 * 1024;
+/// 슬롯 정렬 단위 (바이트). 슬롯 경계가 캐시 라인 내부에서 어긋나지 않도록 보장합니다.
+const SLOT_ALIGN: usize = 16;
+/// 할당 비트맵 한 워드가 관리하는 슬롯 수. 64비트 원자 연산이 없는 32비트 대상에서도
+/// 동작하도록 포인터 폭 워드를 사용합니다.
+const BITS_PER_WORD: usize = usize::BITS as usize;
+
+/// 작은 비밀 데이터를 위한 보안 슬랩(Slab) 할당자입니다.
+///
+/// `SecureBuffer::new_owned(32)`는 32바이트를 위해 한 페이지 전체를 할당하고 별도의 `mlock`을
+/// 수행하므로, 수백 개의 작은 키를 다루는 워크로드는 금세 `RLIMIT_MEMLOCK`에 도달합니다.
+/// `SecureArena`는 몇 개의 큰 영역을 생성 시점에 한 번만 잠그고, 그 안의 고정 크기 슬롯을
+/// [`SecureBuffer`]로 나누어 제공합니다.
+///
+/// # Security Note
+/// - 각 슬롯은 `SecureBuffer`의 `Drop` 시점에 슬롯 전체가 소거된 뒤 아레나에 반환됩니다.
+/// - 아레나 영역은 마지막 슬롯이 반환되고 모든 `SecureArena` 핸들이 해제된 시점에 전체 소거 후 해제됩니다.
+/// - 슬롯은 다른 슬롯과 페이지를 공유하므로 페이지 보호(`protect_*`)는 지원하지 않습니다.
+///
+/// 슬롯 할당 및 반환은 원자적 비트맵으로 수행되어 잠금(lock) 없이 여러 스레드에서 공유할 수 있습니다.
+#[derive(Clone)]
+pub struct SecureArena {
+    inner: Arc<ArenaInner>,
+}
+
+/// 아레나 사용 현황 통계입니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ArenaStats {
+    /// 슬롯 하나의 크기 (바이트, 16바이트 정렬됨)
+    pub slot_size: usize,
+    /// 아레나가 제공할 수 있는 전체 슬롯 수
+    pub total_slots: usize,
+    /// 현재 사용 중인 슬롯 수
+    pub used_slots: usize,
+    /// 아레나 생성 이후 동시에 사용된 최대 슬롯 수
+    pub peak_used_slots: usize,
+    /// 아레나가 OS 레벨에서 잠근 전체 메모리 크기 (바이트)
+    pub bytes_locked: usize,
+}
+
+pub(crate) struct ArenaInner {
+    regions: Vec<SecureMemoryBlock>,
+    slot_size: usize,
+    slots_per_region: usize,
+    total_slots: usize,
+    /// 슬롯 점유 비트맵 (1 = 사용 중)
+    bitmap: Vec<AtomicUsize>,
+    used: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+// Safety: 영역 포인터는 생성 이후 변경되지 않으며, 슬롯 소유권은 원자적 비트맵으로만 이전되므로
+//         서로 다른 스레드가 동일한 슬롯에 동시에 접근하는 상황이 발생하지 않음
+unsafe impl Send for ArenaInner {}
+unsafe impl Sync for ArenaInner {}
+
+/// `SecureBuffer`가 보유하는 아레나 슬롯 소유권입니다.
+///
+/// `Drop` 시점에 슬롯을 아레나에 반환합니다. 슬롯 내용의 소거는 반환 이전에
+/// 소유자(`SecureBuffer`)가 수행해야 합니다.
+pub(crate) struct ArenaSlot {
+    arena: Arc<ArenaInner>,
+    index: usize,
+}
+
+impl SecureArena {
+    /// `slot_size` 바이트 슬롯을 최소 `slot_count`개 제공하는 아레나를 생성합니다.
+    ///
+    /// 필요한 영역을 모두 즉시 할당하고 잠그므로, 이후의 슬롯 할당은 시스템 호출 없이 수행됩니다.
+    /// 페이지 올림으로 생긴 여유 공간도 슬롯으로 사용되므로 실제 슬롯 수는 요청보다 많을 수 있습니다.
+    ///
+    /// # Arguments
+    /// - `slot_size` - 슬롯 하나의 크기 (바이트). 16바이트 배수로 올림 처리됩니다.
+    /// - `slot_count` - 필요한 최소 슬롯 수
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 인자가 0이거나, 크기 계산 오버플로우, 메모리 할당 또는 잠금 실패 시
+    pub fn new(slot_size: usize, slot_count: usize) -> Result<Self, &'static str> {
+        if slot_size == 0 || slot_count == 0 {
+            return Err("Invalid arena layout: Slot size and count must be non-zero.");
+        }
+
+        let slot_size = slot_size
+            .checked_next_multiple_of(SLOT_ALIGN)
+            .ok_or("Invalid arena layout: Slot size overflow.")?;
+        let region_request = slot_size
+            .checked_mul(slot_count)
+            .ok_or("Invalid arena layout: Total size overflow.")?
+            .min(REGION_SIZE.max(slot_size));
+
+        let first = SecureMemoryBlock::allocate_locked(region_request)?;
+        let slots_per_region = first.capacity / slot_size;
+        let region_count = slot_count.div_ceil(slots_per_region);
+
+        let mut regions = Vec::with_capacity(region_count);
+        regions.push(first);
+        while regions.len() < region_count {
+            // 실패 시 이미 할당된 영역은 ArenaInner가 아닌 Vec에 있으므로 직접 해제
+            match SecureMemoryBlock::allocate_locked(region_request) {
+                Ok(block) => regions.push(block),
+                Err(e) => {
+                    for block in &regions {
+                        unsafe { block.deallocate_unlocked() };
+                    }
+                    return Err(e);
+                }
+            }
+        }
+
+        let total_slots = slots_per_region * region_count;
+        let words = total_slots.div_ceil(BITS_PER_WORD);
+        let mut bitmap: Vec<AtomicUsize> = (0..words).map(|_| AtomicUsize::new(0)).collect();
+
+        // 마지막 워드에서 존재하지 않는 슬롯 비트는 사용 중으로 표시하여 할당 대상에서 제외
+        let tail = total_slots % BITS_PER_WORD;
+        if tail != 0
+            && let Some(last) = bitmap.last_mut()
+        {
+            *last.get_mut() = !0usize << tail;
+        }
+
+        Ok(Self {
+            inner: Arc::new(ArenaInner {
+                regions,
+                slot_size,
+                slots_per_region,
+                total_slots,
+                bitmap,
+                used: AtomicUsize::new(0),
+                peak: AtomicUsize::new(0),
+            }),
+        })
+    }
+
+    /// 빈 슬롯 하나를 유효 길이 `len`의 [`SecureBuffer`]로 할당합니다.
+    ///
+    /// 반환된 버퍼는 일반 `SecureBuffer`와 동일하게 사용할 수 있으며, 슬롯 크기를 넘어
+    /// 확장하면 개별 잠긴 메모리 블록으로 자동 이전되고 슬롯은 소거 후 반환됩니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - `len`이 슬롯 크기를 초과하거나 빈 슬롯이 없는 경우
+    pub fn alloc(&self, len: usize) -> Result<SecureBuffer, &'static str> {
+        let inner = &self.inner;
+        if len > inner.slot_size {
+            return Err("Arena slot too small: Requested size exceeds slot size.");
+        }
+
+        // 슬롯 점유 여부는 비밀 데이터와 무관한 공개 정보이므로 일반 분기 허용
+        for (w, word) in inner.bitmap.iter().enumerate() {
+            let mut current = word.load(Ordering::Relaxed);
+            while current != usize::MAX {
+                let bit = (!current).trailing_zeros() as usize;
+                match word.compare_exchange_weak(
+                    current,
+                    current | (1usize << bit),
+                    Ordering::Acquire,
+                    Ordering::Relaxed,
+                ) {
+                    Ok(_) => {
+                        let index = w * BITS_PER_WORD + bit;
+                        let used = inner.used.fetch_add(1, Ordering::Relaxed) + 1;
+                        inner.peak.fetch_max(used, Ordering::Relaxed);
+
+                        let ptr = inner.slot_ptr(index);
+                        let slot = ArenaSlot {
+                            arena: Arc::clone(inner),
+                            index,
+                        };
+                        // 슬롯은 초기 할당 시 0으로 초기화되며, 반환 시마다 소거되므로 항상 0 상태
+                        return Ok(SecureBuffer::from_arena_slot(
+                            ptr,
+                            len,
+                            inner.slot_size,
+                            slot,
+                        ));
+                    }
+                    Err(actual) => current = actual,
+                }
+            }
+        }
+
+        Err("Arena exhausted: No free slot available.")
+    }
+
+    /// 슬롯 하나의 크기(바이트)를 반환합니다.
+    #[inline(always)]
+    pub fn slot_size(&self) -> usize {
+        self.inner.slot_size
+    }
+
+    /// 아레나의 현재 사용 현황을 반환합니다.
+    pub fn stats(&self) -> ArenaStats {
+        let inner = &self.inner;
+        ArenaStats {
+            slot_size: inner.slot_size,
+            total_slots: inner.total_slots,
+            used_slots: inner.used.load(Ordering::Relaxed),
+            peak_used_slots: inner.peak.load(Ordering::Relaxed),
+            bytes_locked: inner.regions.iter().map(|r| r.capacity).sum(),
+        }
+    }
+}
+
+impl ArenaInner {
+    #[inline(always)]
+    fn slot_ptr(&self, index: usize) -> *mut u8 {
+        let region = &self.regions[index / self.slots_per_region];
+        // Safety: index < total_slots 이므로 오프셋은 항상 영역 용량 내부
+        unsafe {
+            region
+                .ptr
+                .add((index % self.slots_per_region) * self.slot_size)
+        }
+    }
+}
+
+impl Drop for ArenaInner {
+    fn drop(&mut self) {
+        // 모든 슬롯이 반환된 이후에만 도달 (각 슬롯이 Arc를 보유)
+        for region in &self.regions {
+            unsafe {
+                Zeroizer::zeroize_raw(region.ptr, region.capacity);
+                region.deallocate_unlocked();
+            }
+        }
+    }
+}
+
+impl ArenaSlot {
+    /// 슬롯이 속한 영역의 OS 수준 보호 상태를 반환합니다.
+    pub(crate) fn region_status(&self) -> ProtectionStatus {
+        self.arena.regions[self.index / self.arena.slots_per_region].status
+    }
+
+    /// 슬롯이 속한 영역의 할당 백엔드를 반환합니다.
+    pub(crate) fn region_backend(&self) -> AllocationBackend {
+        self.arena.regions[self.index / self.arena.slots_per_region].backend
+    }
+}
+
+impl Drop for ArenaSlot {
+    fn drop(&mut self) {
+        let word = &self.arena.bitmap[self.index / BITS_PER_WORD];
+        let mask = 1usize << (self.index % BITS_PER_WORD);
+        // Release: 소유자가 수행한 소거 쓰기가 다음 할당자에게 보이도록 보장
+        word.fetch_and(!mask, Ordering::Release);
+        self.arena.used.fetch_sub(1, Ordering::Relaxed);
+    }
+}
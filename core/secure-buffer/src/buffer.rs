@@ -1,5 +1,8 @@
-use crate::memory::SecureMemoryBlock;
+use crate::arena::{ArenaSlot, SecureArena};
+use crate::memory::{AllocationBackend, MemoryProtection, ProtectionStatus, SecureMemoryBlock};
+use crate::policy;
 use crate::zeroize::{SecureZeroize, Zeroizer};
+use core::ops::Deref;
 
 /// 군사급 보안 요구사항을 충족하는 고수준 보안 버퍼입니다.
 ///
@@ -10,6 +13,7 @@ use crate::zeroize::{SecureZeroize, Zeroizer};
 /// - **자동 소거 (Zeroization)**: `Drop` 시점에 할당된 전체 메모리(`capacity`)를 강제로 0으로 덮어씁니다.
 /// - **메모리 잠금 (Memory Locking)**: 스왑(Swap) 영역으로의 데이터 유출을 방지하기 위해 OS 레벨 잠금을 수행합니다.
 /// - **페이지 정렬 검증 (Page Alignment Check)**: 외부 메모리 주입 시, 보안 강화를 위해 페이지 정렬 여부를 엄격히 검사합니다.
+/// - **페이지 보호 (Page Protection)**: 사용하지 않는 동안 페이지를 읽기 전용 또는 접근 불가 상태로 봉인할 수 있습니다.
 pub struct SecureBuffer {
     /// 데이터가 저장된 메모리의 시작 포인터
     ptr: *mut u8,
@@ -19,6 +23,10 @@ pub struct SecureBuffer {
     capacity: usize,
     /// Rust가 할당한 메모리 블록 정보 (소유권이 있는 경우에만 존재)
     owned_block: Option<SecureMemoryBlock>,
+    /// [`SecureArena`](crate::SecureArena)에서 할당받은 슬롯 정보 (아레나 할당인 경우에만 존재)
+    arena_slot: Option<ArenaSlot>,
+    /// 현재 페이지에 적용된 접근 보호 수준
+    protection: MemoryProtection,
 }
 
 impl SecureBuffer {
@@ -35,12 +43,66 @@ impl SecureBuffer {
     pub fn new_owned(size: usize) -> Result<Self, &'static str> {
         let block = SecureMemoryBlock::allocate_locked(size)?;
 
-        Ok(Self {
-            ptr: block.ptr,
-            len: size,
-            capacity: block.capacity,
-            owned_block: Some(block),
-        })
+        Ok(Self::from_block(block, size))
+    }
+
+    /// 유효 길이가 0이고, 최소 `capacity` 바이트를 수용할 수 있는 빈 버퍼를 할당합니다.
+    ///
+    /// 스트리밍 인코딩이나 핸드셰이크 전사(transcript) 누적처럼 최종 크기를 미리 알 수 없는
+    /// 경우, 이 함수로 버퍼를 만든 뒤 [`extend_from_slice`](Self::extend_from_slice) 등으로
+    /// 데이터를 추가합니다.
+    ///
+    /// # Returns
+    /// - `Ok(SecureBuffer)` - 할당 및 잠금 성공 시
+    /// - `Err(&'static str)` - 메모리 할당 실패 또는 OS 리소스 제한 도달 시
+    pub fn with_capacity(capacity: usize) -> Result<Self, &'static str> {
+        let block = SecureMemoryBlock::allocate_locked(capacity)?;
+
+        Ok(Self::from_block(block, 0))
+    }
+
+    /// 지정한 할당 백엔드로 안전한 메모리를 새로 할당합니다.
+    ///
+    /// 요청한 백엔드가 지원되지 않으면 자동으로 대체되며, 실제로 사용된 백엔드는
+    /// [`backend`](Self::backend)로 확인할 수 있습니다. 이후 재할당에서도 동일한 백엔드가 유지됩니다.
+    ///
+    /// # Returns
+    /// - `Ok(SecureBuffer)` - 할당 및 잠금 성공 시
+    /// - `Err(&'static str)` - 메모리 할당 실패, 매핑 거부 또는 OS 리소스 제한 도달 시
+    pub fn new_with_backend(size: usize, backend: AllocationBackend) -> Result<Self, &'static str> {
+        let block = SecureMemoryBlock::allocate_with_backend(size, backend)?;
+
+        Ok(Self::from_block(block, size))
+    }
+
+    /// 앞뒤에 가드 페이지를 두고, 데이터를 뒤쪽 가드 페이지에 맞춰 우측 정렬한 보안 버퍼를 할당합니다.
+    ///
+    /// 용량은 `size`와 정확히 일치하므로 끝을 넘는 오버플로우는 1바이트라도 `PROT_NONE` 가드
+    /// 페이지에 의해 즉시 차단되며, 데이터 앞쪽으로의 언더플로우는 데이터 바로 앞에 배치된
+    /// 카나리로 `Drop` 시점에 탐지됩니다.
+    /// 일반 할당보다 최소 2페이지의 가상 주소 공간과 추가 시스템 호출 비용이 발생합니다.
+    ///
+    /// # Returns
+    /// - `Ok(SecureBuffer)` - 매핑, 보호 설정 및 잠금 성공 시
+    /// - `Err(&'static str)` - 매핑 실패, OS 리소스 제한 도달 또는 미지원 플랫폼인 경우
+    pub fn new_guarded(size: usize) -> Result<Self, &'static str> {
+        let block = SecureMemoryBlock::allocate_guarded(size)?;
+
+        Ok(Self::from_block(block, size))
+    }
+
+    /// 아레나가 주어지면 아레나 슬롯에서, 그렇지 않으면 개별 잠긴 메모리 블록으로 할당합니다.
+    ///
+    /// 해시 상태나 MAC 결과처럼 선택적으로 [`SecureArena`]를 사용하는 구조체를 위한 함수입니다.
+    /// `size`가 아레나 슬롯 크기를 초과하는 경우(예: 긴 XOF 출력)에는 아레나 대신 개별 블록으로 할당합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 아레나 슬롯 고갈, 메모리 할당 실패 또는 OS 리소스 제한 도달 시
+    pub fn new_in(size: usize, arena: Option<&SecureArena>) -> Result<Self, &'static str> {
+        match arena {
+            Some(arena) if size <= arena.slot_size() => arena.alloc(size),
+            _ => Self::new_owned(size),
+        }
     }
 
     /// Java 등 외부 시스템에서 FFM API를 통해 전달한 메모리를 래핑합니다.
@@ -81,15 +143,115 @@ impl SecureBuffer {
             len,
             capacity: len,
             owned_block: None, // 외부 소유 메모리이므로 None
+            arena_slot: None,
+            protection: MemoryProtection::ReadWrite,
         })
     }
 
+    /// 새로 할당한 메모리 블록을 유효 길이 `len`의 버퍼로 래핑합니다.
+    fn from_block(block: SecureMemoryBlock, len: usize) -> Self {
+        policy::record_buffer_created();
+        Self {
+            ptr: block.ptr,
+            len,
+            capacity: block.capacity,
+            owned_block: Some(block),
+            arena_slot: None,
+            protection: MemoryProtection::ReadWrite,
+        }
+    }
+
+    /// [`SecureArena`](crate::SecureArena)의 슬롯을 래핑합니다.
+    ///
+    /// 슬롯 메모리는 아레나 영역이 이미 잠근 상태이므로 별도의 잠금을 수행하지 않으며,
+    /// `Drop` 시점에 슬롯 전체를 소거한 뒤 아레나에 반환합니다.
+    pub(crate) fn from_arena_slot(
+        ptr: *mut u8,
+        len: usize,
+        capacity: usize,
+        slot: ArenaSlot,
+    ) -> Self {
+        policy::record_buffer_created();
+        Self {
+            ptr,
+            len,
+            capacity,
+            owned_block: None,
+            arena_slot: Some(slot),
+            protection: MemoryProtection::ReadWrite,
+        }
+    }
+
     /// 버퍼의 유효 데이터 길이(바이트)를 반환합니다.
     #[inline(always)]
     pub fn len(&self) -> usize {
         self.len
     }
 
+    /// 버퍼에 할당된 전체 용량(바이트)을 반환합니다.
+    ///
+    /// 가드 페이지 모드에서는 카나리 영역을 제외한 크기이므로 페이지 배수가 아닐 수 있습니다.
+    #[inline(always)]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 버퍼에 실제로 적용된 OS 수준 보호 상태(메모리 잠금, 코어 덤프 제외, fork 시 0 초기화)를 반환합니다.
+    ///
+    /// 외부 메모리는 잠금만 수행하며, 덤프 및 fork 관련 속성은 원래 소유자의 정책을 따릅니다.
+    /// 아레나 슬롯은 해당 슬롯이 속한 아레나 영역의 보호 상태를 반환합니다.
+    pub fn protection_status(&self) -> ProtectionStatus {
+        if let Some(slot) = &self.arena_slot {
+            return slot.region_status();
+        }
+
+        match &self.owned_block {
+            Some(block) => block.status,
+            None => ProtectionStatus {
+                locked: cfg!(feature = "std"),
+                ..ProtectionStatus::default()
+            },
+        }
+    }
+
+    /// 실제로 사용된 할당 백엔드를 반환합니다.
+    ///
+    /// 외부 메모리는 원래 소유자가 할당했으므로 `None`을 반환합니다.
+    pub fn backend(&self) -> Option<AllocationBackend> {
+        if let Some(slot) = &self.arena_slot {
+            return Some(slot.region_backend());
+        }
+        self.owned_block.as_ref().map(|block| block.backend)
+    }
+
+    /// [`SecureArena`](crate::SecureArena)의 슬롯에서 할당된 버퍼인지 여부를 반환합니다.
+    #[inline(always)]
+    pub fn is_arena_slot(&self) -> bool {
+        self.arena_slot.is_some()
+    }
+
+    /// 가드 페이지 모드로 할당된 버퍼인지 여부를 반환합니다.
+    #[inline(always)]
+    pub fn is_guarded(&self) -> bool {
+        self.owned_block
+            .as_ref()
+            .is_some_and(SecureMemoryBlock::is_guarded)
+    }
+
+    /// 유효 영역 바로 앞의 카나리가 변조되지 않았는지 검증합니다.
+    ///
+    /// 가드 페이지 모드가 아닌 버퍼는 항상 `Ok(())`를 반환합니다. `Drop` 시점에 변조가 감지되면
+    /// 프로세스가 즉시 중단(abort)되므로, 변조를 오류로 처리하려면 해제 전에 이 함수를 호출합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 카나리 불일치(버퍼 언더플로우 또는 메모리 변조) 감지 시
+    pub fn verify_canary(&self) -> Result<(), &'static str> {
+        match &self.owned_block {
+            Some(block) => block.verify_canary(),
+            None => Ok(()),
+        }
+    }
+
     /// 버퍼의 유효 데이터가 없으면 `true`를 반환합니다.
     #[inline(always)]
     pub fn is_empty(&self) -> bool {
@@ -114,6 +276,307 @@ impl SecureBuffer {
     pub fn as_mut_slice(&mut self) -> &mut [u8] {
         unsafe { core::slice::from_raw_parts_mut(self.ptr, self.len) }
     }
+
+    /// 최소 `additional` 바이트를 더 기록할 수 있도록 용량을 확보합니다.
+    ///
+    /// 용량이 부족하면 [`grow_to`](Self::grow_to)를 통해 새로운 잠긴 메모리 블록으로
+    /// 안전하게 재할당합니다. 용량이 충분하면 아무 작업도 수행하지 않습니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 용량 계산 오버플로우, 메모리 할당 실패, 외부 메모리의 확장 시도,
+    ///   또는 페이지가 봉인(읽기 전용/접근 불가)된 상태인 경우
+    pub fn reserve(&mut self, additional: usize) -> Result<(), &'static str> {
+        self.ensure_writable()?;
+
+        let required = self
+            .len
+            .checked_add(additional)
+            .ok_or("Capacity overflow: Requested size exceeds usize range.")?;
+
+        // 용량은 공개 정보이므로 일반 분기 허용
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        // 재할당 빈도를 줄이기 위해 기존 용량의 2배 이상으로 확장
+        let doubled = self.capacity.saturating_mul(2);
+        self.grow_to(required.max(doubled))
+    }
+
+    /// 버퍼 끝에 단일 바이트를 추가합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 재할당이 필요하지만 실패한 경우
+    pub fn push(&mut self, byte: u8) -> Result<(), &'static str> {
+        self.reserve(1)?;
+
+        unsafe {
+            self.ptr.add(self.len).write(byte);
+        }
+        self.len += 1;
+
+        Ok(())
+    }
+
+    /// 버퍼 끝에 `data` 전체를 복사하여 추가합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 재할당이 필요하지만 실패한 경우
+    pub fn extend_from_slice(&mut self, data: &[u8]) -> Result<(), &'static str> {
+        self.reserve(data.len())?;
+
+        // Safety: reserve를 통해 len + data.len() <= capacity 가 보장되며,
+        //         data는 외부 슬라이스이므로 내부 메모리와 겹치지 않음
+        unsafe {
+            core::ptr::copy_nonoverlapping(data.as_ptr(), self.ptr.add(self.len), data.len());
+        }
+        self.len += data.len();
+
+        Ok(())
+    }
+
+    /// 유효 길이를 `len`으로 줄입니다.
+    ///
+    /// 잘려 나간 영역(`len..기존 len`)은 즉시 물리적으로 소거되어, 길이만 줄어든 채
+    /// 비밀 데이터가 용량 내부에 잔존하는 상황을 방지합니다.
+    /// `len`이 현재 길이 이상이면 아무 작업도 수행하지 않습니다.
+    ///
+    /// 페이지가 봉인된 상태라면 소거를 위해 일시적으로 쓰기를 허용한 뒤 원래 보호 수준으로 되돌립니다.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let sealed = self.protection;
+        if sealed != MemoryProtection::ReadWrite {
+            self.reseal(MemoryProtection::ReadWrite);
+        }
+
+        unsafe {
+            Zeroizer::zeroize_raw(self.ptr.add(len), self.len - len);
+        }
+        self.len = len;
+
+        if sealed != MemoryProtection::ReadWrite {
+            self.reseal(sealed);
+        }
+    }
+
+    /// 유효 길이를 `new_len`으로 변경합니다.
+    ///
+    /// 길이가 늘어나는 경우 새 영역은 `value`로 채워지며, 줄어드는 경우
+    /// [`truncate`](Self::truncate)와 동일하게 잘린 영역을 소거합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 재할당이 필요하지만 실패한 경우
+    pub fn resize(&mut self, new_len: usize, value: u8) -> Result<(), &'static str> {
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return Ok(());
+        }
+
+        let additional = new_len - self.len;
+        self.reserve(additional)?;
+
+        unsafe {
+            core::ptr::write_bytes(self.ptr.add(self.len), value, additional);
+        }
+        self.len = new_len;
+
+        Ok(())
+    }
+
+    /// 현재 페이지에 적용된 접근 보호 수준을 반환합니다.
+    #[inline(always)]
+    pub fn protection(&self) -> MemoryProtection {
+        self.protection
+    }
+
+    /// 버퍼 페이지를 읽기 전용으로 봉인합니다.
+    ///
+    /// 봉인 이후 [`as_mut_slice`](Self::as_mut_slice) 등을 통한 쓰기는 프로세스를
+    /// `SIGSEGV`(Access Violation)로 종료시키며, 길이를 늘리는 연산은 오류를 반환합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - OS가 보호 속성 변경(`mprotect`/`VirtualProtect`)을 거부한 경우
+    pub fn protect_readonly(&mut self) -> Result<(), &'static str> {
+        self.set_protection(MemoryProtection::ReadOnly)
+    }
+
+    /// 버퍼 페이지를 접근 불가 상태로 봉인합니다.
+    ///
+    /// 장기 보관 중인 키를 사용 시점 사이에 완전히 격리하는 용도입니다. 봉인 이후의 모든
+    /// 읽기/쓰기는 프로세스를 종료시키므로, 읽기가 필요하면 [`read_guard`](Self::read_guard)를 사용합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - OS가 보호 속성 변경(`mprotect`/`VirtualProtect`)을 거부한 경우
+    pub fn protect_noaccess(&mut self) -> Result<(), &'static str> {
+        self.set_protection(MemoryProtection::NoAccess)
+    }
+
+    /// 버퍼 페이지를 읽기/쓰기 가능 상태로 되돌립니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - OS가 보호 속성 변경(`mprotect`/`VirtualProtect`)을 거부한 경우
+    pub fn unprotect(&mut self) -> Result<(), &'static str> {
+        self.set_protection(MemoryProtection::ReadWrite)
+    }
+
+    /// 유효 데이터를 읽을 수 있는 RAII 가드를 반환합니다.
+    ///
+    /// 접근 불가 상태였다면 가드가 살아 있는 동안만 읽기 전용으로 전환되며,
+    /// 가드가 `Drop`되는 시점에 원래 보호 수준으로 다시 봉인됩니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - OS가 보호 속성 변경(`mprotect`/`VirtualProtect`)을 거부한 경우
+    pub fn read_guard(&mut self) -> Result<ReadGuard<'_>, &'static str> {
+        let restore = self.protection;
+        if restore == MemoryProtection::NoAccess {
+            self.set_protection(MemoryProtection::ReadOnly)?;
+        }
+
+        Ok(ReadGuard {
+            buffer: self,
+            restore,
+        })
+    }
+
+    /// 페이지 보호 수준을 변경하고 내부 상태를 갱신합니다.
+    ///
+    /// 보호 대상은 소유 메모리의 경우 카나리를 포함한 데이터 페이지 전체,
+    /// 외부 메모리의 경우 페이지 정렬이 검증된 `capacity` 전체입니다.
+    /// 아레나 슬롯은 다른 슬롯과 페이지를 공유하므로 보호 수준을 변경할 수 없습니다.
+    fn set_protection(&mut self, protection: MemoryProtection) -> Result<(), &'static str> {
+        if self.protection == protection {
+            return Ok(());
+        }
+
+        if self.arena_slot.is_some() {
+            return Err("Page protection is not available for arena slots sharing a page.");
+        }
+
+        #[cfg(feature = "std")]
+        {
+            let (region_ptr, region_len) = match &self.owned_block {
+                Some(block) => block.page_region(),
+                None => (self.ptr, self.capacity),
+            };
+
+            // Safety: 보호 대상 영역은 페이지 정렬된 시작 주소와 페이지 배수 길이를 가짐
+            if !unsafe {
+                crate::memory::os_protect::set_protection(region_ptr, region_len, protection)
+            } {
+                return Err("OS page protection (mprotect/VirtualProtect) failed.");
+            }
+
+            self.protection = protection;
+            Ok(())
+        }
+
+        #[cfg(not(feature = "std"))]
+        {
+            Err("Page protection is not supported in no_std environment.")
+        }
+    }
+
+    /// 내부 소거 경로에서 보호 수준을 강제로 전환합니다.
+    ///
+    /// 소거 대상 페이지가 봉인된 채 남아 있으면 비밀 데이터를 지울 수 없으므로,
+    /// 전환에 실패하면 복구 불가능한 상태로 간주하여 패닉을 발생시킵니다.
+    fn reseal(&mut self, protection: MemoryProtection) {
+        if self.set_protection(protection).is_err() {
+            panic!("Critical Fault: 보안 버퍼의 페이지 보호 속성을 전환할 수 없습니다!");
+        }
+    }
+
+    /// 페이지가 쓰기 가능한 상태인지 확인합니다.
+    fn ensure_writable(&self) -> Result<(), &'static str> {
+        if self.protection != MemoryProtection::ReadWrite {
+            return Err("Security Violation: Buffer pages are sealed. Call unprotect() first.");
+        }
+        Ok(())
+    }
+
+    /// 최소 `min_capacity` 바이트를 수용하는 새 잠긴 메모리 블록으로 재할당합니다.
+    ///
+    /// # Security Note
+    /// 새 블록으로 유효 데이터를 복사한 직후, 기존 블록의 전체 용량을
+    /// [`Zeroizer::zeroize_raw`]로 소거하고 잠금 해제 및 해제를 수행합니다.
+    /// 따라서 재할당 과정에서 이전 위치에 비밀 데이터 잔재가 남지 않습니다.
+    ///
+    /// 외부(FFI)에서 주입된 메모리는 해제 권한이 없으므로 재할당을 거부합니다.
+    /// 아레나 슬롯은 슬롯 크기를 넘어 확장할 수 없으므로, 개별 잠긴 메모리 블록으로 이전한 뒤
+    /// 기존 슬롯을 소거하여 아레나에 반환합니다.
+    fn grow_to(&mut self, min_capacity: usize) -> Result<(), &'static str> {
+        if self.arena_slot.is_some() {
+            let new_block = SecureMemoryBlock::allocate_locked(min_capacity)?;
+
+            unsafe {
+                core::ptr::copy_nonoverlapping(self.ptr, new_block.ptr, self.len);
+                Zeroizer::zeroize_raw(self.ptr, self.capacity);
+            }
+            // 소거가 끝난 슬롯을 아레나에 반환
+            self.arena_slot = None;
+
+            self.ptr = new_block.ptr;
+            self.capacity = new_block.capacity;
+            self.owned_block = Some(new_block);
+
+            return Ok(());
+        }
+
+        let old_block = match &self.owned_block {
+            Some(block) => block,
+            None => {
+                return Err("Security Violation: External memory segment cannot be reallocated.");
+            }
+        };
+
+        // 재할당 전에 기존 블록의 무결성을 먼저 확인하여 변조 흔적이 새 블록으로 은닉되지 않도록 함
+        old_block.verify_canary()?;
+
+        // 가드 페이지 모드 및 할당 백엔드는 재할당 후에도 유지
+        let new_block = if old_block.is_guarded() {
+            SecureMemoryBlock::allocate_guarded(min_capacity)?
+        } else {
+            SecureMemoryBlock::allocate_with_backend(min_capacity, old_block.backend)?
+        };
+
+        unsafe {
+            // 유효 데이터만 새 블록으로 이동 (새 블록의 나머지 영역은 이미 0으로 초기화됨)
+            core::ptr::copy_nonoverlapping(old_block.ptr, new_block.ptr, self.len);
+
+            // 기존 블록의 잔재 소거 후 잠금 해제 및 반환
+            Zeroizer::zeroize_raw(old_block.ptr, old_block.capacity);
+            old_block.deallocate_unlocked();
+        }
+
+        self.ptr = new_block.ptr;
+        self.capacity = new_block.capacity;
+        self.owned_block = Some(new_block);
+
+        Ok(())
+    }
+}
+
+impl SecureZeroize for SecureBuffer {
+    /// 유효 길이는 유지한 채 할당된 전체 용량(`capacity`)을 소거합니다.
+    ///
+    /// 봉인된 버퍼는 소거 동안만 일시적으로 쓰기 권한을 얻은 뒤 원래 보호 수준으로 복원됩니다.
+    fn secure_zeroize(&mut self) {
+        let sealed = self.protection;
+        if sealed != MemoryProtection::ReadWrite {
+            self.reseal(MemoryProtection::ReadWrite);
+        }
+
+        unsafe {
+            Zeroizer::zeroize_raw(self.ptr, self.capacity);
+        }
+
+        if sealed != MemoryProtection::ReadWrite {
+            self.reseal(sealed);
+        }
+    }
 }
 
 impl Drop for SecureBuffer {
@@ -122,6 +585,11 @@ impl Drop for SecureBuffer {
             return;
         }
 
+        // 봉인된 페이지는 소거 및 카나리 검증을 위해 먼저 읽기/쓰기 가능 상태로 복원
+        if self.protection != MemoryProtection::ReadWrite {
+            self.reseal(MemoryProtection::ReadWrite);
+        }
+
         // 강제 물리적 소거
         // 할당된 '전체 capacity'에 대해 수행하여, 패딩 영역까지 꼼꼼하게 지움
         unsafe {
@@ -129,18 +597,73 @@ impl Drop for SecureBuffer {
         }
 
         // 소유권에 따른 메모리 해제 및 잠금 해제 분기
+        if self.owned_block.is_some() || self.arena_slot.is_some() {
+            policy::record_buffer_dropped();
+        }
         if let Some(block) = &self.owned_block {
+            // 해제 전에 카나리 검증 결과를 확보 (가드 페이지 모드가 아니면 항상 Ok)
+            let integrity = block.verify_canary();
+
             // Rust가 소유한 메모리: SecureMemoryBlock에게 해제 위임
             // (내부적으로 잠금 해제 및 dealloc 수행)
             unsafe {
                 block.deallocate_unlocked();
             }
-        } else {
+
+            // 소거 및 해제를 마친 뒤 변조 사실을 보고하고 프로세스를 중단.
+            // 언와인딩 중의 이중 패닉이나 FFI 경계를 넘는 패닉을 피하기 위해 panic 대신 abort 사용
+            if let Err(e) = integrity {
+                tamper_abort(e);
+            }
+        } else if self.arena_slot.is_none() {
             // 외부가 소유한 메모리: 잠금만 해제하고, 메모리 반환은 Java Arena 등에 위임
             #[cfg(feature = "std")]
             unsafe {
                 crate::memory::os_lock::unlock_memory(self.ptr, self.capacity);
             }
         }
+        // 아레나 슬롯은 필드 해제 시점에 ArenaSlot의 Drop을 통해 아레나로 반환됨
+    }
+}
+
+/// 메모리 변조가 감지된 경우 오류를 보고한 뒤 프로세스를 즉시 중단합니다.
+///
+/// 변조된 프로세스는 더 이상 신뢰할 수 없으므로 복구를 시도하지 않습니다. `no_std` 환경에서는
+/// 표준 오류 출력과 `abort`가 없으므로 패닉 핸들러에 위임합니다.
+#[cold]
+#[inline(never)]
+fn tamper_abort(reason: &'static str) -> ! {
+    #[cfg(feature = "std")]
+    {
+        std::eprintln!("{}", reason);
+        std::process::abort()
+    }
+
+    #[cfg(not(feature = "std"))]
+    panic!("{}", reason)
+}
+
+/// 봉인된 [`SecureBuffer`]의 유효 데이터를 일시적으로 읽기 위한 RAII 가드입니다.
+///
+/// [`SecureBuffer::read_guard`]로 생성되며, `Drop` 시점에 버퍼를 가드 생성 이전의
+/// 보호 수준으로 다시 봉인합니다.
+pub struct ReadGuard<'a> {
+    buffer: &'a mut SecureBuffer,
+    /// 가드 해제 시 복원할 보호 수준
+    restore: MemoryProtection,
+}
+
+impl Deref for ReadGuard<'_> {
+    type Target = [u8];
+
+    #[inline(always)]
+    fn deref(&self) -> &[u8] {
+        self.buffer.as_slice()
+    }
+}
+
+impl Drop for ReadGuard<'_> {
+    fn drop(&mut self) {
+        self.buffer.reseal(self.restore);
     }
 }
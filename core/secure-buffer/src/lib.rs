@@ -1,10 +1,29 @@
 #![doc = include_str!("../README.md")]
 #![cfg_attr(not(feature = "std"), no_std)]
 
+mod arena;
 mod buffer;
 mod memory;
+#[cfg(not(feature = "std"))]
+mod platform;
+mod policy;
+mod secret;
 mod zeroize;
 
 extern crate alloc;
 
-pub use buffer::SecureBuffer;
+pub use arena::{ArenaStats, SecureArena};
+pub use buffer::{ReadGuard, SecureBuffer};
+pub use memory::{AllocationBackend, MemoryProtection, ProtectionStatus};
+#[cfg(not(feature = "std"))]
+pub use platform::{NO_STD_PAGE_SIZE, PlatformMemoryHook, set_platform_hook};
+pub use policy::{LockPolicy, MemoryStats, lock_policy, memory_stats, set_lock_policy};
+pub use secret::{SecretArray, SecretKey};
+pub use zeroize::{SecureZeroize, Zeroizer};
+
+/// 구조체/열거형의 모든 필드를 소거하는 [`SecureZeroize`] 구현을 생성합니다.
+///
+/// `#[zeroize(skip)]`이 지정된 필드는 소거 대상에서 제외됩니다.
+pub use entlib_native_zeroize_derive::SecureZeroize;
+/// `Drop` 시점에 [`SecureZeroize::secure_zeroize`]를 호출하는 구현을 생성합니다.
+pub use entlib_native_zeroize_derive::ZeroizeOnDrop;
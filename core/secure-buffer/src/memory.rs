@@ -23,9 +23,8 @@ pub(crate) fn page_size() -> usize {
 
     #[cfg(not(feature = "std"))]
     {
-        // Q. T. Felix NOTE: no_std 환경에서는 빌드 타임 타겟 환경 변수 또는 하드웨어 레지스터 직접 조회 방식 적용이 필요합니다
-        //                   현재는 안전을 위해 최소 기준인 4096을 반환하되, 실제 환경에 맞춘 엄격한 포팅이 요구됩니다.
-        4096
+        // 런타임 조회 수단이 없으므로 빌드 시점에 검증된 값을 사용
+        crate::platform::NO_STD_PAGE_SIZE
     }
 }
 
@@ -42,6 +41,8 @@ unsafe fn fetch_os_page_size() -> usize {
         in("rdi") path.as_ptr(),
         in("rsi") 0, // O_RDONLY
         lateout("rax") fd,
+        lateout("rcx") _,
+        lateout("r11") _,
         options(nostack, preserves_flags)
         );
     }
@@ -78,6 +79,8 @@ unsafe fn fetch_os_page_size() -> usize {
             in("rsi") buf.as_mut_ptr(),
             in("rdx") buf.len() * core::mem::size_of::<usize>(),
             lateout("rax") bytes_read,
+            lateout("rcx") _,
+            lateout("r11") _,
             options(nostack, preserves_flags)
             );
         }
@@ -127,6 +130,8 @@ unsafe fn fetch_os_page_size() -> usize {
         in("rax") 3, // SYS_close
         in("rdi") fd,
         lateout("rax") _,
+        lateout("rcx") _,
+        lateout("r11") _,
         options(nostack, preserves_flags)
         );
     }
@@ -169,16 +174,76 @@ fn fetch_os_page_size() -> usize {
 /// 요청된 크기를 시스템 페이지 크기의 배수로 올림 처리합니다.
 ///
 /// 메모리 할당 시 페이지 정렬(Page Alignment)을 보장하기 위해 사용됩니다.
-fn align_to_page(size: usize) -> usize {
+/// 올림 결과가 `usize` 범위를 벗어나면 `None`을 반환합니다.
+fn align_to_page(size: usize) -> Option<usize> {
     let ps = page_size();
     let remainder = size % ps;
     if remainder == 0 {
-        size
+        Some(size)
     } else {
-        size + (ps - remainder)
+        size.checked_add(ps - remainder)
     }
 }
 
+/// 보안 버퍼 페이지에 적용되는 접근 보호 수준입니다.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MemoryProtection {
+    /// 읽기/쓰기 가능 (기본 상태)
+    ReadWrite,
+    /// 읽기 전용. 쓰기 시도 시 프로세스가 `SIGSEGV`(Access Violation)로 종료됩니다.
+    ReadOnly,
+    /// 접근 불가. 읽기/쓰기 시도 시 프로세스가 `SIGSEGV`(Access Violation)로 종료됩니다.
+    NoAccess,
+}
+
+/// 보안 메모리 블록의 물리 메모리를 확보하는 방식(백엔드)입니다.
+///
+/// 요청한 백엔드를 현재 커널 또는 플랫폼이 지원하지 않으면 한 단계 약한 백엔드로
+/// 자동 대체되며, 실제로 사용된 백엔드는 [`SecureMemoryBlock::backend`] 필드로 확인할 수 있습니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AllocationBackend {
+    /// 전역 할당자(`alloc_zeroed`)로 페이지 정렬된 힙 메모리를 할당하고 잠급니다. (기본값)
+    #[default]
+    Heap,
+    /// 익명 개인 매핑(`mmap`)으로 할당하고 잠급니다. 할당자 메타데이터와 페이지를 공유하지 않습니다.
+    /// `std`가 비활성화되었거나 Unix 계열이 아니면 `Heap`으로 대체됩니다.
+    Mmap,
+    /// Linux 5.14 이상의 `memfd_secret(2)`으로 할당합니다. 해당 페이지는 커널 직접 매핑(direct map)에서
+    /// 제거되어 커널 수준의 읽기까지 차단됩니다. 시스템 호출이 `ENOSYS`를 반환하면 `Mmap`으로 대체됩니다.
+    MemfdSecret,
+}
+
+/// 보안 메모리 블록에 실제로 적용된 OS 수준 보호 상태입니다.
+///
+/// 각 보호는 플랫폼 및 커널 버전에 따라 지원 여부가 다르므로, 할당 성공 이후
+/// 어떤 보호가 실제로 적용되었는지 호출자가 확인할 수 있도록 노출됩니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ProtectionStatus {
+    /// 스왑 방지를 위한 메모리 잠금(`mlock`/`VirtualLock`) 적용 여부
+    pub locked: bool,
+    /// 코어 덤프 제외(`MADV_DONTDUMP`) 적용 여부
+    pub excluded_from_dump: bool,
+    /// `fork()` 자식 프로세스로의 노출 차단 여부. 개인 매핑은 0 초기화(`MADV_WIPEONFORK`),
+    /// `memfd_secret` 같은 공유 매핑은 상속 제외(`MADV_DONTFORK`)로 적용됩니다.
+    pub wiped_on_fork: bool,
+}
+
+/// 가드 페이지 모드에서 데이터 영역 바로 앞에 배치되는 카나리(Canary)의 크기 (바이트)
+#[cfg(all(feature = "std", unix))]
+pub(crate) const CANARY_SIZE: usize = 16;
+
+/// 가드 페이지 모드로 매핑된 전체 영역 정보입니다.
+///
+/// `[가드 페이지][데이터 페이지...][가드 페이지]` 형태로 매핑된 영역의 시작 주소와
+/// 전체 길이를 보관하며, 해제 시 `munmap` 대상이 됩니다.
+#[cfg_attr(not(all(feature = "std", unix)), allow(dead_code))]
+pub struct GuardedRegion {
+    /// 앞쪽 가드 페이지를 포함한 매핑 영역의 시작 주소
+    pub base: *mut u8,
+    /// 앞/뒤 가드 페이지를 포함한 매핑 영역의 전체 길이 (바이트)
+    pub total_len: usize,
+}
+
 /// 보안 요구사항을 충족하는 저수준 메모리 블록입니다.
 ///
 /// 이 구조체는 `Vec<u8>`과 달리, 메모리 할당 시점부터 보안을 고려하여 설계되었습니다.
@@ -187,20 +252,36 @@ fn align_to_page(size: usize) -> usize {
 /// 1. 페이지 정렬(Page Alignment): 메모리 시작 주소가 페이지 경계에 맞춰지도록 할당합니다.
 /// 2. Zero-Initialization**: 할당된 메모리는 즉시 0으로 초기화되어, 이전 데이터(Heap Residue)의 유출을 방지합니다.
 /// 3. 메모리 잠금(Memory Locking): `std` 기능 활성화 시, OS 레벨에서 메모리 페이징(Swap)을 방지합니다.
+///    Linux에서는 추가로 코어 덤프 및 `fork()` 자식 프로세스로의 노출을 차단합니다.
+/// 4. 가드 페이지(Guard Pages): [`allocate_guarded`](Self::allocate_guarded)로 할당한 경우,
+///    앞뒤에 접근 불가(`PROT_NONE`) 페이지를 두고, 데이터를 뒤쪽 가드 페이지에 맞춰 우측 정렬한 뒤
+///    데이터 영역 바로 앞에 카나리를 배치합니다.
+/// 5. 할당 백엔드 선택: [`allocate_with_backend`](Self::allocate_with_backend)로 힙, 익명 매핑,
+///    `memfd_secret` 중 하나를 선택할 수 있습니다.
 pub struct SecureMemoryBlock {
-    /// 할당된 메모리의 시작 포인터
+    /// 할당된 메모리의 시작 포인터. 가드 페이지 모드에서는 데이터가 우측 정렬되므로
+    /// 페이지 경계에 정렬되지 않을 수 있습니다.
     pub ptr: *mut u8,
-    /// 할당된 메모리의 총 용량 (바이트 단위, 페이지 정렬됨)
+    /// 할당된 메모리의 총 용량 (바이트 단위). 일반 모드에서는 페이지 정렬되며,
+    /// 가드 페이지 모드에서는 요청한 크기와 정확히 일치합니다.
     pub capacity: usize,
-    /// 메모리 할당에 사용된 레이아웃 정보 (해제 시 필요)
+    /// 메모리 할당에 사용된 레이아웃 정보 (해제 시 필요).
+    /// 가드 페이지 모드에서는 카나리를 포함한 데이터 페이지 영역을 나타냅니다.
     pub layout: Layout,
+    /// 가드 페이지 모드로 할당된 경우의 매핑 영역 정보
+    pub guard: Option<GuardedRegion>,
+    /// 할당 시 실제로 적용된 OS 수준 보호 상태
+    pub status: ProtectionStatus,
+    /// 실제로 사용된 할당 백엔드 (대체가 발생한 경우 요청과 다를 수 있음)
+    pub backend: AllocationBackend,
 }
 
 impl SecureMemoryBlock {
     /// 페이지 정렬된 메모리를 할당하고, 즉시 0으로 초기화한 뒤 OS 레벨 잠금을 시도합니다.
     ///
     /// # Arguments
-    /// - `size` - 필요한 메모리 크기 (바이트). 내부적으로 페이지 크기 배수로 올림 처리됩니다.
+    /// - `size` - 필요한 메모리 크기 (바이트). 내부적으로 페이지 크기 배수로 올림 처리되며,
+    ///   `0`이 전달되더라도 최소 1페이지가 할당됩니다.
     ///
     /// # Returns
     /// - `Ok(SecureMemoryBlock)` - 할당 및 잠금 성공 시
@@ -210,7 +291,43 @@ impl SecureMemoryBlock {
     /// 내부적으로 `alloc_zeroed`를 사용하여 초기화되지 않은 메모리 접근(UB)을 방지합니다.
     /// 하지만 OS의 메모리 잠금 제한(RLIMIT_MEMLOCK 등)에 걸릴 경우 실패할 수 있습니다.
     pub fn allocate_locked(size: usize) -> Result<Self, &'static str> {
-        let capacity = align_to_page(size);
+        Self::allocate_with_backend(size, AllocationBackend::Heap)
+    }
+
+    /// 지정한 백엔드로 페이지 정렬된 메모리를 확보하고 OS 레벨 잠금을 시도합니다.
+    ///
+    /// 요청한 백엔드가 지원되지 않으면 `MemfdSecret` → `Mmap` → `Heap` 순으로 자동 대체되며,
+    /// 실제로 사용된 백엔드는 반환된 블록의 `backend` 필드에 기록됩니다. `memfd_secret`은
+    /// `ENOSYS`(미지원 커널 또는 `secretmem` 비활성화)인 경우에만 대체되고, 그 외의 거부는 오류로 반환됩니다.
+    ///
+    /// # Arguments
+    /// - `size` - 필요한 메모리 크기 (바이트). 페이지 크기 배수로 올림 처리되며 최소 1페이지가 할당됩니다.
+    /// - `backend` - 요청할 할당 백엔드
+    ///
+    /// # Returns
+    /// - `Ok(SecureMemoryBlock)` - 할당 및 잠금 성공 시
+    /// - `Err(&'static str)` - 메모리 할당 실패, 매핑 거부 또는 잠금 실패(리소스 제한 등) 시
+    pub fn allocate_with_backend(
+        size: usize,
+        backend: AllocationBackend,
+    ) -> Result<Self, &'static str> {
+        match backend {
+            AllocationBackend::Heap => Self::allocate_heap(size),
+            #[cfg(all(feature = "std", unix))]
+            AllocationBackend::Mmap => Self::allocate_mapped(size, false),
+            #[cfg(all(feature = "std", unix))]
+            AllocationBackend::MemfdSecret => Self::allocate_mapped(size, true),
+            // 매핑 기반 백엔드를 사용할 수 없는 환경에서는 힙 백엔드로 대체
+            #[cfg(not(all(feature = "std", unix)))]
+            AllocationBackend::Mmap | AllocationBackend::MemfdSecret => Self::allocate_heap(size),
+        }
+    }
+
+    /// 전역 할당자를 통해 페이지 정렬된 힙 메모리를 할당하고 잠급니다.
+    fn allocate_heap(size: usize) -> Result<Self, &'static str> {
+        // 크기 0의 레이아웃으로 alloc_zeroed를 호출하는 것은 UB이므로 최소 1페이지를 확보
+        let capacity =
+            align_to_page(size.max(1)).ok_or("Invalid memory layout: Size or alignment error")?;
         let ps = page_size();
         // 페이지 크기로 정렬된 레이아웃 생성
         let layout = Layout::from_size_align(capacity, ps)
@@ -223,13 +340,86 @@ impl SecureMemoryBlock {
             return Err("Memory allocation failed: Out of memory");
         }
 
-        #[cfg(feature = "std")]
+        #[allow(unused_mut)]
+        let mut status = ProtectionStatus::default();
+
+        unsafe {
+            // OS별 메모리 잠금 수행 (no_std 환경에서는 플랫폼 훅에 위임)
+            // 잠금 실패 시, 할당했던 메모리를 즉시 해제하고 에러 반환
+            status.locked = match os_lock::lock_tracked(ptr, capacity) {
+                Ok(locked) => locked,
+                Err(e) => {
+                    dealloc(ptr, layout);
+                    return Err(e);
+                }
+            };
+
+            // 코어 덤프 및 fork 자식 프로세스로의 노출 차단 (미지원 환경에서는 false 유지)
+            #[cfg(feature = "std")]
+            os_advise::apply(ptr, capacity, &mut status);
+        }
+
+        Ok(Self {
+            ptr,
+            capacity,
+            layout,
+            guard: None,
+            status,
+            backend: AllocationBackend::Heap,
+        })
+    }
+
+    /// 익명 매핑 또는 `memfd_secret` 매핑으로 메모리를 확보하고 잠급니다.
+    ///
+    /// 두 방식 모두 커널이 0으로 초기화된 페이지를 제공하므로 별도의 초기화가 필요하지 않습니다.
+    #[cfg(all(feature = "std", unix))]
+    fn allocate_mapped(size: usize, secret: bool) -> Result<Self, &'static str> {
+        let capacity =
+            align_to_page(size.max(1)).ok_or("Invalid memory layout: Size or alignment error")?;
+        let layout = Layout::from_size_align(capacity, page_size())
+            .map_err(|_| "Invalid memory layout: Size or alignment error")?;
+
+        let secret_region = if secret {
+            match unsafe { os_guard::map_secret_region(capacity) } {
+                Ok(ptr) => Some(ptr),
+                // 미지원 커널 또는 secretmem 비활성화: 익명 매핑으로 대체
+                Err(os_guard::ENOSYS) => None,
+                Err(_) => {
+                    return Err("Memory allocation failed: memfd_secret mapping rejected by OS");
+                }
+            }
+        } else {
+            None
+        };
+
+        let (ptr, backend) = match secret_region {
+            Some(ptr) => (ptr, AllocationBackend::MemfdSecret),
+            None => {
+                let ptr = unsafe { os_guard::map_region(capacity) }
+                    .ok_or("Memory allocation failed: Anonymous mapping rejected by OS")?;
+                (ptr, AllocationBackend::Mmap)
+            }
+        };
+
+        let mut status = ProtectionStatus::default();
         unsafe {
-            // OS별 메모리 잠금 수행
-            if !os_lock::lock_memory(ptr, capacity) {
-                // 잠금 실패 시, 할당했던 메모리를 즉시 해제하고 에러 반환
-                dealloc(ptr, layout);
-                return Err("OS memory lock (mlock/VirtualLock) failed. Resource limit reached.");
+            // secretmem 매핑은 커널이 mmap 시점에 RLIMIT_MEMLOCK으로 과금하고 VM_LOCKED로 고정하며,
+            // 페이지 폴트 선반영(populate)이 금지되어 있어 mlock이 실패하므로 명시적 잠금을 생략
+            status.locked = if backend == AllocationBackend::MemfdSecret {
+                crate::policy::record_lock(capacity);
+                true
+            } else {
+                match os_lock::lock_tracked(ptr, capacity) {
+                    Ok(locked) => locked,
+                    Err(e) => {
+                        os_guard::unmap_region(ptr, capacity);
+                        return Err(e);
+                    }
+                }
+            };
+            os_advise::apply(ptr, capacity, &mut status);
+            if backend == AllocationBackend::MemfdSecret {
+                os_advise::exclude_from_fork(ptr, capacity, &mut status);
             }
         }
 
@@ -237,9 +427,145 @@ impl SecureMemoryBlock {
             ptr,
             capacity,
             layout,
+            guard: None,
+            status,
+            backend,
         })
     }
 
+    /// 앞뒤에 가드 페이지를 둔 메모리를 매핑하고, OS 레벨 잠금 및 카나리 기록을 수행합니다.
+    ///
+    /// libsodium의 `sodium_malloc` 모델을 따릅니다. 데이터 페이지의 앞뒤 페이지는
+    /// `PROT_NONE`으로 보호되어 인접 객체로부터의(또는 인접 객체로의) 선형 오버플로우가
+    /// 즉시 `SIGSEGV`로 차단됩니다. 데이터는 뒤쪽 가드 페이지에 맞닿도록 우측 정렬되므로
+    /// 용량을 1바이트라도 넘는 쓰기는 페이지 여유 공간에 묻히지 않고 곧바로 가드 페이지에 닿습니다.
+    /// 데이터 바로 앞의 `CANARY_SIZE` 바이트에는 프로세스 전역 무작위 카나리가 기록되며,
+    /// [`verify_canary`](Self::verify_canary)로 언더플로우 및 변조 여부를 검증할 수 있습니다.
+    ///
+    /// # Arguments
+    /// - `size` - 필요한 메모리 크기 (바이트). 블록의 용량은 `size`와 정확히 일치하며,
+    ///   데이터 페이지는 카나리 영역을 더한 뒤 페이지 크기 배수로 올림 처리됩니다.
+    ///
+    /// # Returns
+    /// - `Ok(SecureMemoryBlock)` - 매핑, 보호 설정 및 잠금 성공 시
+    /// - `Err(&'static str)` - 매핑 실패, 보호 설정 실패, 잠금 실패 또는 미지원 플랫폼인 경우
+    pub fn allocate_guarded(size: usize) -> Result<Self, &'static str> {
+        #[cfg(all(feature = "std", unix))]
+        {
+            let ps = page_size();
+            let data_len = size
+                .checked_add(CANARY_SIZE)
+                .and_then(align_to_page)
+                .ok_or("Invalid memory layout: Size or alignment error")?;
+            let total_len = data_len
+                .checked_add(2 * ps)
+                .ok_or("Invalid memory layout: Size or alignment error")?;
+            let layout = Layout::from_size_align(data_len, ps)
+                .map_err(|_| "Invalid memory layout: Size or alignment error")?;
+
+            // 익명 매핑은 커널에 의해 0으로 초기화되어 반환됨
+            let base = unsafe { os_guard::map_region(total_len) }
+                .ok_or("Memory allocation failed: Guarded mapping rejected by OS")?;
+
+            unsafe {
+                let data_page = base.add(ps);
+                let rear_guard = data_page.add(data_len);
+
+                if !os_guard::protect(base, ps, os_guard::PROT_NONE)
+                    || !os_guard::protect(rear_guard, ps, os_guard::PROT_NONE)
+                {
+                    os_guard::unmap_region(base, total_len);
+                    return Err("Guard page protection (mprotect) failed.");
+                }
+
+                let locked = match os_lock::lock_tracked(data_page, data_len) {
+                    Ok(locked) => locked,
+                    Err(e) => {
+                        os_guard::unmap_region(base, total_len);
+                        return Err(e);
+                    }
+                };
+
+                let mut status = ProtectionStatus {
+                    locked,
+                    ..ProtectionStatus::default()
+                };
+                os_advise::apply(data_page, data_len, &mut status);
+
+                // 데이터를 뒤쪽 가드 페이지에 맞춰 우측 정렬하고, 그 바로 앞에 카나리 기록
+                let ptr = rear_guard.sub(size);
+                core::ptr::copy_nonoverlapping(
+                    os_guard::canary().as_ptr(),
+                    ptr.sub(CANARY_SIZE),
+                    CANARY_SIZE,
+                );
+
+                Ok(Self {
+                    ptr,
+                    capacity: size,
+                    layout,
+                    guard: Some(GuardedRegion { base, total_len }),
+                    status,
+                    backend: AllocationBackend::Mmap,
+                })
+            }
+        }
+
+        #[cfg(not(all(feature = "std", unix)))]
+        {
+            let _ = size;
+            Err("Guarded allocation is not supported on this platform.")
+        }
+    }
+
+    /// 가드 페이지 모드로 할당된 블록인지 여부를 반환합니다.
+    #[inline(always)]
+    pub fn is_guarded(&self) -> bool {
+        self.guard.is_some()
+    }
+
+    /// 데이터 영역 바로 앞의 카나리가 변조되지 않았는지 검증합니다.
+    ///
+    /// 비교는 전체 카나리를 누적 XOR로 스캔하므로 불일치 위치에 따른 조기 종료가 없습니다.
+    /// 가드 페이지 모드가 아닌 블록은 검증 대상이 없으므로 항상 `Ok(())`를 반환합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 카나리 불일치(버퍼 오버플로우 또는 메모리 변조) 감지 시
+    pub fn verify_canary(&self) -> Result<(), &'static str> {
+        #[cfg(all(feature = "std", unix))]
+        if self.guard.is_some() {
+            let expected = os_guard::canary();
+            let mut diff = 0u8;
+            for (i, &e) in expected.iter().enumerate() {
+                // Safety: 카나리 영역은 ptr 바로 앞의 CANARY_SIZE 바이트로, 데이터 페이지 내부에 존재
+                let actual = unsafe { core::ptr::read_volatile(self.ptr.sub(CANARY_SIZE).add(i)) };
+                diff |= actual ^ e;
+            }
+            if diff != 0 {
+                return Err(
+                    "Security Violation: Guard canary corrupted. Buffer overflow or memory tampering detected.",
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 잠금 및 페이지 보호 속성 변경의 대상이 되는 페이지 정렬 영역을 반환합니다.
+    ///
+    /// 가드 페이지 모드에서는 우측 정렬된 데이터와 카나리를 포함하는 데이터 페이지 전체이며,
+    /// 그 외에는 `ptr`부터 레이아웃 크기만큼의 영역입니다.
+    #[cfg_attr(not(feature = "std"), allow(dead_code))]
+    pub fn page_region(&self) -> (*mut u8, usize) {
+        #[cfg(all(feature = "std", unix))]
+        if let Some(region) = &self.guard {
+            // Safety: 데이터 페이지는 앞쪽 가드 페이지 바로 뒤에서 시작하며 매핑 내부에 존재
+            return (unsafe { region.base.add(page_size()) }, self.layout.size());
+        }
+
+        (self.ptr, self.layout.size())
+    }
+
     /// 메모리 잠금을 해제하고 할당을 취소(해제)하는 함수입니다.
     ///
     /// # Safety
@@ -247,10 +573,42 @@ impl SecureMemoryBlock {
     /// - 이미 해제된 메모리에 대해 호출하면 Double Free 오류가 발생합니다.
     /// - 호출 전, 메모리 내용 소거는 별도로 수행되어야 합니다. (이 함수는 소거를 수행하지 않음)
     pub unsafe fn deallocate_unlocked(&self) {
-        #[cfg(feature = "std")]
+        #[cfg(all(feature = "std", unix))]
+        if let Some(region) = &self.guard {
+            // 가드 페이지 모드: 데이터 페이지 잠금 해제 후 가드 페이지를 포함한 전체 매핑 해제
+            unsafe {
+                if self.status.locked {
+                    let (data_page, data_len) = self.page_region();
+                    os_lock::unlock_tracked(data_page, data_len);
+                }
+                os_guard::unmap_region(region.base, region.total_len);
+            }
+            return;
+        }
+
+        #[cfg(all(feature = "std", unix))]
+        if self.backend != AllocationBackend::Heap {
+            // 매핑 기반 백엔드: 잠금 해제 후 매핑 해제 (페이지는 커널로 반환되어 재사용되지 않음)
+            unsafe {
+                if self.backend == AllocationBackend::MemfdSecret {
+                    // 커널이 잠근 secretmem 매핑은 munmap 시점에 과금이 해제됨
+                    crate::policy::record_unlock(self.capacity);
+                } else if self.status.locked {
+                    os_lock::unlock_tracked(self.ptr, self.capacity);
+                }
+                os_guard::unmap_region(self.ptr, self.capacity);
+            }
+            return;
+        }
+
         // 메모리 잠금 해제 (페이지 아웃 허용)
         unsafe {
-            os_lock::unlock_memory(self.ptr, self.capacity);
+            if self.status.locked {
+                os_lock::unlock_tracked(self.ptr, self.capacity);
+            }
+            // 힙 페이지는 할당자에 재사용되므로 덤프/fork 속성을 기본값으로 복원
+            #[cfg(feature = "std")]
+            os_advise::revert(self.ptr, self.capacity, &self.status);
         }
 
         // 메모리 할당 해제
@@ -263,8 +621,35 @@ impl SecureMemoryBlock {
 /// OS별 메모리 잠금/해제 구현 모듈
 #[cfg(feature = "std")]
 pub(crate) mod os_lock {
+    use crate::policy::{self, LockPolicy};
     use core::ffi::c_void;
 
+    /// 전역 잠금 정책에 따라 메모리 잠금을 수행하고, 결과를 전역 사용 현황에 반영합니다.
+    ///
+    /// # Returns
+    /// - `Ok(true)` - 잠금 성공 시
+    /// - `Ok(false)` - 잠금에 실패했으나 [`LockPolicy::AllowUnlocked`] 정책에 의해 허용된 경우
+    /// - `Err(&'static str)` - 잠금 실패 시
+    pub unsafe fn lock_tracked(ptr: *mut u8, len: usize) -> Result<bool, &'static str> {
+        if unsafe { lock_memory(ptr, len) } {
+            policy::record_lock(len);
+            return Ok(true);
+        }
+
+        if policy::lock_policy() == LockPolicy::AllowUnlocked {
+            policy::record_unlocked_allocation();
+            return Ok(false);
+        }
+
+        Err("OS memory lock (mlock/VirtualLock) failed. Resource limit reached.")
+    }
+
+    /// [`lock_tracked`]로 잠금에 성공한 영역의 잠금을 해제하고, 전역 사용 현황에 반영합니다.
+    pub unsafe fn unlock_tracked(ptr: *mut u8, len: usize) {
+        unsafe { unlock_memory(ptr, len) };
+        policy::record_unlock(len);
+    }
+
     #[cfg(unix)]
     unsafe extern "C" {
         fn mlock(addr: *const c_void, len: usize) -> i32;
@@ -280,7 +665,8 @@ pub(crate) mod os_lock {
     /// Unix 계열(Linux, macOS 등)에서의 메모리 잠금 구현
     ///
     /// `mlock` 시스템 콜을 사용하여 지정된 범위의 가상 주소 공간을 RAM에 고정합니다.
-    /// 성공 시 `true`, 실패 시 `false`를 반환합니다.
+    /// 성공 시 `true`, 실패 시 `false`를 반환합니다. 전역 정책이 [`LockPolicy::RaiseLimit`]인
+    /// 경우에만 Linux에서 `RLIMIT_MEMLOCK` 상향 후 재시도합니다.
     #[cfg(unix)]
     pub unsafe fn lock_memory(ptr: *mut u8, len: usize) -> bool {
         // 1차 잠금 시도
@@ -289,9 +675,9 @@ pub(crate) mod os_lock {
         }
 
         // 1차 실패
-        // os 리소스 제한 동적 해제 시도
+        // 전역 정책이 허용하는 경우에만 os 리소스 제한 동적 해제 시도
         #[cfg(target_os = "linux")]
-        {
+        if crate::policy::lock_policy() == LockPolicy::RaiseLimit {
             #[repr(C)]
             struct Rlimit {
                 rlim_cur: u64,
@@ -299,8 +685,8 @@ pub(crate) mod os_lock {
             }
 
             unsafe extern "C" {
-                fn get_rlimit(resource: i32, rlim: *mut Rlimit) -> i32;
-                fn set_rlimit(resource: i32, rlim: *const Rlimit) -> i32;
+                fn getrlimit(resource: i32, rlim: *mut Rlimit) -> i32;
+                fn setrlimit(resource: i32, rlim: *const Rlimit) -> i32;
             }
 
             const RLIMIT_MEMLOCK: i32 = 8;
@@ -312,12 +698,12 @@ pub(crate) mod os_lock {
             };
 
             unsafe {
-                if get_rlimit(RLIMIT_MEMLOCK, &mut rlim) == 0 {
+                if getrlimit(RLIMIT_MEMLOCK, &mut rlim) == 0 {
                     rlim.rlim_cur = RLIM_INFINITY;
                     rlim.rlim_max = RLIM_INFINITY;
 
                     // 한도 상향 성공 시 2차 잠금 재시도
-                    if set_rlimit(RLIMIT_MEMLOCK, &rlim) == 0 {
+                    if setrlimit(RLIMIT_MEMLOCK, &rlim) == 0 {
                         return mlock(ptr as *const c_void, len) == 0;
                     }
                 }
@@ -354,3 +740,440 @@ pub(crate) mod os_lock {
         VirtualUnlock(ptr as *const c_void, len);
     }
 }
+
+/// `no_std` 환경의 메모리 잠금/해제 구현 모듈
+///
+/// OS 잠금 API가 없으므로 [`set_platform_hook`](crate::set_platform_hook)으로 등록된
+/// 플랫폼 훅에 위임합니다. 훅이 등록되지 않은 경우 잠금 없이 할당을 진행합니다.
+#[cfg(not(feature = "std"))]
+pub(crate) mod os_lock {
+    use crate::platform::platform_hook;
+    use crate::policy::{self, LockPolicy};
+
+    /// 플랫폼 훅으로 메모리 잠금을 수행하고, 결과를 전역 사용 현황에 반영합니다.
+    ///
+    /// # Returns
+    /// - `Ok(true)` - 잠금 성공 시
+    /// - `Ok(false)` - 훅이 등록되지 않았거나, 잠금에 실패했으나 [`LockPolicy::AllowUnlocked`] 정책에 의해 허용된 경우
+    /// - `Err(&'static str)` - 잠금 실패 시
+    pub unsafe fn lock_tracked(ptr: *mut u8, len: usize) -> Result<bool, &'static str> {
+        let Some(hook) = platform_hook() else {
+            return Ok(false);
+        };
+
+        if unsafe { hook.lock(ptr, len) } {
+            policy::record_lock(len);
+            return Ok(true);
+        }
+
+        // 상향할 리소스 한도가 없으므로 RaiseLimit은 FailHard와 동일하게 처리
+        if policy::lock_policy() == LockPolicy::AllowUnlocked {
+            policy::record_unlocked_allocation();
+            return Ok(false);
+        }
+
+        Err("Platform memory lock hook failed.")
+    }
+
+    /// [`lock_tracked`]로 잠금에 성공한 영역의 잠금을 해제하고, 전역 사용 현황에 반영합니다.
+    pub unsafe fn unlock_tracked(ptr: *mut u8, len: usize) {
+        if let Some(hook) = platform_hook() {
+            unsafe { hook.unlock(ptr, len) };
+        }
+        policy::record_unlock(len);
+    }
+}
+
+/// 가드 페이지 매핑 및 카나리 생성 구현 모듈
+///
+/// Linux에서는 `fetch_os_page_size`와 동일하게 libc를 거치지 않고 원시 시스템 호출로
+/// `mmap`/`mprotect`/`munmap`/`getrandom`을 수행합니다. 그 외 Unix 계열에서는 POSIX API를 사용합니다.
+#[cfg(all(feature = "std", unix))]
+pub(crate) mod os_guard {
+    use super::CANARY_SIZE;
+    use std::sync::OnceLock;
+
+    pub const PROT_NONE: usize = 0;
+    pub const PROT_READ: usize = 1;
+    pub const PROT_WRITE: usize = 2;
+    const MAP_SHARED: usize = 0x01;
+    const MAP_PRIVATE: usize = 0x02;
+    #[cfg(target_os = "linux")]
+    const MAP_ANONYMOUS: usize = 0x20;
+    #[cfg(not(target_os = "linux"))]
+    const MAP_ANONYMOUS: usize = 0x1000;
+
+    #[cfg(all(target_os = "linux", target_arch = "x86_64"))]
+    pub(super) mod nr {
+        pub const MMAP: usize = 9;
+        pub const MPROTECT: usize = 10;
+        pub const MUNMAP: usize = 11;
+        pub const GETRANDOM: usize = 318;
+        pub const MADVISE: usize = 28;
+        pub const FTRUNCATE: usize = 77;
+        pub const CLOSE: usize = 3;
+        pub const MEMFD_SECRET: usize = 447;
+    }
+
+    #[cfg(all(target_os = "linux", target_arch = "aarch64"))]
+    pub(super) mod nr {
+        pub const MMAP: usize = 222;
+        pub const MPROTECT: usize = 226;
+        pub const MUNMAP: usize = 215;
+        pub const GETRANDOM: usize = 278;
+        pub const MADVISE: usize = 233;
+        pub const FTRUNCATE: usize = 46;
+        pub const CLOSE: usize = 57;
+        pub const MEMFD_SECRET: usize = 447;
+    }
+
+    /// 인자 6개까지의 원시 시스템 호출을 수행합니다. 실패 시 음수 errno가 반환됩니다.
+    #[cfg(target_os = "linux")]
+    pub(super) unsafe fn syscall6(
+        n: usize,
+        a0: usize,
+        a1: usize,
+        a2: usize,
+        a3: usize,
+        a4: usize,
+        a5: usize,
+    ) -> isize {
+        let ret: isize;
+
+        #[cfg(target_arch = "x86_64")]
+        unsafe {
+            core::arch::asm!("syscall",
+            inlateout("rax") n as isize => ret,
+            in("rdi") a0,
+            in("rsi") a1,
+            in("rdx") a2,
+            in("r10") a3,
+            in("r8") a4,
+            in("r9") a5,
+            lateout("rcx") _,
+            lateout("r11") _,
+            options(nostack, preserves_flags)
+            );
+        }
+
+        #[cfg(target_arch = "aarch64")]
+        unsafe {
+            core::arch::asm!("svc #0",
+            in("x8") n,
+            inlateout("x0") a0 as isize => ret,
+            in("x1") a1,
+            in("x2") a2,
+            in("x3") a3,
+            in("x4") a4,
+            in("x5") a5,
+            options(nostack, preserves_flags)
+            );
+        }
+
+        ret
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    unsafe extern "C" {
+        fn mmap(
+            addr: *mut core::ffi::c_void,
+            len: usize,
+            prot: i32,
+            flags: i32,
+            fd: i32,
+            offset: i64,
+        ) -> *mut core::ffi::c_void;
+        fn mprotect(addr: *mut core::ffi::c_void, len: usize, prot: i32) -> i32;
+        fn munmap(addr: *mut core::ffi::c_void, len: usize) -> i32;
+        fn getentropy(buf: *mut core::ffi::c_void, len: usize) -> i32;
+    }
+
+    /// 읽기/쓰기 가능한 익명 개인 매핑을 생성합니다. 실패 시 `None`을 반환합니다.
+    pub unsafe fn map_region(len: usize) -> Option<*mut u8> {
+        #[cfg(target_os = "linux")]
+        let ret = unsafe {
+            syscall6(
+                nr::MMAP,
+                0,
+                len,
+                PROT_READ | PROT_WRITE,
+                MAP_PRIVATE | MAP_ANONYMOUS,
+                usize::MAX, // fd = -1
+                0,
+            )
+        };
+
+        #[cfg(not(target_os = "linux"))]
+        let ret = unsafe {
+            mmap(
+                core::ptr::null_mut(),
+                len,
+                (PROT_READ | PROT_WRITE) as i32,
+                (MAP_PRIVATE | MAP_ANONYMOUS) as i32,
+                -1,
+                0,
+            ) as isize
+        };
+
+        // 커널은 실패 시 [-4095, -1] 범위의 errno(또는 MAP_FAILED = -1)를 반환
+        if (-4095..0).contains(&ret) {
+            None
+        } else {
+            Some(ret as *mut u8)
+        }
+    }
+
+    /// `ENOSYS` (시스템 호출 미지원)
+    pub const ENOSYS: isize = 38;
+
+    /// `memfd_secret(2)`으로 생성한 비밀 메모리를 공유 매핑으로 확보합니다.
+    ///
+    /// 매핑 이후 파일 디스크립터는 즉시 닫으며, 매핑이 유지되는 동안 메모리는 계속 유효합니다.
+    /// 실패 시 양수 errno를 반환합니다. Linux 이외의 플랫폼에서는 항상 `ENOSYS`를 반환합니다.
+    #[cfg(target_os = "linux")]
+    pub unsafe fn map_secret_region(len: usize) -> Result<*mut u8, isize> {
+        const O_CLOEXEC: usize = 0o2000000;
+
+        unsafe {
+            let fd = syscall6(nr::MEMFD_SECRET, O_CLOEXEC, 0, 0, 0, 0, 0);
+            if fd < 0 {
+                return Err(-fd);
+            }
+
+            let ret = syscall6(nr::FTRUNCATE, fd as usize, len, 0, 0, 0, 0);
+            if ret < 0 {
+                syscall6(nr::CLOSE, fd as usize, 0, 0, 0, 0, 0);
+                return Err(-ret);
+            }
+
+            let ret = syscall6(
+                nr::MMAP,
+                0,
+                len,
+                PROT_READ | PROT_WRITE,
+                MAP_SHARED,
+                fd as usize,
+                0,
+            );
+            syscall6(nr::CLOSE, fd as usize, 0, 0, 0, 0, 0);
+
+            if (-4095..0).contains(&ret) {
+                Err(-ret)
+            } else {
+                Ok(ret as *mut u8)
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub unsafe fn map_secret_region(_len: usize) -> Result<*mut u8, isize> {
+        Err(ENOSYS)
+    }
+
+    /// 지정된 범위의 페이지 보호 속성을 `prot`(`PROT_*` 조합)으로 전환합니다.
+    pub unsafe fn protect(ptr: *mut u8, len: usize, prot: usize) -> bool {
+        #[cfg(target_os = "linux")]
+        unsafe {
+            syscall6(nr::MPROTECT, ptr as usize, len, prot, 0, 0, 0) == 0
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        unsafe {
+            mprotect(ptr as *mut core::ffi::c_void, len, prot as i32) == 0
+        }
+    }
+
+    /// 매핑 영역을 해제합니다.
+    pub unsafe fn unmap_region(ptr: *mut u8, len: usize) {
+        #[cfg(target_os = "linux")]
+        unsafe {
+            syscall6(nr::MUNMAP, ptr as usize, len, 0, 0, 0, 0);
+        }
+
+        #[cfg(not(target_os = "linux"))]
+        unsafe {
+            munmap(ptr as *mut core::ffi::c_void, len);
+        }
+    }
+
+    /// 프로세스 전역 카나리 값을 반환합니다.
+    ///
+    /// 최초 호출 시 커널 CSPRNG(`getrandom`/`getentropy`)로부터 한 번만 생성됩니다.
+    /// 커널이 난수 제공을 거부하면 예측 가능한 카나리로 동작하지 않도록 패닉을 발생시킵니다.
+    pub fn canary() -> &'static [u8; CANARY_SIZE] {
+        static CANARY: OnceLock<[u8; CANARY_SIZE]> = OnceLock::new();
+        CANARY.get_or_init(|| {
+            let mut buf = [0u8; CANARY_SIZE];
+            let mut filled = 0;
+
+            while filled < CANARY_SIZE {
+                #[cfg(target_os = "linux")]
+                let ret = unsafe {
+                    syscall6(
+                        nr::GETRANDOM,
+                        buf.as_mut_ptr().wrapping_add(filled) as usize,
+                        CANARY_SIZE - filled,
+                        0,
+                        0,
+                        0,
+                        0,
+                    )
+                };
+
+                #[cfg(not(target_os = "linux"))]
+                let ret = unsafe {
+                    if getentropy(buf.as_mut_ptr() as *mut core::ffi::c_void, CANARY_SIZE) == 0 {
+                        CANARY_SIZE as isize
+                    } else {
+                        -1
+                    }
+                };
+
+                if ret == -4 {
+                    // EINTR: 시그널에 의해 중단된 경우 재시도
+                    continue;
+                }
+                if ret <= 0 {
+                    panic!("Critical Fault: 커널이 카나리 생성을 위한 난수 제공을 거부했습니다!");
+                }
+                filled += ret as usize;
+            }
+
+            buf
+        })
+    }
+}
+
+/// OS별 페이지 보호 속성 변경 구현 모듈
+#[cfg(feature = "std")]
+pub(crate) mod os_protect {
+    use super::MemoryProtection;
+
+    #[cfg(windows)]
+    unsafe extern "system" {
+        fn VirtualProtect(
+            lpAddress: *mut core::ffi::c_void,
+            dwSize: usize,
+            flNewProtect: u32,
+            lpflOldProtect: *mut u32,
+        ) -> i32;
+    }
+
+    /// Unix 계열에서의 페이지 보호 속성 변경 구현
+    ///
+    /// `mprotect` 시스템 콜을 사용합니다. 성공 시 `true`, 실패 시 `false`를 반환합니다.
+    #[cfg(unix)]
+    pub unsafe fn set_protection(ptr: *mut u8, len: usize, protection: MemoryProtection) -> bool {
+        use super::os_guard::{PROT_NONE, PROT_READ, PROT_WRITE};
+
+        let prot = match protection {
+            MemoryProtection::ReadWrite => PROT_READ | PROT_WRITE,
+            MemoryProtection::ReadOnly => PROT_READ,
+            MemoryProtection::NoAccess => PROT_NONE,
+        };
+        unsafe { super::os_guard::protect(ptr, len, prot) }
+    }
+
+    /// Windows에서의 페이지 보호 속성 변경 구현
+    ///
+    /// `VirtualProtect` API를 사용합니다. 성공 시 `true`, 실패 시 `false`를 반환합니다.
+    #[cfg(windows)]
+    pub unsafe fn set_protection(ptr: *mut u8, len: usize, protection: MemoryProtection) -> bool {
+        const PAGE_NOACCESS: u32 = 0x01;
+        const PAGE_READONLY: u32 = 0x02;
+        const PAGE_READWRITE: u32 = 0x04;
+
+        let new_protect = match protection {
+            MemoryProtection::ReadWrite => PAGE_READWRITE,
+            MemoryProtection::ReadOnly => PAGE_READONLY,
+            MemoryProtection::NoAccess => PAGE_NOACCESS,
+        };
+        let mut old_protect = 0u32;
+        unsafe {
+            VirtualProtect(
+                ptr as *mut core::ffi::c_void,
+                len,
+                new_protect,
+                &mut old_protect,
+            ) != 0
+        }
+    }
+}
+
+/// 코어 덤프 및 `fork()` 노출 차단 구현 모듈
+///
+/// Linux에서는 원시 시스템 호출로 `madvise`를 수행합니다. `MADV_WIPEONFORK`는 커널 4.14 이상에서만
+/// 지원되므로, 실패하더라도 할당 자체는 실패시키지 않고 [`ProtectionStatus`]에 결과만 기록합니다.
+/// 그 외 플랫폼에서는 아무 작업도 수행하지 않습니다.
+#[cfg(feature = "std")]
+pub(crate) mod os_advise {
+    use super::ProtectionStatus;
+
+    #[cfg(target_os = "linux")]
+    const MADV_DONTFORK: usize = 10;
+    #[cfg(target_os = "linux")]
+    const MADV_DONTDUMP: usize = 16;
+    #[cfg(target_os = "linux")]
+    const MADV_DODUMP: usize = 17;
+    #[cfg(target_os = "linux")]
+    const MADV_WIPEONFORK: usize = 18;
+    #[cfg(target_os = "linux")]
+    const MADV_KEEPONFORK: usize = 19;
+
+    #[cfg(target_os = "linux")]
+    unsafe fn madvise(ptr: *mut u8, len: usize, advice: usize) -> bool {
+        unsafe {
+            super::os_guard::syscall6(
+                super::os_guard::nr::MADVISE,
+                ptr as usize,
+                len,
+                advice,
+                0,
+                0,
+                0,
+            ) == 0
+        }
+    }
+
+    /// 페이지 정렬된 영역에 덤프 제외 및 fork 시 0 초기화를 적용하고 결과를 기록합니다.
+    #[cfg(target_os = "linux")]
+    pub unsafe fn apply(ptr: *mut u8, len: usize, status: &mut ProtectionStatus) {
+        unsafe {
+            status.excluded_from_dump = madvise(ptr, len, MADV_DONTDUMP);
+            status.wiped_on_fork = madvise(ptr, len, MADV_WIPEONFORK);
+        }
+    }
+
+    /// 공유 매핑에 대해 `fork()` 시 상속 제외(`MADV_DONTFORK`)를 적용하고 결과를 기록합니다.
+    ///
+    /// `MADV_WIPEONFORK`는 개인 익명 매핑에만 적용되므로, 공유 매핑은 자식 프로세스에서
+    /// 매핑 자체가 사라지도록 처리합니다. 힙 페이지에는 사용해서는 안 됩니다.
+    #[cfg(target_os = "linux")]
+    pub unsafe fn exclude_from_fork(ptr: *mut u8, len: usize, status: &mut ProtectionStatus) {
+        unsafe {
+            status.wiped_on_fork = madvise(ptr, len, MADV_DONTFORK);
+        }
+    }
+
+    /// 적용했던 속성을 기본값(`MADV_DODUMP`, `MADV_KEEPONFORK`)으로 되돌립니다.
+    #[cfg(target_os = "linux")]
+    pub unsafe fn revert(ptr: *mut u8, len: usize, status: &ProtectionStatus) {
+        unsafe {
+            if status.excluded_from_dump {
+                madvise(ptr, len, MADV_DODUMP);
+            }
+            if status.wiped_on_fork {
+                madvise(ptr, len, MADV_KEEPONFORK);
+            }
+        }
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    pub unsafe fn apply(_ptr: *mut u8, _len: usize, _status: &mut ProtectionStatus) {}
+
+    #[cfg(not(target_os = "linux"))]
+    pub unsafe fn exclude_from_fork(_ptr: *mut u8, _len: usize, _status: &mut ProtectionStatus) {}
+
+    #[cfg(not(target_os = "linux"))]
+    pub unsafe fn revert(_ptr: *mut u8, _len: usize, _status: &ProtectionStatus) {}
+}
@@ -0,0 +1,131 @@
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// `no_std` 환경에서 사용하는 페이지 크기 (바이트)
+///
+/// 런타임 조회 수단이 없는 베어메탈 환경을 위해 빌드 시점의 `ENTLIB_NO_STD_PAGE_SIZE` 환경 변수로
+/// 지정하며, 지정하지 않으면 4096을 사용합니다. 값은 MPU/PMP 영역의 최소 단위를 고려하여
+/// 32 이상의 2의 거듭제곱이어야 하며, 조건을 만족하지 않으면 컴파일이 실패합니다.
+///
+/// ```text
+/// ENTLIB_NO_STD_PAGE_SIZE=1024 cargo build --target thumbv7em-none-eabihf
+/// ```
+pub const NO_STD_PAGE_SIZE: usize = match option_env!("ENTLIB_NO_STD_PAGE_SIZE") {
+    Some(value) => parse_page_size(value),
+    None => 4096,
+};
+
+/// 빌드 시점에 주입된 페이지 크기 문자열을 검증하며 해석합니다.
+const fn parse_page_size(value: &str) -> usize {
+    let bytes = value.as_bytes();
+    if bytes.is_empty() {
+        panic!("ENTLIB_NO_STD_PAGE_SIZE must not be empty");
+    }
+
+    let mut size = 0usize;
+    let mut i = 0;
+    while i < bytes.len() {
+        let digit = bytes[i];
+        if !digit.is_ascii_digit() {
+            panic!("ENTLIB_NO_STD_PAGE_SIZE must be a decimal integer");
+        }
+        size = match size.checked_mul(10) {
+            Some(v) => match v.checked_add((digit - b'0') as usize) {
+                Some(v) => v,
+                None => panic!("ENTLIB_NO_STD_PAGE_SIZE overflows usize"),
+            },
+            None => panic!("ENTLIB_NO_STD_PAGE_SIZE overflows usize"),
+        };
+        i += 1;
+    }
+
+    if size < 32 || !size.is_power_of_two() {
+        panic!("ENTLIB_NO_STD_PAGE_SIZE must be a power of two and at least 32");
+    }
+    size
+}
+
+/// `no_std` 환경에서 메모리 잠금 및 캐시 정리를 플랫폼에 위임하기 위한 훅 트레이트입니다.
+///
+/// OS가 없는 환경에서는 `mlock`에 해당하는 기능이 존재하지 않으므로, MPU/PMP 영역 설정이나
+/// 보안 SRAM 뱅크 전환처럼 대상 하드웨어에 맞는 보호 방식을 펌웨어가 직접 구현하여
+/// [`set_platform_hook`]으로 등록합니다. 등록된 훅은 모든 보안 메모리 블록 할당/해제 시점에
+/// 호출되며, 훅이 없으면 기존과 동일하게 잠금 없이 할당됩니다.
+///
+/// 잠금 실패 시의 동작은 전역 [`LockPolicy`](crate::LockPolicy)를 따르며, `RaiseLimit`은
+/// 상향할 리소스 한도가 없으므로 `FailHard`와 동일하게 동작합니다.
+pub trait PlatformMemoryHook: Sync {
+    /// 페이지 정렬된 `[ptr, ptr + len)` 영역을 보호합니다. 성공 시 `true`를 반환합니다.
+    ///
+    /// # Safety
+    /// `ptr`은 [`NO_STD_PAGE_SIZE`] 경계에 정렬된 유효한 메모리이며, `len`은 그 배수입니다.
+    unsafe fn lock(&self, ptr: *mut u8, len: usize) -> bool;
+
+    /// [`lock`](Self::lock)으로 보호한 영역의 보호를 해제합니다.
+    ///
+    /// 호출 시점에 영역은 이미 소거가 완료된 상태입니다.
+    ///
+    /// # Safety
+    /// `ptr`과 `len`은 이전에 `lock`이 성공한 영역과 동일해야 합니다.
+    unsafe fn unlock(&self, ptr: *mut u8, len: usize);
+
+    /// 소거 직후 `[ptr, ptr + len)` 영역의 데이터 캐시를 정리(clean and invalidate)합니다.
+    ///
+    /// Cortex-M7의 `SCB` 캐시 유지보수 레지스터나 RISC-V `Zicbom`처럼 캐시 조작 방식이
+    /// 코어마다 다르므로 플랫폼이 구현합니다. 데이터 캐시가 없는 코어는 기본 구현(아무 작업 없음)을 사용합니다.
+    ///
+    /// # Safety
+    /// `ptr`은 `len` 바이트만큼 유효한 메모리여야 합니다.
+    unsafe fn clean_cache(&self, ptr: *mut u8, len: usize) {
+        let _ = (ptr, len);
+    }
+}
+
+const HOOK_UNSET: u8 = 0;
+const HOOK_WRITING: u8 = 1;
+const HOOK_READY: u8 = 2;
+
+/// 단 한 번만 기록되는 훅 저장소입니다.
+struct HookSlot(UnsafeCell<Option<&'static dyn PlatformMemoryHook>>);
+
+// Safety: 기록은 HOOK_STATE의 compare_exchange로 단 한 번만 허용되며,
+//         읽기는 HOOK_READY가 Release로 게시된 이후에만 수행됨
+unsafe impl Sync for HookSlot {}
+
+static HOOK_STATE: AtomicU8 = AtomicU8::new(HOOK_UNSET);
+static HOOK: HookSlot = HookSlot(UnsafeCell::new(None));
+
+/// 프로세스(펌웨어) 전역 플랫폼 메모리 훅을 등록합니다.
+///
+/// 보호 정책이 런타임에 바뀌는 것을 막기 위해 최초 1회만 등록할 수 있으며,
+/// 이미 할당된 버퍼에는 적용되지 않으므로 첫 보안 버퍼 할당 이전에 호출해야 합니다.
+///
+/// # Returns
+/// - `Err(&'static str)` - 이미 훅이 등록된 경우
+pub fn set_platform_hook(hook: &'static dyn PlatformMemoryHook) -> Result<(), &'static str> {
+    if HOOK_STATE
+        .compare_exchange(
+            HOOK_UNSET,
+            HOOK_WRITING,
+            Ordering::Acquire,
+            Ordering::Relaxed,
+        )
+        .is_err()
+    {
+        return Err("Platform memory hook is already registered.");
+    }
+
+    // Safety: compare_exchange에 성공한 단일 호출자만 이 지점에 도달함
+    unsafe { *HOOK.0.get() = Some(hook) };
+    HOOK_STATE.store(HOOK_READY, Ordering::Release);
+    Ok(())
+}
+
+/// 등록된 플랫폼 메모리 훅을 반환합니다.
+pub(crate) fn platform_hook() -> Option<&'static dyn PlatformMemoryHook> {
+    if HOOK_STATE.load(Ordering::Acquire) != HOOK_READY {
+        return None;
+    }
+    // Safety: HOOK_READY 이후에는 저장소가 다시 기록되지 않음
+    unsafe { *HOOK.0.get() }
+}
@@ -0,0 +1,105 @@
+use core::sync::atomic::{AtomicU8, AtomicUsize, Ordering};
+
+/// OS 메모리 잠금(`mlock`/`VirtualLock`) 실패 시 적용할 프로세스 전역 정책입니다.
+///
+/// 공유 컨테이너처럼 리소스 한도를 운영 측에서 통제하는 환경에서는 라이브러리가 임의로
+/// `RLIMIT_MEMLOCK`을 상향하는 동작이 바람직하지 않을 수 있으므로, [`set_lock_policy`]로
+/// 프로세스 시작 시점에 정책을 명시적으로 선택할 수 있습니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(u8)]
+pub enum LockPolicy {
+    /// 잠금 실패 시 즉시 할당을 실패시킵니다. 리소스 한도는 변경하지 않습니다.
+    FailHard = 0,
+    /// 잠금 실패 시 `RLIMIT_MEMLOCK`을 `RLIM_INFINITY`로 상향한 뒤 한 번 더 시도합니다. (기본값)
+    ///
+    /// 한도 상향은 Linux에서만 시도되며, 권한이 없으면 `FailHard`와 동일하게 실패합니다.
+    #[default]
+    RaiseLimit = 1,
+    /// 잠금 실패 시에도 잠기지 않은 상태로 할당을 허용합니다.
+    ///
+    /// 해당 버퍼는 [`ProtectionStatus::locked`](crate::ProtectionStatus::locked)가 `false`로
+    /// 보고되며, [`MemoryStats::unlocked_allocations`]가 증가합니다.
+    ///
+    /// 외부 메모리(`SecureBuffer::from_raw_parts`)는 잠금 여부를 추적할 수 없으므로 이 정책에서도 거부됩니다.
+    AllowUnlocked = 2,
+}
+
+impl LockPolicy {
+    /// FFI 경계에서 전달된 정수 값을 정책으로 변환합니다. 알 수 없는 값은 `None`을 반환합니다.
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::FailHard),
+            1 => Some(Self::RaiseLimit),
+            2 => Some(Self::AllowUnlocked),
+            _ => None,
+        }
+    }
+}
+
+/// 프로세스 전역 보안 메모리 사용 현황입니다.
+///
+/// 모든 값은 [`memory_stats`] 호출 시점의 스냅샷이며, 각 필드는 개별적으로 원자적으로 읽히므로
+/// 동시 할당이 진행 중인 경우 필드 간 일관성은 보장되지 않습니다.
+///
+/// 집계 대상은 이 크레이트가 할당한 메모리(개별 블록, 아레나 영역 및 슬롯)이며, FFI 등 외부에서
+/// 주입된 메모리는 원래 소유자가 생명주기를 관리하므로 제외됩니다.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    /// 현재 OS 레벨에서 잠겨 있는 메모리 크기 (바이트). 아레나 영역을 포함합니다.
+    pub locked_bytes: usize,
+    /// 프로세스 시작 이후 동시에 잠긴 최대 메모리 크기 (바이트)
+    pub peak_locked_bytes: usize,
+    /// 현재 살아 있는 [`SecureBuffer`](crate::SecureBuffer) 인스턴스 수 (아레나 슬롯 포함, 외부 메모리 래핑 제외)
+    pub live_buffers: usize,
+    /// [`LockPolicy::AllowUnlocked`] 정책에 의해 잠금 없이 진행된 할당의 누적 횟수
+    pub unlocked_allocations: usize,
+}
+
+static LOCK_POLICY: AtomicU8 = AtomicU8::new(LockPolicy::RaiseLimit as u8);
+static LOCKED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LOCKED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BUFFERS: AtomicUsize = AtomicUsize::new(0);
+static UNLOCKED_ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// 프로세스 전역 메모리 잠금 정책을 설정합니다.
+///
+/// 이미 할당된 버퍼에는 영향을 주지 않으며, 이후의 잠금 시도부터 적용됩니다.
+pub fn set_lock_policy(policy: LockPolicy) {
+    LOCK_POLICY.store(policy as u8, Ordering::Relaxed);
+}
+
+/// 현재 프로세스 전역 메모리 잠금 정책을 반환합니다.
+pub fn lock_policy() -> LockPolicy {
+    LockPolicy::from_u8(LOCK_POLICY.load(Ordering::Relaxed)).unwrap_or_default()
+}
+
+/// 프로세스 전역 보안 메모리 사용 현황을 반환합니다.
+pub fn memory_stats() -> MemoryStats {
+    MemoryStats {
+        locked_bytes: LOCKED_BYTES.load(Ordering::Relaxed),
+        peak_locked_bytes: PEAK_LOCKED_BYTES.load(Ordering::Relaxed),
+        live_buffers: LIVE_BUFFERS.load(Ordering::Relaxed),
+        unlocked_allocations: UNLOCKED_ALLOCATIONS.load(Ordering::Relaxed),
+    }
+}
+
+pub(crate) fn record_lock(len: usize) {
+    let locked = LOCKED_BYTES.fetch_add(len, Ordering::Relaxed) + len;
+    PEAK_LOCKED_BYTES.fetch_max(locked, Ordering::Relaxed);
+}
+
+pub(crate) fn record_unlock(len: usize) {
+    LOCKED_BYTES.fetch_sub(len, Ordering::Relaxed);
+}
+
+pub(crate) fn record_unlocked_allocation() {
+    UNLOCKED_ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_buffer_created() {
+    LIVE_BUFFERS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn record_buffer_dropped() {
+    LIVE_BUFFERS.fetch_sub(1, Ordering::Relaxed);
+}
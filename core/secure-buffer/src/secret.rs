@@ -0,0 +1,216 @@
+use crate::buffer::SecureBuffer;
+use crate::zeroize::SecureZeroize;
+use core::fmt;
+use core::marker::PhantomData;
+use entlib_native_constant_time::choice::Choice;
+use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeOrd};
+
+/// 길이가 타입에 고정된 `N`바이트 비밀 데이터 컨테이너입니다.
+///
+/// 내부 저장소는 [`SecureBuffer`]이므로 메모리 잠금 및 `Drop` 시점의 소거가 그대로 보장되며,
+/// 길이가 타입 수준에서 고정되어 32바이트 키와 48바이트 키를 컴파일 타임에 구분할 수 있습니다.
+///
+/// # Security Note
+/// - 비밀 데이터는 [`expose_secret`](Self::expose_secret)을 통해서만 명시적으로 접근할 수 있습니다.
+/// - `Debug`는 내용 대신 길이만 출력하며, `Display`는 구현하지 않습니다.
+/// - `PartialEq`는 [`ConstantTimeEq`]를 통해 상수-시간으로 비교합니다.
+/// - 비밀 데이터의 암묵적 복제를 막기 위해 `Clone`은 구현하지 않습니다.
+pub struct SecretArray<const N: usize> {
+    buffer: SecureBuffer,
+}
+
+impl<const N: usize> SecretArray<N> {
+    /// 비밀 데이터의 길이 (바이트)
+    pub const LEN: usize = N;
+
+    /// 0으로 초기화된 `N`바이트 비밀 컨테이너를 할당합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 메모리 할당 실패 또는 OS 리소스 제한 도달 시
+    pub fn zeroed() -> Result<Self, &'static str> {
+        Ok(Self {
+            buffer: SecureBuffer::new_owned(N)?,
+        })
+    }
+
+    /// `data`를 새로 할당한 보안 메모리로 복사하여 컨테이너를 생성합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - `data`의 길이가 `N`과 다르거나 메모리 할당 실패 시
+    pub fn from_slice(data: &[u8]) -> Result<Self, &'static str> {
+        if data.len() != N {
+            return Err("Invalid secret length: Input length does not match the array size.");
+        }
+
+        let mut secret = Self::zeroed()?;
+        secret.buffer.as_mut_slice().copy_from_slice(data);
+        Ok(secret)
+    }
+
+    /// 비밀 데이터를 읽기 전용 고정 길이 배열 참조로 노출합니다.
+    #[inline(always)]
+    pub fn expose_secret(&self) -> &[u8; N] {
+        // Safety: 버퍼의 유효 길이는 생성 시점에 N으로 고정되며 이후 변경되지 않음
+        unsafe { &*(self.buffer.as_slice().as_ptr() as *const [u8; N]) }
+    }
+
+    /// 비밀 데이터를 쓰기 가능한 고정 길이 배열 참조로 노출합니다.
+    #[inline(always)]
+    pub fn expose_secret_mut(&mut self) -> &mut [u8; N] {
+        // Safety: 버퍼의 유효 길이는 생성 시점에 N으로 고정되며 이후 변경되지 않음
+        unsafe { &mut *(self.buffer.as_mut_slice().as_mut_ptr() as *mut [u8; N]) }
+    }
+
+    /// 컨테이너를 해체하여 내부 [`SecureBuffer`]를 반환합니다.
+    #[inline(always)]
+    pub fn into_buffer(self) -> SecureBuffer {
+        self.buffer
+    }
+}
+
+impl<const N: usize> TryFrom<SecureBuffer> for SecretArray<N> {
+    type Error = &'static str;
+
+    /// 유효 길이가 정확히 `N`인 [`SecureBuffer`]를 복사 없이 고정 길이 컨테이너로 변환합니다.
+    ///
+    /// 길이가 맞지 않으면 입력 버퍼는 `Drop`을 통해 소거된 뒤 해제됩니다.
+    fn try_from(buffer: SecureBuffer) -> Result<Self, Self::Error> {
+        if buffer.len() != N {
+            return Err("Invalid secret length: Buffer length does not match the array size.");
+        }
+        Ok(Self { buffer })
+    }
+}
+
+impl<const N: usize> From<SecretArray<N>> for SecureBuffer {
+    #[inline(always)]
+    fn from(secret: SecretArray<N>) -> Self {
+        secret.buffer
+    }
+}
+
+impl<const N: usize> ConstantTimeEq for SecretArray<N> {
+    /// 전체 바이트를 누적 AND로 스캔하므로 불일치 위치에 따른 조기 종료가 없습니다.
+    #[inline(always)]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.expose_secret().ct_eq(other.expose_secret())
+    }
+}
+
+impl<const N: usize> ConstantTimeOrd for SecretArray<N> {
+    /// 두 값을 빅엔디안 부호 없는 정수로 해석하여 `self >= other`를 상수-시간으로 판별합니다.
+    #[inline(always)]
+    fn ct_is_ge(&self, other: &Self) -> Choice {
+        self.expose_secret().ct_is_ge(other.expose_secret())
+    }
+}
+
+impl<const N: usize> PartialEq for SecretArray<N> {
+    #[inline(never)]
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).unwrap_u8() == 0xFF
+    }
+}
+
+impl<const N: usize> Eq for SecretArray<N> {}
+
+impl<const N: usize> SecureZeroize for SecretArray<N> {
+    #[inline(always)]
+    fn secure_zeroize(&mut self) {
+        self.buffer.secure_zeroize();
+    }
+}
+
+impl<const N: usize> fmt::Debug for SecretArray<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretArray<{}>([REDACTED])", N)
+    }
+}
+
+/// 특정 알고리즘 용도로 태그된 `N`바이트 비밀 키입니다.
+///
+/// `Alg`는 키를 소비하는 알고리즘 타입(예: `HMACSHA256`, `HKDFSHA256`)을 가리키는 마커로,
+/// 런타임 비용 없이 서로 다른 용도의 키가 뒤섞이는 실수를 컴파일 타임에 차단합니다.
+/// 예를 들어 `HKDFSHA256::extract`가 반환하는 `SecretKey<HKDFSHA256, 32>`는
+/// `HKDFSHA384::expand`에 전달할 수 없습니다.
+pub struct SecretKey<Alg, const N: usize> {
+    secret: SecretArray<N>,
+    _alg: PhantomData<fn() -> Alg>,
+}
+
+impl<Alg, const N: usize> SecretKey<Alg, N> {
+    /// 키의 길이 (바이트)
+    pub const LEN: usize = N;
+
+    /// `data`를 새로 할당한 보안 메모리로 복사하여 키를 생성합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - `data`의 길이가 `N`과 다르거나 메모리 할당 실패 시
+    pub fn from_slice(data: &[u8]) -> Result<Self, &'static str> {
+        SecretArray::from_slice(data).map(Self::from)
+    }
+
+    /// 키 바이트를 읽기 전용 고정 길이 배열 참조로 노출합니다.
+    #[inline(always)]
+    pub fn expose_secret(&self) -> &[u8; N] {
+        self.secret.expose_secret()
+    }
+
+    /// 알고리즘 태그를 제거하고 내부 [`SecretArray`]를 반환합니다.
+    #[inline(always)]
+    pub fn into_secret(self) -> SecretArray<N> {
+        self.secret
+    }
+}
+
+impl<Alg, const N: usize> From<SecretArray<N>> for SecretKey<Alg, N> {
+    #[inline(always)]
+    fn from(secret: SecretArray<N>) -> Self {
+        Self {
+            secret,
+            _alg: PhantomData,
+        }
+    }
+}
+
+impl<Alg, const N: usize> TryFrom<SecureBuffer> for SecretKey<Alg, N> {
+    type Error = &'static str;
+
+    fn try_from(buffer: SecureBuffer) -> Result<Self, Self::Error> {
+        SecretArray::try_from(buffer).map(Self::from)
+    }
+}
+
+impl<Alg, const N: usize> From<SecretKey<Alg, N>> for SecureBuffer {
+    #[inline(always)]
+    fn from(key: SecretKey<Alg, N>) -> Self {
+        key.secret.buffer
+    }
+}
+
+impl<Alg, const N: usize> PartialEq for SecretKey<Alg, N> {
+    #[inline(always)]
+    fn eq(&self, other: &Self) -> bool {
+        self.secret == other.secret
+    }
+}
+
+impl<Alg, const N: usize> Eq for SecretKey<Alg, N> {}
+
+impl<Alg, const N: usize> SecureZeroize for SecretKey<Alg, N> {
+    #[inline(always)]
+    fn secure_zeroize(&mut self) {
+        self.secret.secure_zeroize();
+    }
+}
+
+impl<Alg, const N: usize> fmt::Debug for SecretKey<Alg, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "SecretKey<{}, {}>([REDACTED])",
+            core::any::type_name::<Alg>(),
+            N
+        )
+    }
+}
@@ -4,12 +4,10 @@ use core::sync::atomic::{Ordering, compiler_fence, fence};
 #[inline(always)]
 fn get_cache_line_size() -> usize {
     // CPUID Leaf 1을 호출하여 ebx 레지스터에서 clflush 크기 추출
-    unsafe {
-        let cpuid = core::arch::x86_64::__cpuid(1);
-        let clflush_size = ((cpuid.ebx >> 8) & 0xFF) as usize * 8;
-        // CPUID 실패 또는 비정상 반환 시 안전한 기본값(Fallback)으로 64바이트 반환
-        if clflush_size == 0 { 64 } else { clflush_size }
-    }
+    let cpuid = core::arch::x86_64::__cpuid(1);
+    let clflush_size = ((cpuid.ebx >> 8) & 0xFF) as usize * 8;
+    // CPUID 실패 또는 비정상 반환 시 안전한 기본값(Fallback)으로 64바이트 반환
+    if clflush_size == 0 { 64 } else { clflush_size }
 }
 
 #[cfg(target_arch = "aarch64")]
@@ -31,20 +29,70 @@ fn get_cache_line_size() -> usize {
     4 << dminline
 }
 
-/// 원시 포인터 기반의 물리적 메모리 소거 트레이트
+/// 베어메탈 ARM(Cortex-M/R/A) 환경의 데이터 동기화 배리어
+///
+/// `dsb sy`는 선행 메모리 접근이 모두 완료될 때까지 대기하고, `isb sy`는 이후 명령어 파이프라인을
+/// 비워 배리어 이전 상태에서 실행되는 명령어가 없도록 합니다. ARMv6-M 이상에서 지원됩니다.
+#[cfg(all(target_arch = "arm", not(all(any(unix, windows), feature = "std"))))]
+#[inline(always)]
+fn embedded_barrier() {
+    unsafe {
+        core::arch::asm!("dsb sy", "isb sy", options(nostack, preserves_flags));
+    }
+}
+
+/// 베어메탈 RISC-V 환경의 메모리 배리어
+///
+/// `fence iorw, iorw`로 일반 메모리뿐 아니라 장치(I/O) 접근과의 순서까지 보장하여,
+/// 소거 이후의 DMA 전송이나 보호 영역 재설정이 소거 완료 이전 상태를 관측하지 않도록 합니다.
+#[cfg(all(
+    any(target_arch = "riscv32", target_arch = "riscv64"),
+    not(all(any(unix, windows), feature = "std"))
+))]
+#[inline(always)]
+fn embedded_barrier() {
+    unsafe {
+        core::arch::asm!("fence iorw, iorw", options(nostack, preserves_flags));
+    }
+}
+
+/// 전용 배리어가 정의되지 않은 아키텍처에서는 공통 `fence(SeqCst)`에 의존합니다.
+#[cfg(all(
+    not(any(
+        target_arch = "x86_64",
+        target_arch = "aarch64",
+        target_arch = "arm",
+        target_arch = "riscv32",
+        target_arch = "riscv64"
+    )),
+    not(all(any(unix, windows), feature = "std"))
+))]
+#[inline(always)]
+fn embedded_barrier() {}
+
+/// 값의 메모리를 물리적으로 소거하는 트레이트
+///
+/// 정수형, 배열, 슬라이스, `Option<T>`, 튜플 및 보안 버퍼 타입에 대한 구현을 제공하며,
+/// 사용자 정의 구조체는 `#[derive(SecureZeroize)]`로 필드 단위 구현을 생성할 수 있습니다.
+/// 모든 구현은 `write_volatile` 또는 [`Zeroizer::zeroize_raw`]를 통해 컴파일러의
+/// 데드 스토어 제거(DSE) 최적화로 소거가 생략되지 않도록 보장합니다.
 pub trait SecureZeroize {
-    /// 할당된 전체 용량(capacity)에 대해 소거를 수행합니다.
-    ///
-    /// # Safety
-    /// `ptr`은 유효한 메모리여야 하며, `capacity` 범위를 초과하여 접근하지 않아야 합니다.
-    unsafe fn zeroize_raw(ptr: *mut u8, capacity: usize);
+    /// 값의 메모리를 0으로 소거합니다.
+    fn secure_zeroize(&mut self);
 }
 
+/// 원시 포인터 기반의 하드웨어 수준 메모리 소거 구현체입니다.
 pub struct Zeroizer;
 
-impl SecureZeroize for Zeroizer {
+impl Zeroizer {
+    /// 할당된 전체 용량(capacity)에 대해 소거를 수행합니다.
+    ///
+    /// 소거 이후 캐시 라인 플러시 및 메모리 배리어까지 수행하므로, 힙/잠긴 메모리 영역 소거에 사용합니다.
+    ///
+    /// # Safety
+    /// `ptr`은 유효한 메모리여야 하며, `capacity` 범위를 초과하여 접근하지 않아야 합니다.
     #[inline(never)]
-    unsafe fn zeroize_raw(ptr: *mut u8, capacity: usize) {
+    pub unsafe fn zeroize_raw(ptr: *mut u8, capacity: usize) {
         if ptr.is_null() || capacity == 0 {
             return;
         }
@@ -140,13 +188,20 @@ impl SecureZeroize for Zeroizer {
             #[cfg(not(all(any(unix, windows), feature = "std")))]
             {
                 // OS API가 부재한 베어메탈 환경에서는 기존과 같이 volatile 기반 강제 쓰기 수행
-                // Q. T. Felix TODO: 해당 환경의 하드웨어(CPU) 특성에 따라 캐시 라인 플러시가 보장되지 않을 수 있다고 합니다.
-                //                   thumbv6m-none-eabi 와 같은 arm 베어메탈 아키텍처에서의 연구가 필요합니다!!!!!
                 let mut byte_ptr = ptr;
                 for _ in 0..capacity {
                     core::ptr::write_volatile(byte_ptr, 0);
                     byte_ptr = byte_ptr.add(1);
                 }
+
+                // 캐시 유지보수 방식은 코어마다 다르므로 등록된 플랫폼 훅에 위임
+                #[cfg(not(feature = "std"))]
+                if let Some(hook) = crate::platform::platform_hook() {
+                    hook.clean_cache(ptr, capacity);
+                }
+
+                // 소거 쓰기가 버스(또는 DMA 마스터)에서 관측 가능해질 때까지 대기
+                embedded_barrier();
             }
         }
 
@@ -155,3 +210,82 @@ impl SecureZeroize for Zeroizer {
         fence(Ordering::SeqCst);
     }
 }
+
+/// 기본 스칼라 타입에 대한 소거를 일괄 구현하는 매크로입니다.
+///
+/// 스택 임시 값은 해시 압축 함수 등 고빈도 경로에서 소거되므로, 캐시 플러시 없이
+/// `write_volatile`과 컴파일러 배리어만 사용합니다.
+macro_rules! impl_secure_zeroize_for_scalar {
+    ($($t:ty => $zero:expr),* $(,)?) => {
+        $(
+            impl SecureZeroize for $t {
+                #[inline(always)]
+                fn secure_zeroize(&mut self) {
+                    // Safety: &mut 참조는 항상 유효하고 정렬된 포인터임
+                    unsafe { core::ptr::write_volatile(self, $zero) };
+                    compiler_fence(Ordering::SeqCst);
+                }
+            }
+        )*
+    };
+}
+
+impl_secure_zeroize_for_scalar!(
+    u8 => 0, u16 => 0, u32 => 0, u64 => 0, u128 => 0, usize => 0,
+    i8 => 0, i16 => 0, i32 => 0, i64 => 0, i128 => 0, isize => 0,
+    bool => false,
+);
+
+impl<T: SecureZeroize> SecureZeroize for [T] {
+    #[inline]
+    fn secure_zeroize(&mut self) {
+        for item in self.iter_mut() {
+            item.secure_zeroize();
+        }
+    }
+}
+
+impl<T: SecureZeroize, const N: usize> SecureZeroize for [T; N] {
+    #[inline]
+    fn secure_zeroize(&mut self) {
+        self.as_mut_slice().secure_zeroize();
+    }
+}
+
+impl<T: SecureZeroize> SecureZeroize for Option<T> {
+    /// 내부 값을 소거한 뒤 `None`으로 전환합니다.
+    ///
+    /// 내부 값은 `Drop`을 거쳐 해제되므로, 보안 버퍼처럼 자원을 보유한 타입도 누수 없이 반환됩니다.
+    #[inline]
+    fn secure_zeroize(&mut self) {
+        if let Some(value) = self.as_mut() {
+            value.secure_zeroize();
+        }
+        *self = None;
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+macro_rules! impl_secure_zeroize_for_tuple {
+    ($(($($name:ident : $idx:tt),+)),* $(,)?) => {
+        $(
+            impl<$($name: SecureZeroize),+> SecureZeroize for ($($name,)+) {
+                #[inline]
+                fn secure_zeroize(&mut self) {
+                    $(self.$idx.secure_zeroize();)+
+                }
+            }
+        )*
+    };
+}
+
+impl_secure_zeroize_for_tuple!(
+    (A: 0),
+    (A: 0, B: 1),
+    (A: 0, B: 1, C: 2),
+    (A: 0, B: 1, C: 2, D: 3),
+    (A: 0, B: 1, C: 2, D: 3, E: 4),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6),
+    (A: 0, B: 1, C: 2, D: 3, E: 4, F: 5, G: 6, H: 7),
+);
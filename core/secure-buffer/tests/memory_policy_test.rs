@@ -0,0 +1,118 @@
+//! 프로세스 전역 잠금 정책 및 메모리 사용 현황 테스트
+//!
+//! 전역 카운터를 공유하는 다른 테스트와 간섭하지 않도록 별도 테스트 바이너리로 분리하며,
+//! 동일 바이너리 내 테스트도 `SERIAL` 잠금으로 직렬화합니다.
+
+#[cfg(all(test, feature = "std"))]
+mod memory_policy_tests {
+    use entlib_native_secure_buffer::{
+        LockPolicy, SecureArena, SecureBuffer, lock_policy, memory_stats, set_lock_policy,
+    };
+    use std::sync::Mutex;
+
+    static SERIAL: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn stats_track_owned_and_arena_buffers() {
+        let _serial = SERIAL.lock().unwrap();
+        let base = memory_stats();
+
+        let buf = SecureBuffer::new_owned(100).unwrap();
+        let after_alloc = memory_stats();
+        assert_eq!(after_alloc.locked_bytes, base.locked_bytes + buf.capacity());
+        assert_eq!(after_alloc.live_buffers, base.live_buffers + 1);
+        assert!(after_alloc.peak_locked_bytes >= after_alloc.locked_bytes);
+
+        // 아레나 영역은 생성 시점에 한 번만 집계되고, 슬롯은 버퍼 수에만 반영됨
+        let arena = SecureArena::new(32, 4).unwrap();
+        let with_arena = memory_stats();
+        assert_eq!(
+            with_arena.locked_bytes,
+            after_alloc.locked_bytes + arena.stats().bytes_locked
+        );
+        let slot = arena.alloc(32).unwrap();
+        assert_eq!(memory_stats().locked_bytes, with_arena.locked_bytes);
+        assert_eq!(memory_stats().live_buffers, base.live_buffers + 2);
+
+        drop(slot);
+        drop(arena);
+        drop(buf);
+        let after_drop = memory_stats();
+        assert_eq!(after_drop.locked_bytes, base.locked_bytes);
+        assert_eq!(after_drop.live_buffers, base.live_buffers);
+        assert!(after_drop.peak_locked_bytes >= with_arena.locked_bytes);
+    }
+
+    #[test]
+    fn policy_roundtrip() {
+        let _serial = SERIAL.lock().unwrap();
+        assert_eq!(lock_policy(), LockPolicy::RaiseLimit);
+
+        for policy in [
+            LockPolicy::FailHard,
+            LockPolicy::AllowUnlocked,
+            LockPolicy::RaiseLimit,
+        ] {
+            set_lock_policy(policy);
+            assert_eq!(lock_policy(), policy);
+            assert_eq!(LockPolicy::from_u8(policy as u8), Some(policy));
+        }
+        assert_eq!(LockPolicy::from_u8(3), None);
+    }
+
+    /// `RLIMIT_MEMLOCK`을 0으로 낮춘 뒤 정책별 동작 검증
+    ///
+    /// `CAP_IPC_LOCK` 권한이 있는 프로세스는 한도와 무관하게 잠금에 성공하므로 검증을 건너뜁니다.
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn policy_governs_lock_failure() {
+        #[repr(C)]
+        struct Rlimit {
+            rlim_cur: u64,
+            rlim_max: u64,
+        }
+        unsafe extern "C" {
+            fn getrlimit(resource: i32, rlim: *mut Rlimit) -> i32;
+            fn setrlimit(resource: i32, rlim: *const Rlimit) -> i32;
+        }
+        const RLIMIT_MEMLOCK: i32 = 8;
+
+        let _serial = SERIAL.lock().unwrap();
+        let mut original = Rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        assert_eq!(unsafe { getrlimit(RLIMIT_MEMLOCK, &mut original) }, 0);
+        // 하드 한도는 유지하여 테스트 종료 후 소프트 한도를 복원할 수 있도록 함
+        let lowered = Rlimit {
+            rlim_cur: 0,
+            rlim_max: original.rlim_max,
+        };
+        assert_eq!(unsafe { setrlimit(RLIMIT_MEMLOCK, &lowered) }, 0);
+        let restore = || {
+            set_lock_policy(LockPolicy::RaiseLimit);
+            unsafe { setrlimit(RLIMIT_MEMLOCK, &original) };
+        };
+
+        set_lock_policy(LockPolicy::FailHard);
+        if SecureBuffer::new_owned(64).is_ok() {
+            restore();
+            return;
+        }
+
+        let base = memory_stats();
+        set_lock_policy(LockPolicy::AllowUnlocked);
+        let buf = SecureBuffer::new_owned(64).unwrap();
+        assert!(!buf.protection_status().locked);
+
+        let stats = memory_stats();
+        assert_eq!(stats.unlocked_allocations, base.unlocked_allocations + 1);
+        assert_eq!(stats.locked_bytes, base.locked_bytes);
+        assert_eq!(stats.live_buffers, base.live_buffers + 1);
+
+        // 잠기지 않은 버퍼의 해제는 잠금 집계에 영향을 주지 않아야 함
+        drop(buf);
+        assert_eq!(memory_stats().locked_bytes, base.locked_bytes);
+        restore();
+    }
+}
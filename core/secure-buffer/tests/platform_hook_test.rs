@@ -0,0 +1,106 @@
+//! `no_std` 플랫폼 메모리 훅 테스트
+//!
+//! 훅은 전역으로 단 한 번만 등록할 수 있으므로 별도 테스트 바이너리로 분리하며,
+//! `std` 피처가 비활성화된 빌드(`cargo test -p entlib-native-secure-buffer`)에서만 실행됩니다.
+
+#[cfg(all(test, not(feature = "std")))]
+mod platform_hook_tests {
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use entlib_native_secure_buffer::{
+        LockPolicy, NO_STD_PAGE_SIZE, PlatformMemoryHook, SecureBuffer, memory_stats,
+        set_lock_policy, set_platform_hook,
+    };
+    use std::sync::{Mutex, Once};
+
+    static SERIAL: Mutex<()> = Mutex::new(());
+    static INSTALL: Once = Once::new();
+
+    /// 호출 횟수를 기록하고, `reject`가 설정되면 잠금을 거부하는 테스트용 훅
+    struct CountingHook {
+        locks: AtomicUsize,
+        unlocks: AtomicUsize,
+        reject: AtomicBool,
+    }
+
+    impl PlatformMemoryHook for CountingHook {
+        unsafe fn lock(&self, ptr: *mut u8, len: usize) -> bool {
+            assert_eq!(ptr as usize % NO_STD_PAGE_SIZE, 0);
+            assert_eq!(len % NO_STD_PAGE_SIZE, 0);
+            if self.reject.load(Ordering::SeqCst) {
+                return false;
+            }
+            self.locks.fetch_add(1, Ordering::SeqCst);
+            true
+        }
+
+        unsafe fn unlock(&self, _ptr: *mut u8, _len: usize) {
+            self.unlocks.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    static HOOK: CountingHook = CountingHook {
+        locks: AtomicUsize::new(0),
+        unlocks: AtomicUsize::new(0),
+        reject: AtomicBool::new(false),
+    };
+
+    fn install() {
+        INSTALL.call_once(|| set_platform_hook(&HOOK).unwrap());
+    }
+
+    #[test]
+    fn hook_is_registered_once() {
+        let _serial = SERIAL.lock().unwrap();
+        install();
+        assert!(set_platform_hook(&HOOK).is_err());
+    }
+
+    #[test]
+    fn hook_locks_and_unlocks_owned_buffers() {
+        let _serial = SERIAL.lock().unwrap();
+        install();
+        let locks = HOOK.locks.load(Ordering::SeqCst);
+        let unlocks = HOOK.unlocks.load(Ordering::SeqCst);
+        let base = memory_stats();
+
+        let buf = SecureBuffer::new_owned(10).unwrap();
+        assert!(buf.protection_status().locked);
+        assert_eq!(buf.capacity() % NO_STD_PAGE_SIZE, 0);
+        assert_eq!(HOOK.locks.load(Ordering::SeqCst), locks + 1);
+        assert_eq!(
+            memory_stats().locked_bytes,
+            base.locked_bytes + buf.capacity()
+        );
+
+        drop(buf);
+        assert_eq!(HOOK.unlocks.load(Ordering::SeqCst), unlocks + 1);
+        assert_eq!(memory_stats().locked_bytes, base.locked_bytes);
+    }
+
+    #[test]
+    fn rejected_lock_follows_policy() {
+        let _serial = SERIAL.lock().unwrap();
+        install();
+        HOOK.reject.store(true, Ordering::SeqCst);
+
+        set_lock_policy(LockPolicy::RaiseLimit);
+        assert!(SecureBuffer::new_owned(10).is_err());
+
+        let base = memory_stats();
+        set_lock_policy(LockPolicy::AllowUnlocked);
+        let buf = SecureBuffer::new_owned(10).unwrap();
+        assert!(!buf.protection_status().locked);
+        assert_eq!(
+            memory_stats().unlocked_allocations,
+            base.unlocked_allocations + 1
+        );
+
+        // 잠기지 않은 버퍼의 해제는 훅의 해제 경로를 거치지 않아야 함
+        let unlocks = HOOK.unlocks.load(Ordering::SeqCst);
+        drop(buf);
+        assert_eq!(HOOK.unlocks.load(Ordering::SeqCst), unlocks);
+
+        set_lock_policy(LockPolicy::RaiseLimit);
+        HOOK.reject.store(false, Ordering::SeqCst);
+    }
+}
@@ -0,0 +1,683 @@
+use entlib_native_secure_buffer::SecureBuffer;
+
+#[cfg(test)]
+mod secure_buffer_growth_tests {
+    use super::*;
+
+    #[test]
+    fn test_push_and_extend() {
+        let mut buf = SecureBuffer::with_capacity(0).unwrap();
+        assert!(buf.is_empty());
+
+        buf.push(0x01).unwrap();
+        buf.extend_from_slice(&[0x02, 0x03, 0x04]).unwrap();
+
+        assert_eq!(buf.len(), 4);
+        assert_eq!(buf.as_slice(), &[0x01, 0x02, 0x03, 0x04]);
+    }
+
+    #[test]
+    fn test_growth_beyond_page_preserves_data() {
+        let mut buf = SecureBuffer::with_capacity(16).unwrap();
+        let initial_capacity = buf.capacity();
+
+        // 초기 용량(최소 1페이지)을 넘어서도록 데이터 누적
+        let chunk: Vec<u8> = (0..=255u8).collect();
+        let rounds = initial_capacity / chunk.len() + 2;
+        for _ in 0..rounds {
+            buf.extend_from_slice(&chunk).unwrap();
+        }
+
+        assert!(buf.capacity() > initial_capacity);
+        assert_eq!(buf.len(), rounds * chunk.len());
+        for (i, &b) in buf.as_slice().iter().enumerate() {
+            assert_eq!(b, (i % 256) as u8, "Data corrupted at index {}", i);
+        }
+    }
+
+    #[test]
+    fn test_truncate_zeroizes_tail() {
+        let mut buf = SecureBuffer::with_capacity(8).unwrap();
+        buf.extend_from_slice(&[0xAA; 8]).unwrap();
+
+        buf.truncate(3);
+        assert_eq!(buf.as_slice(), &[0xAA; 3]);
+
+        // 잘린 영역은 다시 노출될 때 0이어야 함 (잔재 없음)
+        buf.resize(8, 0x00).unwrap();
+        assert_eq!(buf.as_slice(), &[0xAA, 0xAA, 0xAA, 0, 0, 0, 0, 0]);
+
+        // 현재 길이 이상으로의 truncate는 무시
+        buf.truncate(100);
+        assert_eq!(buf.len(), 8);
+    }
+
+    #[test]
+    fn test_resize_fills_value() {
+        let mut buf = SecureBuffer::new_owned(2).unwrap();
+        buf.as_mut_slice().copy_from_slice(&[0x11, 0x22]);
+
+        buf.resize(5, 0x7F).unwrap();
+        assert_eq!(buf.as_slice(), &[0x11, 0x22, 0x7F, 0x7F, 0x7F]);
+
+        buf.resize(1, 0x00).unwrap();
+        assert_eq!(buf.as_slice(), &[0x11]);
+    }
+
+    #[test]
+    fn test_reserve() {
+        let mut buf = SecureBuffer::with_capacity(0).unwrap();
+        let target = buf.capacity() * 3 + 1;
+
+        buf.reserve(target).unwrap();
+        assert!(buf.capacity() >= target);
+        assert!(buf.is_empty());
+
+        assert!(buf.reserve(usize::MAX).is_err());
+    }
+}
+
+#[cfg(all(test, unix, feature = "std"))]
+mod secure_buffer_guard_tests {
+    use super::*;
+
+    #[test]
+    fn test_guarded_allocation() {
+        let mut buf = SecureBuffer::new_guarded(32).unwrap();
+        assert!(buf.is_guarded());
+        assert_eq!(buf.len(), 32);
+        assert_eq!(buf.capacity(), 32);
+        // 데이터 끝이 뒤쪽 가드 페이지 경계와 맞닿도록 우측 정렬됨
+        let end = buf.as_slice().as_ptr() as usize + buf.capacity();
+        assert_eq!(end % 4096, 0);
+        assert!(buf.as_slice().iter().all(|&b| b == 0));
+
+        buf.as_mut_slice().fill(0x5A);
+        assert!(buf.verify_canary().is_ok());
+
+        // 일반 할당은 가드 페이지 모드가 아님
+        let plain = SecureBuffer::new_owned(32).unwrap();
+        assert!(!plain.is_guarded());
+        assert!(plain.verify_canary().is_ok());
+    }
+
+    #[test]
+    fn test_guarded_growth_keeps_mode() {
+        let mut buf = SecureBuffer::new_guarded(0).unwrap();
+        let initial_capacity = buf.capacity();
+
+        let data = vec![0xC3u8; initial_capacity + 1];
+        buf.extend_from_slice(&data).unwrap();
+
+        assert!(buf.is_guarded());
+        assert!(buf.capacity() > initial_capacity);
+        assert_eq!(buf.as_slice(), data.as_slice());
+        assert!(buf.verify_canary().is_ok());
+    }
+
+    #[test]
+    fn test_canary_tamper_detected() {
+        let mut buf = SecureBuffer::new_guarded(16).unwrap();
+        let canary_ptr = unsafe { buf.as_mut_slice().as_mut_ptr().sub(1) };
+
+        // 데이터 시작 경계를 1바이트 넘는 언더플로우 시뮬레이션
+        let original = unsafe { canary_ptr.read() };
+        unsafe { canary_ptr.write(original ^ 0xFF) };
+        assert!(buf.verify_canary().is_err());
+
+        // Drop 시점 abort를 피하기 위해 원래 값 복원
+        unsafe { canary_ptr.write(original) };
+        assert!(buf.verify_canary().is_ok());
+    }
+}
+
+#[cfg(all(test, any(unix, windows), feature = "std"))]
+mod secure_buffer_protection_tests {
+    use super::*;
+    use entlib_native_secure_buffer::MemoryProtection;
+
+    #[test]
+    fn test_protection_transitions() {
+        let mut buf = SecureBuffer::new_owned(4).unwrap();
+        buf.as_mut_slice().copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(buf.protection(), MemoryProtection::ReadWrite);
+
+        buf.protect_readonly().unwrap();
+        assert_eq!(buf.protection(), MemoryProtection::ReadOnly);
+        assert_eq!(buf.as_slice(), &[1, 2, 3, 4]);
+
+        // 봉인 상태에서 길이 확장은 거부
+        assert!(buf.push(5).is_err());
+        assert!(buf.extend_from_slice(&[5]).is_err());
+
+        buf.unprotect().unwrap();
+        buf.as_mut_slice()[0] = 0xFF;
+        assert_eq!(buf.as_slice(), &[0xFF, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_read_guard_reseals() {
+        let mut buf = SecureBuffer::new_owned(3).unwrap();
+        buf.as_mut_slice().copy_from_slice(&[0xAA, 0xBB, 0xCC]);
+        buf.protect_noaccess().unwrap();
+
+        {
+            let guard = buf.read_guard().unwrap();
+            assert_eq!(&*guard, &[0xAA, 0xBB, 0xCC]);
+        }
+        assert_eq!(buf.protection(), MemoryProtection::NoAccess);
+
+        // 읽기/쓰기 상태에서의 가드는 보호 수준을 변경하지 않음
+        buf.unprotect().unwrap();
+        {
+            let guard = buf.read_guard().unwrap();
+            assert_eq!(guard.len(), 3);
+        }
+        assert_eq!(buf.protection(), MemoryProtection::ReadWrite);
+    }
+
+    #[test]
+    fn test_truncate_and_drop_while_sealed() {
+        let mut buf = SecureBuffer::new_owned(8).unwrap();
+        buf.as_mut_slice().fill(0x42);
+        buf.protect_noaccess().unwrap();
+
+        // 봉인 상태에서도 truncate 소거 후 원래 보호 수준 유지
+        buf.truncate(2);
+        assert_eq!(buf.protection(), MemoryProtection::NoAccess);
+        assert_eq!(&*buf.read_guard().unwrap(), &[0x42, 0x42]);
+
+        // 봉인 상태로 Drop 되어도 소거 과정에서 접근 위반이 발생하지 않아야 함
+        drop(buf);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_guarded_buffer_protection() {
+        let mut buf = SecureBuffer::new_guarded(16).unwrap();
+        buf.protect_noaccess().unwrap();
+        assert!(buf.read_guard().unwrap().iter().all(|&b| b == 0));
+        buf.unprotect().unwrap();
+        assert!(buf.verify_canary().is_ok());
+    }
+}
+
+#[cfg(all(test, target_os = "linux", feature = "std"))]
+mod secure_buffer_fork_tests {
+    use super::*;
+
+    unsafe extern "C" {
+        fn fork() -> i32;
+        fn waitpid(pid: i32, status: *mut i32, options: i32) -> i32;
+        fn _exit(status: i32) -> !;
+    }
+
+    /// 자식 프로세스에서 `check`를 실행하고, 정상 종료(exit 0) 여부를 반환
+    fn run_in_child(check: impl FnOnce() -> bool) -> bool {
+        let pid = unsafe { fork() };
+        assert!(pid >= 0, "fork failed");
+
+        if pid == 0 {
+            // 자식: 테스트 하네스로 돌아가지 않고 즉시 종료
+            let code = if check() { 0 } else { 1 };
+            unsafe { _exit(code) };
+        }
+
+        let mut status = 0;
+        assert_eq!(unsafe { waitpid(pid, &mut status, 0) }, pid);
+        // WIFEXITED && WEXITSTATUS == 0
+        status & 0x7F == 0 && (status >> 8) & 0xFF == 0
+    }
+
+    /// `/proc/self/smaps`에서 `addr`을 포함하는 매핑의 VmFlags를 조회
+    fn vm_flags_of(addr: usize) -> String {
+        let smaps = std::fs::read_to_string("/proc/self/smaps").unwrap();
+        let mut in_range = false;
+        for line in smaps.lines() {
+            if let Some((range, _)) = line.split_once(' ')
+                && let Some((start, end)) = range.split_once('-')
+                && let (Ok(start), Ok(end)) = (
+                    usize::from_str_radix(start, 16),
+                    usize::from_str_radix(end, 16),
+                )
+            {
+                in_range = (start..end).contains(&addr);
+                continue;
+            }
+            if in_range && let Some(flags) = line.strip_prefix("VmFlags:") {
+                return flags.to_string();
+            }
+        }
+        String::new()
+    }
+
+    fn assert_child_sees_zeroes(mut buf: SecureBuffer) {
+        buf.as_mut_slice().fill(0xA5);
+        let ptr = buf.as_slice().as_ptr() as usize;
+        let len = buf.len();
+
+        let zeroed_in_child = run_in_child(|| {
+            let view = unsafe { core::slice::from_raw_parts(ptr as *const u8, len) };
+            view.iter().all(|&b| b == 0)
+        });
+
+        assert!(zeroed_in_child, "Child process observed secret data");
+        // 부모 프로세스의 데이터는 그대로 유지
+        assert!(buf.as_slice().iter().all(|&b| b == 0xA5));
+    }
+
+    #[test]
+    fn test_fork_child_observes_zeroed_memory() {
+        let buf = SecureBuffer::new_owned(64).unwrap();
+        let status = buf.protection_status();
+        assert!(status.locked);
+        if !status.wiped_on_fork {
+            // MADV_WIPEONFORK 미지원 커널 (4.14 미만)
+            return;
+        }
+        assert_child_sees_zeroes(buf);
+    }
+
+    #[test]
+    fn test_fork_child_observes_zeroed_guarded_memory() {
+        let buf = SecureBuffer::new_guarded(64).unwrap();
+        if !buf.protection_status().wiped_on_fork {
+            return;
+        }
+        assert_child_sees_zeroes(buf);
+    }
+
+    #[test]
+    fn test_vm_flags_reflect_status() {
+        let buf = SecureBuffer::new_owned(32).unwrap();
+        let status = buf.protection_status();
+        let flags = vm_flags_of(buf.as_slice().as_ptr() as usize);
+
+        assert_eq!(flags.contains(" dd"), status.excluded_from_dump);
+        assert_eq!(flags.contains(" wf"), status.wiped_on_fork);
+        assert!(flags.contains(" lo"));
+    }
+}
+
+#[cfg(test)]
+mod secure_buffer_backend_tests {
+    use super::*;
+    use entlib_native_secure_buffer::AllocationBackend;
+
+    fn roundtrip(backend: AllocationBackend) -> SecureBuffer {
+        let mut buf = SecureBuffer::new_with_backend(48, backend).unwrap();
+        assert_eq!(buf.len(), 48);
+        assert!(buf.as_slice().iter().all(|&b| b == 0));
+
+        buf.as_mut_slice().fill(0x3C);
+        // 재할당 이후에도 실제 백엔드가 유지되어야 함
+        let used = buf.backend();
+        let data = vec![0x3Cu8; buf.capacity()];
+        buf.extend_from_slice(&data).unwrap();
+        assert_eq!(buf.backend(), used);
+        assert!(buf.as_slice().iter().all(|&b| b == 0x3C));
+        buf
+    }
+
+    #[test]
+    fn test_heap_backend() {
+        let buf = roundtrip(AllocationBackend::Heap);
+        assert_eq!(buf.backend(), Some(AllocationBackend::Heap));
+    }
+
+    #[cfg(all(unix, feature = "std"))]
+    #[test]
+    fn test_mmap_backend() {
+        let buf = roundtrip(AllocationBackend::Mmap);
+        assert_eq!(buf.backend(), Some(AllocationBackend::Mmap));
+        assert!(buf.protection_status().locked);
+    }
+
+    #[test]
+    fn test_memfd_secret_backend_with_fallback() {
+        let buf = roundtrip(AllocationBackend::MemfdSecret);
+        // memfd_secret 미지원 커널에서는 Mmap(또는 no_std/비 Unix 환경에서는 Heap)으로 대체
+        assert!(matches!(
+            buf.backend(),
+            Some(
+                AllocationBackend::MemfdSecret | AllocationBackend::Mmap | AllocationBackend::Heap
+            )
+        ));
+        #[cfg(all(unix, feature = "std"))]
+        assert_ne!(buf.backend(), Some(AllocationBackend::Heap));
+    }
+
+    #[cfg(all(target_os = "linux", feature = "std"))]
+    #[test]
+    fn test_memfd_secret_not_inherited_by_fork() {
+        unsafe extern "C" {
+            fn fork() -> i32;
+            fn waitpid(pid: i32, status: *mut i32, options: i32) -> i32;
+            fn _exit(status: i32) -> !;
+        }
+
+        let mut buf = SecureBuffer::new_with_backend(32, AllocationBackend::MemfdSecret).unwrap();
+        if buf.backend() != Some(AllocationBackend::MemfdSecret)
+            || !buf.protection_status().wiped_on_fork
+        {
+            return;
+        }
+        buf.as_mut_slice().fill(0x99);
+        let ptr = buf.as_slice().as_ptr();
+
+        let pid = unsafe { fork() };
+        assert!(pid >= 0, "fork failed");
+        if pid == 0 {
+            // 매핑이 상속되지 않았다면 접근 즉시 SIGSEGV로 종료되어야 함
+            let leaked = unsafe { core::ptr::read_volatile(ptr) } == 0x99;
+            unsafe { _exit(if leaked { 1 } else { 0 }) };
+        }
+
+        let mut status = 0;
+        assert_eq!(unsafe { waitpid(pid, &mut status, 0) }, pid);
+        // WIFSIGNALED && WTERMSIG == SIGSEGV(11)
+        assert_eq!(status & 0x7F, 11, "Child process accessed secret mapping");
+    }
+}
+
+#[cfg(test)]
+mod secure_arena_tests {
+    use super::*;
+    use entlib_native_secure_buffer::SecureArena;
+
+    #[test]
+    fn test_arena_stats_and_release() {
+        let arena = SecureArena::new(32, 100).unwrap();
+        let stats = arena.stats();
+        assert_eq!(stats.slot_size, 32);
+        assert!(stats.total_slots >= 100);
+        assert_eq!(stats.used_slots, 0);
+        // 100개의 32바이트 키를 하나의 영역(페이지 단위)으로 수용
+        assert!(stats.bytes_locked < 100 * 4096);
+
+        let keys: Vec<SecureBuffer> = (0..100).map(|_| arena.alloc(32).unwrap()).collect();
+        assert_eq!(arena.stats().used_slots, 100);
+        assert!(keys.iter().all(|k| k.is_arena_slot() && k.len() == 32));
+
+        drop(keys);
+        let stats = arena.stats();
+        assert_eq!(stats.used_slots, 0);
+        assert_eq!(stats.peak_used_slots, 100);
+    }
+
+    #[test]
+    fn test_arena_slot_zeroized_on_drop() {
+        let arena = SecureArena::new(64, 1).unwrap();
+
+        let mut first = arena.alloc(64).unwrap();
+        first.as_mut_slice().fill(0xEE);
+        let addr = first.as_slice().as_ptr();
+        drop(first);
+
+        // 반환된 슬롯을 다시 할당받았을 때 이전 데이터가 남아 있지 않아야 함
+        let second = arena.alloc(64).unwrap();
+        assert_eq!(second.as_slice().as_ptr(), addr);
+        assert!(second.as_slice().iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_arena_exhaustion_and_oversize() {
+        let arena = SecureArena::new(4096, 1).unwrap();
+        let total = arena.stats().total_slots;
+
+        assert!(arena.alloc(4097).is_err());
+
+        let held: Vec<SecureBuffer> = (0..total).map(|_| arena.alloc(16).unwrap()).collect();
+        assert!(arena.alloc(16).is_err());
+        drop(held);
+        assert!(arena.alloc(16).is_ok());
+    }
+
+    #[test]
+    fn test_arena_slot_growth_migrates() {
+        let arena = SecureArena::new(16, 4).unwrap();
+        let mut buf = arena.alloc(0).unwrap();
+        buf.extend_from_slice(&[0x11; 16]).unwrap();
+        assert!(buf.is_arena_slot());
+        assert_eq!(arena.stats().used_slots, 1);
+
+        // 슬롯 크기를 넘는 확장은 개별 블록으로 이전되고 슬롯은 반환됨
+        buf.push(0x22).unwrap();
+        assert!(!buf.is_arena_slot());
+        assert_eq!(arena.stats().used_slots, 0);
+        assert_eq!(&buf.as_slice()[..16], &[0x11; 16]);
+        assert_eq!(buf.as_slice()[16], 0x22);
+    }
+
+    #[test]
+    fn test_arena_slot_rejects_page_protection() {
+        let arena = SecureArena::new(32, 2).unwrap();
+        let mut buf = arena.alloc(32).unwrap();
+        assert!(buf.protect_noaccess().is_err());
+    }
+
+    #[test]
+    fn test_arena_outlives_handle() {
+        let arena = SecureArena::new(32, 1).unwrap();
+        let mut buf = arena.alloc(8).unwrap();
+        drop(arena);
+
+        // 아레나 핸들이 해제되어도 슬롯이 살아 있는 동안 영역은 유지됨
+        buf.as_mut_slice().fill(0x7A);
+        assert_eq!(buf.as_slice(), &[0x7A; 8]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_arena_shared_across_threads() {
+        let arena = SecureArena::new(32, 64).unwrap();
+
+        std::thread::scope(|scope| {
+            for t in 0..4u8 {
+                let arena = &arena;
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        let mut buf = arena.alloc(32).unwrap();
+                        buf.as_mut_slice().fill(t);
+                        assert!(buf.as_slice().iter().all(|&b| b == t));
+                    }
+                });
+            }
+        });
+
+        assert_eq!(arena.stats().used_slots, 0);
+    }
+}
+
+#[cfg(test)]
+mod secret_array_tests {
+    use super::*;
+    use entlib_native_constant_time::traits::ConstantTimeOrd;
+    use entlib_native_secure_buffer::{SecretArray, SecretKey};
+
+    struct TestAlg;
+
+    #[test]
+    fn test_from_slice_and_expose() {
+        let secret = SecretArray::<4>::from_slice(&[1, 2, 3, 4]).unwrap();
+        assert_eq!(secret.expose_secret(), &[1, 2, 3, 4]);
+        assert_eq!(SecretArray::<4>::LEN, 4);
+
+        // 길이 불일치 거부
+        assert!(SecretArray::<4>::from_slice(&[1, 2, 3]).is_err());
+        assert!(SecretArray::<4>::from_slice(&[0; 5]).is_err());
+    }
+
+    #[test]
+    fn test_buffer_conversion() {
+        let mut buf = SecureBuffer::new_owned(3).unwrap();
+        buf.as_mut_slice().copy_from_slice(&[7, 8, 9]);
+        let secret = SecretArray::<3>::try_from(buf).unwrap();
+        assert_eq!(secret.expose_secret(), &[7, 8, 9]);
+
+        let buf: SecureBuffer = secret.into();
+        assert_eq!(buf.as_slice(), &[7, 8, 9]);
+        assert!(SecretArray::<4>::try_from(buf).is_err());
+    }
+
+    #[test]
+    fn test_constant_time_eq_and_ge() {
+        let a = SecretArray::<3>::from_slice(&[1, 2, 3]).unwrap();
+        let b = SecretArray::<3>::from_slice(&[1, 2, 3]).unwrap();
+        let c = SecretArray::<3>::from_slice(&[1, 3, 0]).unwrap();
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+
+        // 빅엔디안 대소 비교: 최초로 다른 바이트가 결과를 결정
+        assert_eq!(c.ct_is_ge(&a).unwrap_u8(), 0xFF);
+        assert_eq!(a.ct_is_ge(&c).unwrap_u8(), 0x00);
+        assert_eq!(a.ct_is_ge(&b).unwrap_u8(), 0xFF);
+    }
+
+    #[test]
+    fn test_debug_redacts_secret() {
+        let secret = SecretArray::<2>::from_slice(&[0xAB, 0xCD]).unwrap();
+        let rendered = format!("{:?}", secret);
+        assert_eq!(rendered, "SecretArray<2>([REDACTED])");
+
+        let key = SecretKey::<TestAlg, 2>::from(secret);
+        let rendered = format!("{:?}", key);
+        assert!(rendered.contains("[REDACTED]"));
+        assert!(!rendered.contains("171") && !rendered.to_lowercase().contains("ab, cd"));
+    }
+
+    #[test]
+    fn test_secret_key_tagging() {
+        let key = SecretKey::<TestAlg, 4>::from_slice(&[9; 4]).unwrap();
+        assert_eq!(key.expose_secret(), &[9; 4]);
+        assert_eq!(key, SecretKey::<TestAlg, 4>::from_slice(&[9; 4]).unwrap());
+        assert_eq!(key.into_secret().expose_secret(), &[9; 4]);
+    }
+}
+
+#[cfg(test)]
+mod secure_zeroize_tests {
+    use core::cell::Cell;
+    use entlib_native_secure_buffer::{SecretArray, SecureBuffer, SecureZeroize, ZeroizeOnDrop};
+
+    /// 소거 호출 횟수를 외부 카운터에 기록하는 테스트용 필드 타입
+    struct Probe<'a>(&'a Cell<usize>);
+
+    impl SecureZeroize for Probe<'_> {
+        fn secure_zeroize(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[derive(SecureZeroize)]
+    struct Named {
+        key: [u8; 4],
+        counter: u64,
+        nonce: Option<[u32; 2]>,
+        #[zeroize(skip)]
+        algorithm: u8,
+    }
+
+    #[derive(SecureZeroize)]
+    struct Tuple(u16, #[zeroize(skip)] bool, (u8, i32));
+
+    #[derive(SecureZeroize)]
+    enum Material {
+        Raw([u8; 3]),
+        Split { left: u8, right: u8 },
+        Empty,
+    }
+
+    #[derive(SecureZeroize, ZeroizeOnDrop)]
+    struct Guarded<'a> {
+        probe: Probe<'a>,
+        #[zeroize(skip)]
+        _skipped: Probe<'a>,
+    }
+
+    #[test]
+    fn test_scalar_array_option_tuple() {
+        let mut scalar = 0xDEAD_BEEFu32;
+        scalar.secure_zeroize();
+        assert_eq!(scalar, 0);
+
+        let mut flag = true;
+        flag.secure_zeroize();
+        assert!(!flag);
+
+        let mut array = [[0xAAu8; 4]; 3];
+        array.secure_zeroize();
+        assert_eq!(array, [[0u8; 4]; 3]);
+
+        let mut slice = [1i64, -2, 3];
+        slice[1..].secure_zeroize();
+        assert_eq!(slice, [1, 0, 0]);
+
+        let mut option = Some([7u8; 8]);
+        option.secure_zeroize();
+        assert!(option.is_none());
+
+        let mut tuple = (1u8, 2u128, [3usize; 2]);
+        tuple.secure_zeroize();
+        assert_eq!(tuple, (0, 0, [0, 0]));
+    }
+
+    #[test]
+    fn test_secure_buffer_types() {
+        let mut buf = SecureBuffer::new_owned(5).unwrap();
+        buf.as_mut_slice().copy_from_slice(&[1, 2, 3, 4, 5]);
+        buf.secure_zeroize();
+        assert_eq!(buf.len(), 5);
+        assert_eq!(buf.as_slice(), &[0; 5]);
+
+        let mut secret = SecretArray::<3>::from_slice(&[9, 9, 9]).unwrap();
+        secret.secure_zeroize();
+        assert_eq!(secret.expose_secret(), &[0; 3]);
+    }
+
+    #[test]
+    fn test_derive_respects_skip() {
+        let mut named = Named {
+            key: [0x11; 4],
+            counter: 42,
+            nonce: Some([5, 6]),
+            algorithm: 3,
+        };
+        named.secure_zeroize();
+        assert_eq!(named.key, [0; 4]);
+        assert_eq!(named.counter, 0);
+        assert!(named.nonce.is_none());
+        assert_eq!(named.algorithm, 3);
+
+        let mut tuple = Tuple(0xFFFF, true, (7, -7));
+        tuple.secure_zeroize();
+        assert_eq!((tuple.0, tuple.1, tuple.2), (0, true, (0, 0)));
+    }
+
+    #[test]
+    fn test_derive_enum_variants() {
+        let mut raw = Material::Raw([1, 2, 3]);
+        raw.secure_zeroize();
+        assert!(matches!(raw, Material::Raw([0, 0, 0])));
+
+        let mut split = Material::Split { left: 4, right: 5 };
+        split.secure_zeroize();
+        assert!(matches!(split, Material::Split { left: 0, right: 0 }));
+
+        let mut empty = Material::Empty;
+        empty.secure_zeroize();
+        assert!(matches!(empty, Material::Empty));
+    }
+
+    #[test]
+    fn test_zeroize_on_drop() {
+        let zeroized = Cell::new(0);
+        let skipped = Cell::new(0);
+        drop(Guarded {
+            probe: Probe(&zeroized),
+            _skipped: Probe(&skipped),
+        });
+        assert_eq!(zeroized.get(), 1);
+        assert_eq!(skipped.get(), 0);
+    }
+}
@@ -0,0 +1,136 @@
+//! `entlib-native-secure-buffer`의 `SecureZeroize` 트레이트를 위한 파생(derive) 매크로입니다.
+//!
+//! 이 크레이트는 직접 의존하지 않고 `entlib_native_secure_buffer`의 재노출 경로를 통해 사용합니다.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields, Index, parse_macro_input};
+
+/// 모든 필드에 대해 `SecureZeroize::secure_zeroize`를 호출하는 구현을 생성합니다.
+///
+/// 구조체(명명/튜플/유닛)와 열거형을 지원하며, 열거형은 현재 활성화된 변형(variant)의 필드만 소거합니다.
+/// 비밀이 아니거나 `SecureZeroize`를 구현하지 않는 필드(알고리즘 식별자, 아레나 핸들 등)는
+/// `#[zeroize(skip)]`으로 제외할 수 있습니다.
+#[proc_macro_derive(SecureZeroize, attributes(zeroize))]
+pub fn derive_secure_zeroize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_secure_zeroize(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// `Drop` 시점에 `SecureZeroize::secure_zeroize`를 호출하는 구현을 생성합니다.
+///
+/// 대상 타입은 `SecureZeroize`를 구현해야 하며, 일반적으로 `#[derive(SecureZeroize, ZeroizeOnDrop)]`
+/// 형태로 함께 사용합니다.
+#[proc_macro_derive(ZeroizeOnDrop)]
+pub fn derive_zeroize_on_drop(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::ops::Drop for #name #ty_generics #where_clause {
+            fn drop(&mut self) {
+                ::entlib_native_secure_buffer::SecureZeroize::secure_zeroize(self);
+            }
+        }
+    }
+    .into()
+}
+
+fn expand_secure_zeroize(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let calls = zeroize_fields(&data.fields, |i, field| match &field.ident {
+                Some(ident) => quote!(self.#ident),
+                None => {
+                    let index = Index::from(i);
+                    quote!(self.#index)
+                }
+            })?;
+            quote! { #(::entlib_native_secure_buffer::SecureZeroize::secure_zeroize(&mut #calls);)* }
+        }
+        Data::Enum(data) => {
+            let mut arms = Vec::with_capacity(data.variants.len());
+            for variant in &data.variants {
+                let ident = &variant.ident;
+                let bindings: Vec<_> = (0..variant.fields.len())
+                    .map(|i| format_ident!("__field_{}", i))
+                    .collect();
+                let calls = zeroize_fields(&variant.fields, |i, _| {
+                    let binding = &bindings[i];
+                    quote!(*#binding)
+                })?;
+                let pattern = match &variant.fields {
+                    Fields::Named(fields) => {
+                        let names = fields.named.iter().map(|f| &f.ident);
+                        quote!(Self::#ident { #(#names: #bindings),* })
+                    }
+                    Fields::Unnamed(_) => quote!(Self::#ident(#(#bindings),*)),
+                    Fields::Unit => quote!(Self::#ident),
+                };
+                arms.push(quote! {
+                    #[allow(unused_variables)]
+                    #pattern => {
+                        #(::entlib_native_secure_buffer::SecureZeroize::secure_zeroize(&mut #calls);)*
+                    }
+                });
+            }
+            quote! {
+                match self {
+                    #(#arms)*
+                    #[allow(unreachable_patterns)]
+                    _ => {}
+                }
+            }
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new_spanned(
+                data.union_token,
+                "SecureZeroize cannot be derived for unions",
+            ));
+        }
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::entlib_native_secure_buffer::SecureZeroize for #name #ty_generics #where_clause {
+            fn secure_zeroize(&mut self) {
+                #body
+            }
+        }
+    })
+}
+
+/// `#[zeroize(skip)]`이 지정되지 않은 필드에 대한 접근 식을 수집합니다.
+fn zeroize_fields(
+    fields: &Fields,
+    access: impl Fn(usize, &syn::Field) -> TokenStream2,
+) -> syn::Result<Vec<TokenStream2>> {
+    let mut calls = Vec::new();
+    for (i, field) in fields.iter().enumerate() {
+        if !is_skipped(field)? {
+            calls.push(access(i, field));
+        }
+    }
+    Ok(calls)
+}
+
+fn is_skipped(field: &syn::Field) -> syn::Result<bool> {
+    let mut skip = false;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("zeroize")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                skip = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported zeroize attribute, expected `skip`"))
+            }
+        })?;
+    }
+    Ok(skip)
+}
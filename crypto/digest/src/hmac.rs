@@ -0,0 +1,209 @@
+//! 해시 함수에 대해 제네릭한 HMAC(NIST FIPS 198-1) 구현입니다.
+//!
+//! 해시 구현 크레이트(`sha2`, `sha3`)가 중간 상태 인증([`midstate`](crate::midstate))에 사용할 수
+//! 있도록 이 크레이트에 두며, 알고리즘별 타입 별칭은 `entlib-native-hmac` 크레이트가 제공합니다.
+
+use crate::Digest;
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_secure_buffer::{
+    SecretKey, SecureArena, SecureBuffer, SecureZeroize, ZeroizeOnDrop,
+};
+
+/// HMAC 연산 중 발생할 수 있는 보안 오류
+#[derive(Debug)]
+pub enum HmacError {
+    /// NIST SP 800-107r1에 따른 최소 키 길이(112 bits / 14 bytes) 미달
+    WeakKeyLength,
+    /// 내부 해시 연산 중 발생한 오류
+    HashComputationError(&'static str),
+    /// 내부 해시 상태 또는 MAC 결과를 저장하기 위한 SecureBuffer 할당 실패
+    AllocationError(&'static str),
+}
+
+const IPAD: u8 = 0x36;
+const OPAD: u8 = 0x5c;
+// 지원하는 해시 함수 중 가장 큰 블록 크기 (SHA3-224 rate = 1152 bits)
+const MAX_BLOCK_SIZE: usize = 144;
+const MIN_KEY_LEN: usize = 14; // 112 bits (NIST SP 800-107r1)
+
+/// 키 패딩 전의 원본 키 블록(K0)입니다.
+///
+/// 초기화 도중 `?`로 조기 반환하는 경로를 포함해, 스코프를 벗어나는 모든 경로에서 `Drop` 시점에 소거됩니다.
+#[derive(SecureZeroize, ZeroizeOnDrop)]
+struct KeyBlock([u8; MAX_BLOCK_SIZE]);
+
+/// 생성된 MAC을 담는 래퍼 구조체입니다.
+///
+/// 내부 필드는 [`SecureBuffer`]로 관리되어, `Drop` 시점에 MAC 바이트가
+/// 자동으로 0으로 소거되고 OS 레벨 메모리 잠금(mlock)이 해제됩니다.
+pub struct MacResult(SecureBuffer);
+
+impl MacResult {
+    /// MAC 바이트를 읽기 전용 슬라이스로 반환합니다.
+    #[inline(always)]
+    pub fn as_slice(&self) -> &[u8] {
+        self.0.as_slice()
+    }
+}
+
+impl From<MacResult> for SecureBuffer {
+    /// MAC 바이트를 복사 없이 [`SecureBuffer`]로 이전합니다.
+    ///
+    /// HKDF의 PRK처럼 MAC 결과를 다시 키로 사용하는 경우, `SecretKey::try_from`과 함께
+    /// 사용하여 비밀 데이터의 추가 복사본 생성을 피할 수 있습니다.
+    #[inline(always)]
+    fn from(mac: MacResult) -> Self {
+        mac.0
+    }
+}
+
+impl PartialEq for MacResult {
+    /// 부채널 공격(Timing Attack) 방지를 위해 검증된 constant-time 크레이트 활용.
+    ///
+    /// MAC 길이(공개 정보)가 다르면 즉시 불일치로 판정하고, 같으면 바이트를 상수-시간으로 비교합니다.
+    #[inline(never)]
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_slice().ct_eq(other.0.as_slice()).unwrap_u8() == 0xFF
+    }
+}
+
+impl Eq for MacResult {}
+
+/// NIST FIPS 198-1 HMAC 구조체
+///
+/// 기반 해시 함수 `D`의 [`Digest::BLOCK_LEN`]으로 키를 패딩하고, [`Digest::OUTPUT_LEN`]
+/// 바이트의 MAC을 생성합니다. 메모리 잔존 공격 방지를 위해 `Drop` 시점에 키 패딩 블록이
+/// 소거되며, 내부 해시 상태는 해시 타입 자체의 `Drop`에서 소거됩니다.
+///
+/// 키 패딩 블록은 지원하는 최대 블록 크기로 고정 할당되며, 앞 `D::BLOCK_LEN` 바이트만 사용합니다.
+///
+/// 같은 키로 여러 메시지를 인증할 때는 초기화된 인스턴스를 `clone`하여 키 처리와 ipad 블록
+/// 압축을 반복하지 않을 수 있습니다. 복제본도 `Drop` 시점에 소거됩니다.
+#[derive(Clone, SecureZeroize, ZeroizeOnDrop)]
+pub struct Hmac<D: Digest> {
+    i_key_pad: [u8; MAX_BLOCK_SIZE],
+    o_key_pad: [u8; MAX_BLOCK_SIZE],
+    #[zeroize(skip)]
+    hasher: D,
+    #[zeroize(skip)]
+    arena: Option<SecureArena>,
+}
+
+impl<D: Digest> Hmac<D> {
+    /// MAC 크기 (바이트)
+    pub const MAC_LEN: usize = D::OUTPUT_LEN;
+
+    /// HMAC 초기화 및 키 준비 함수입니다.
+    pub fn new(key: &[u8]) -> Result<Self, HmacError> {
+        Self::init(key, None)
+    }
+
+    /// 이 HMAC 용도로 태그된 [`SecretKey`]로 초기화합니다.
+    ///
+    /// 키 길이가 타입에 고정되어 있으므로 다른 알고리즘용 키가 전달되는 실수를
+    /// 컴파일 타임에 차단합니다. 최소 키 길이 검증은 [`new`](Self::new)와 동일하게 수행됩니다.
+    pub fn from_key<const N: usize>(key: &SecretKey<Self, N>) -> Result<Self, HmacError> {
+        Self::init(key.expose_secret(), None)
+    }
+
+    /// 내부 해시 상태와 [`MacResult`]를 보안 아레나 슬롯에서 할당하는 HMAC 초기화 함수입니다.
+    ///
+    /// 다수의 짧은 MAC을 생성하는 워크로드에서 호출마다 발생하는 페이지 단위 할당 및
+    /// `mlock` 시스템 호출을 제거합니다.
+    pub fn new_in(key: &[u8], arena: &SecureArena) -> Result<Self, HmacError> {
+        Self::init(key, Some(arena))
+    }
+
+    /// 아레나 사용 여부(공개 정보)에 따라 해시 인스턴스를 생성합니다.
+    fn new_hasher(arena: Option<&SecureArena>) -> Result<D, HmacError> {
+        match arena {
+            Some(arena) => D::new_in(arena).map_err(HmacError::AllocationError),
+            None => D::try_new().map_err(HmacError::AllocationError),
+        }
+    }
+
+    fn init(key: &[u8], arena: Option<&SecureArena>) -> Result<Self, HmacError> {
+        // 해시된 키가 패딩 블록에 들어가지 않는 해시 함수는 컴파일 타임에 거부
+        const {
+            assert!(D::BLOCK_LEN <= MAX_BLOCK_SIZE && D::OUTPUT_LEN <= D::BLOCK_LEN);
+        }
+
+        // [Security Control] NIST SP 800-107r1 5.3절: 112비트 미만의 키 거부
+        if key.len() < MIN_KEY_LEN {
+            return Err(HmacError::WeakKeyLength);
+        }
+
+        let block_size = D::BLOCK_LEN;
+        let mut k_block = KeyBlock([0u8; MAX_BLOCK_SIZE]);
+
+        // 키 길이가 블록 크기보다 길 경우 해싱 (RFC 2104)
+        if key.len() > block_size {
+            let mut key_hasher = Self::new_hasher(arena)?;
+            key_hasher.update(key);
+            let hashed_key = key_hasher
+                .finalize()
+                .map_err(HmacError::HashComputationError)?;
+
+            let hash_slice = hashed_key.as_slice();
+            k_block.0[..hash_slice.len()].copy_from_slice(hash_slice);
+        } else {
+            k_block.0[..key.len()].copy_from_slice(key);
+        }
+
+        // 패딩 블록을 만들기 전에 내부 해시 인스턴스를 할당하여, 할당 실패 시 스택에 패딩 블록이
+        // 남지 않도록 함
+        let mut hasher = Self::new_hasher(arena)?;
+
+        let mut i_key_pad = [0u8; MAX_BLOCK_SIZE];
+        let mut o_key_pad = [0u8; MAX_BLOCK_SIZE];
+
+        for i in 0..block_size {
+            i_key_pad[i] = k_block.0[i] ^ IPAD;
+            o_key_pad[i] = k_block.0[i] ^ OPAD;
+        }
+
+        // 사용이 끝난 원본 키 블록은 즉시 소거
+        drop(k_block);
+
+        // H(K XOR ipad, text)의 첫 단계: H에 i_key_pad 주입
+        hasher.update(&i_key_pad[..block_size]);
+
+        Ok(Self {
+            i_key_pad,
+            o_key_pad,
+            hasher,
+            arena: arena.cloned(),
+        })
+    }
+
+    /// 스트리밍 방식을 지원하는 데이터 업데이트 함수입니다.
+    pub fn update(&mut self, data: &[u8]) {
+        self.hasher.update(data);
+    }
+
+    /// 최종 MAC 계산 및 반환 함수입니다.
+    pub fn finalize(mut self) -> Result<MacResult, HmacError> {
+        // SecureBuffer에 MAC을 기록하여 mlock + 자동 소거 보장
+        let mut mac_buf = SecureBuffer::new_in(Self::MAC_LEN, self.arena.as_ref())
+            .map_err(HmacError::AllocationError)?;
+
+        // 내부 해시 결과 H(K XOR ipad, text). 내부 해시 인스턴스는 초기 상태로 복귀하므로
+        // 새 인스턴스 할당 없이 외부 해시에 재사용
+        let mut inner_hash = [0u8; MAX_BLOCK_SIZE];
+        let inner_hash = &mut inner_hash[..D::OUTPUT_LEN];
+        self.hasher
+            .finalize_into(inner_hash)
+            .map_err(HmacError::HashComputationError)?;
+
+        // H(K XOR opad, H(K XOR ipad, text))
+        self.hasher.update(&self.o_key_pad[..D::BLOCK_LEN]);
+        self.hasher.update(inner_hash);
+        inner_hash.secure_zeroize();
+
+        self.hasher
+            .finalize_into(mac_buf.as_mut_slice())
+            .map_err(HmacError::HashComputationError)?;
+
+        Ok(MacResult(mac_buf))
+    }
+}
@@ -0,0 +1,159 @@
+//! 해시 함수 공통 트레이트 모음입니다.
+//!
+//! `sha2`, `sha3` 크레이트의 모든 해시 구조체가 이 트레이트를 구현하므로, HMAC, HKDF 및
+//! 애플리케이션 코드는 알고리즘별 매크로 없이 해시 함수에 대해 제네릭하게 작성할 수 있습니다.
+//!
+//! | 트레이트             | 구현 타입                                                 |
+//! |----------------------|-----------------------------------------------------------|
+//! | [`Digest`]           | `SHA224` … `SHA512`, `SHA512_224`, `SHA512_256`, `SHA3_*` |
+//! | [`ExtendableOutput`] | `SHAKE128`, `SHAKE256`                                    |
+//! | [`Reset`]            | 위의 모든 해시 구조체                                     |
+//! | [`Midstate`]         | 위의 모든 해시 구조체                                     |
+//!
+//! 이 트레이트들에 대해 제네릭한 HMAC 구현은 [`hmac`] 모듈에 있으며, `hmac` 크레이트가 이를
+//! 재노출하고 알고리즘별 타입 별칭을 제공합니다.
+//!
+//! 각 해시 구조체는 동일한 이름의 고유(inherent) 메서드도 계속 제공하므로, 트레이트를
+//! 가져오지 않은 기존 코드는 변경 없이 동작합니다.
+//!
+//! 모든 해시 구조체는 `Clone`을 구현합니다. 복제본은 내부 버퍼를 원본과 같은 아레나(또는
+//! 개별 잠긴 메모리 블록)에 새로 할당하며, 원본과 마찬가지로 `Drop` 시점에 소거됩니다.
+//!
+//! # Usage
+//! ```
+//! use entlib_native_digest::Digest;
+//! use entlib_native_secure_buffer::SecureBuffer;
+//!
+//! fn hash_twice<D: Digest>(data: &[u8]) -> Result<SecureBuffer, &'static str> {
+//!     let mut inner = D::new();
+//!     inner.update(data);
+//!     let first = inner.finalize()?;
+//!
+//!     let mut outer = D::new();
+//!     outer.update(first.as_slice());
+//!     outer.finalize()
+//! }
+//! ```
+
+#![no_std]
+
+pub mod hmac;
+pub mod midstate;
+
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer};
+
+/// 고정 길이 출력 해시 함수입니다.
+///
+/// 반환되는 다이제스트는 [`SecureBuffer`]에 보관되어 `Drop` 시점에 소거됩니다.
+pub trait Digest: Sized {
+    /// 다이제스트 크기 (바이트)
+    const OUTPUT_LEN: usize;
+    /// 입력 블록 크기 (바이트). HMAC 키 패딩 길이로 사용됩니다.
+    ///
+    /// SHA-2는 압축 함수의 블록 크기, SHA-3는 스펀지의 rate입니다 (NIST FIPS 198-1, FIPS 202).
+    const BLOCK_LEN: usize;
+
+    /// 인스턴스를 초기화합니다.
+    ///
+    /// # Panics
+    /// 내부 버퍼의 보안 메모리 할당(`mlock`)에 실패하면 패닉합니다. 라이브러리 코드에서는
+    /// [`try_new`](Self::try_new)를 사용하세요.
+    fn new() -> Self;
+
+    /// 인스턴스를 초기화합니다. 보안 메모리 할당에 실패하면 패닉 대신 오류를 반환합니다.
+    fn try_new() -> Result<Self, &'static str>;
+
+    /// 내부 버퍼와 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스를 초기화합니다.
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str>;
+
+    /// 해시 대상 데이터를 주입합니다.
+    fn update(&mut self, data: &[u8]);
+
+    /// 해시 연산을 완료하고 [`OUTPUT_LEN`](Self::OUTPUT_LEN) 바이트의 다이제스트를 반환합니다.
+    fn finalize(self) -> Result<SecureBuffer, &'static str>;
+
+    /// 다이제스트를 `out`에 기록하고 인스턴스를 [`new`](Self::new) 직후의 상태로 되돌립니다.
+    ///
+    /// 메모리 할당이 발생하지 않으므로, 머클 트리나 해시 기반 서명처럼 짧은 메시지를 대량으로
+    /// 해시하는 경우 호출마다 발생하는 `mlock`/`munlock` 시스템 호출을 피할 수 있습니다.
+    /// `out`의 소거는 호출자의 책임입니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - `out`의 길이가 [`OUTPUT_LEN`](Self::OUTPUT_LEN)과 다른 경우
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str>;
+
+    /// 다이제스트를 반환하고 인스턴스를 [`new`](Self::new) 직후의 상태로 되돌립니다.
+    ///
+    /// 다이제스트 버퍼 할당에 실패하면 인스턴스 상태는 변경되지 않습니다.
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str>;
+}
+
+/// 가변 길이 출력 함수(XOF, eXtendable-Output Function)입니다.
+pub trait ExtendableOutput: Sized {
+    /// 스펀지의 rate (바이트)
+    const BLOCK_LEN: usize;
+
+    /// 인스턴스를 초기화합니다.
+    ///
+    /// # Panics
+    /// 내부 버퍼의 보안 메모리 할당(`mlock`)에 실패하면 패닉합니다. 라이브러리 코드에서는
+    /// [`try_new`](Self::try_new)를 사용하세요.
+    fn new() -> Self;
+
+    /// 인스턴스를 초기화합니다. 보안 메모리 할당에 실패하면 패닉 대신 오류를 반환합니다.
+    fn try_new() -> Result<Self, &'static str>;
+
+    /// 내부 버퍼와 출력을 보안 아레나 슬롯에서 할당하는 인스턴스를 초기화합니다.
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str>;
+
+    /// 해시 대상 데이터를 주입합니다.
+    fn update(&mut self, data: &[u8]);
+
+    /// 흡수를 완료하고 `output_len` 바이트를 출력합니다.
+    fn finalize_xof(self, output_len: usize) -> Result<SecureBuffer, &'static str>;
+
+    /// 흡수를 완료하여 `out`의 길이만큼 출력하고 인스턴스를 [`new`](Self::new) 직후의
+    /// 상태로 되돌립니다. 메모리 할당이 발생하지 않습니다.
+    fn finalize_xof_into(&mut self, out: &mut [u8]);
+
+    /// 흡수를 완료하고 `output_len` 바이트를 출력한 뒤 인스턴스를 [`new`](Self::new) 직후의
+    /// 상태로 되돌립니다.
+    ///
+    /// 출력 버퍼 할당에 실패하면 인스턴스 상태는 변경되지 않습니다.
+    fn finalize_xof_reset(&mut self, output_len: usize) -> Result<SecureBuffer, &'static str>;
+}
+
+/// 해시 인스턴스를 초기 상태로 되돌립니다.
+pub trait Reset {
+    /// 지금까지 주입된 데이터와 체인 값을 소거하고 [`Digest::new`] 직후의 상태로 되돌립니다.
+    ///
+    /// 보안 아레나에서 할당된 인스턴스는 동일한 슬롯을 재사용하므로 추가 할당이 발생하지 않습니다.
+    fn reset(&mut self);
+}
+
+/// 해시 중간 상태(midstate)를 인증된 형식으로 내보내고 복원합니다.
+///
+/// 고정된 접두사(예: HMAC ipad 블록, 프로토콜 전사의 공통 머리부)를 흡수한 상태를 보관해 두면,
+/// 이후 재해싱 없이 같은 지점에서 해시를 이어갈 수 있습니다. 직렬화 포맷은 [`midstate`] 모듈을
+/// 참고하세요. 같은 프로세스 안에서의 분기만 필요하다면 `Clone`이 더 저렴합니다.
+pub trait Midstate: Sized {
+    /// 현재 상태를 `key`로 인증하여 직렬화합니다. 원본 인스턴스는 변경되지 않습니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - `key`가 [`midstate::MIN_KEY_LEN`]보다 짧거나 메모리 할당 실패 시
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str>;
+
+    /// [`export_midstate`](Self::export_midstate)로 직렬화된 상태를 검증하고 복원합니다.
+    ///
+    /// # Returns
+    /// - `Err(&'static str)` - 키 길이 미달, 길이/버전/알고리즘 불일치, 태그 검증 실패 또는
+    ///   복원된 값이 유효 범위를 벗어난 경우
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str>;
+
+    /// 내부 버퍼를 보안 아레나 슬롯에서 할당하여 상태를 복원합니다.
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str>;
+}
@@ -0,0 +1,134 @@
+//! 해시 중간 상태(midstate) 직렬화 포맷입니다.
+//!
+//! `sha2`, `sha3` 크레이트가 [`Midstate`](crate::Midstate)를 구현할 때 공유하는 버전 헤더와
+//! 인증 태그 처리를 제공합니다. 본문(body)의 구성은 알고리즘마다 고정되어 있으며 각 구현
+//! 크레이트가 정의합니다.
+//!
+//! ```text
+//! +------+---------+-----------+------------------+---------------------------+
+//! | "EM" | version | algorithm | body             | tag                       |
+//! | 2 B  | 1 B     | 1 B       | 알고리즘별 고정  | 32 B, HMAC(key, 앞 전체)  |
+//! +------+---------+-----------+------------------+---------------------------+
+//! ```
+//!
+//! 태그는 호출자 키로 계산한 HMAC(NIST FIPS 198-1, [`hmac`](crate::hmac) 모듈)이며, 기반 해시
+//! 함수는 구현 크레이트가 선택합니다(`sha2`: HMAC-SHA-256, `sha3`: HMAC-SHA3-256). 직렬화된 상태는
+//! 흡수한 데이터에서 유도된 값을 포함하므로 [`SecureBuffer`]로 반환되지만, 포맷 자체는 기밀성을
+//! 제공하지 않습니다. 보안 메모리 밖에 저장해야 한다면 별도로 암호화해야 합니다.
+
+use crate::Digest;
+use crate::hmac::{Hmac, HmacError, MacResult};
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer};
+
+/// 현재 포맷 버전
+pub const VERSION: u8 = 1;
+/// 헤더 길이 (매직 2바이트, 버전, 알고리즘 식별자)
+pub const HEADER_LEN: usize = 4;
+/// 인증 태그 길이
+pub const TAG_LEN: usize = 32;
+/// 인증 키 최소 길이. 112 bits (NIST SP 800-107r1)
+pub const MIN_KEY_LEN: usize = 14;
+
+const MAGIC: [u8; 2] = *b"EM";
+
+/// 직렬화된 상태의 알고리즘 식별자
+///
+/// 같은 크기의 상태를 갖는 알고리즘(예: SHA-256과 SHA-224) 사이의 잘못된 복원을 차단합니다.
+#[repr(u8)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Algorithm {
+    Sha224 = 0x01,
+    Sha256 = 0x02,
+    Sha384 = 0x03,
+    Sha512 = 0x04,
+    Sha512_224 = 0x05,
+    Sha512_256 = 0x06,
+    Sha3_224 = 0x11,
+    Sha3_256 = 0x12,
+    Sha3_384 = 0x13,
+    Sha3_512 = 0x14,
+    Shake128 = 0x21,
+    Shake256 = 0x22,
+}
+
+/// `body_len` 바이트의 본문을 `write`로 채운 뒤, 헤더와 `D` 기반 HMAC 태그를 붙여 봉인합니다.
+///
+/// # Returns
+/// - `Err(&'static str)` - `key`가 [`MIN_KEY_LEN`]보다 짧거나 메모리 할당 실패 시
+pub fn seal<D: Digest>(
+    algorithm: Algorithm,
+    key: &[u8],
+    body_len: usize,
+    arena: Option<&SecureArena>,
+    write: impl FnOnce(&mut [u8]),
+) -> Result<SecureBuffer, &'static str> {
+    if key.len() < MIN_KEY_LEN {
+        return Err("Midstate key is too short");
+    }
+
+    let mut out = SecureBuffer::new_in(HEADER_LEN + body_len + TAG_LEN, arena)?;
+    let (authenticated, tag) = out.as_mut_slice().split_at_mut(HEADER_LEN + body_len);
+    authenticated[..2].copy_from_slice(&MAGIC);
+    authenticated[2] = VERSION;
+    authenticated[3] = algorithm as u8;
+    write(&mut authenticated[HEADER_LEN..]);
+
+    let mac = compute_tag::<D>(key, authenticated)?;
+    tag.copy_from_slice(mac.as_slice());
+    Ok(out)
+}
+
+/// 헤더와 태그를 검증하고 본문 슬라이스를 반환합니다.
+///
+/// 길이, 헤더 필드는 공개 정보이므로 먼저 검사하며, 태그는 상수-시간으로 비교합니다.
+///
+/// # Returns
+/// - `Err(&'static str)` - 키 길이 미달, 길이/매직/버전/알고리즘 불일치 또는 태그 검증 실패 시
+pub fn open<'a, D: Digest>(
+    algorithm: Algorithm,
+    key: &[u8],
+    blob: &'a [u8],
+    body_len: usize,
+) -> Result<&'a [u8], &'static str> {
+    if key.len() < MIN_KEY_LEN {
+        return Err("Midstate key is too short");
+    }
+    if blob.len() != HEADER_LEN + body_len + TAG_LEN {
+        return Err("Midstate length mismatch");
+    }
+    if blob[..2] != MAGIC {
+        return Err("Midstate magic mismatch");
+    }
+    if blob[2] != VERSION {
+        return Err("Unsupported midstate version");
+    }
+    if blob[3] != algorithm as u8 {
+        return Err("Midstate algorithm mismatch");
+    }
+
+    let (authenticated, tag) = blob.split_at(HEADER_LEN + body_len);
+    let expected = compute_tag::<D>(key, authenticated)?;
+    if expected.as_slice().ct_eq(tag).unwrap_u8() != 0xFF {
+        return Err("Midstate authentication failed");
+    }
+    Ok(&authenticated[HEADER_LEN..])
+}
+
+/// 인증 태그를 계산합니다. HMAC 연산은 [`Hmac`] 구현을 그대로 사용합니다.
+fn compute_tag<D: Digest>(key: &[u8], data: &[u8]) -> Result<MacResult, &'static str> {
+    const {
+        assert!(D::OUTPUT_LEN == TAG_LEN);
+    }
+
+    let mut mac = Hmac::<D>::new(key).map_err(compute_tag_error)?;
+    mac.update(data);
+    mac.finalize().map_err(compute_tag_error)
+}
+
+fn compute_tag_error(err: HmacError) -> &'static str {
+    match err {
+        HmacError::WeakKeyLength => "Midstate key is too short",
+        HmacError::HashComputationError(e) | HmacError::AllocationError(e) => e,
+    }
+}
@@ -1,10 +1,10 @@
 use core::cmp::min;
-use core::ptr::write_volatile;
-use entlib_native_hmac::{
-    HMACSHA3_224, HMACSHA3_256, HMACSHA3_384, HMACSHA3_512, HMACSHA224, HMACSHA256, HMACSHA384,
-    HMACSHA512,
-};
-use entlib_native_secure_buffer::SecureBuffer;
+use core::marker::PhantomData;
+use entlib_native_digest::Digest;
+use entlib_native_hmac::{Hmac, HmacError};
+use entlib_native_secure_buffer::{SecretKey, SecureBuffer, SecureZeroize};
+use entlib_native_sha2::api::{SHA224, SHA256, SHA384, SHA512, SHA512_224, SHA512_256};
+use entlib_native_sha3::api::{SHA3_224, SHA3_256, SHA3_384, SHA3_512};
 
 /// HKDF 연산 중 발생할 수 있는 상태 및 오류를 정의합니다.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -18,148 +18,159 @@ pub enum HKDFState {
     HmacError,
 }
 
-macro_rules! impl_hkdf {
-    (
-        $struct_name:ident,
-        $hmac_type:ty,
-        $hash_len:expr
-    ) => {
-        /// NIST SP 800-56Cr2를 준수하는 HKDF 인스턴스
-        pub struct $struct_name;
-
-        impl Default for $struct_name {
-            fn default() -> Self {
-                Self::new()
-            }
+/// HMAC 오류를 HKDF 상태로 변환합니다. 보안 메모리 할당 실패는 [`HKDFState::AllocationFailed`]로 구분합니다.
+fn hmac_state(err: HmacError) -> HKDFState {
+    match err {
+        HmacError::AllocationError(_) => HKDFState::AllocationFailed,
+        _ => HKDFState::HmacError,
+    }
+}
+
+/// NIST SP 800-56Cr2를 준수하는 HKDF 인스턴스
+///
+/// `D`는 HMAC의 기반 해시 함수이며, `N`은 해시 출력 크기(`D::OUTPUT_LEN`)입니다.
+/// PRK 타입 [`SecretKey<Self, N>`]과 내부 블록 배열의 길이는 타입 위치에서 결정되어야 하므로
+/// 출력 크기를 상수 인자로 함께 받으며, `N`과 `D::OUTPUT_LEN`의 불일치는 컴파일 타임에 거부됩니다.
+pub struct Hkdf<D: Digest, const N: usize> {
+    _digest: PhantomData<fn() -> D>,
+}
+
+impl<D: Digest, const N: usize> Default for Hkdf<D, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: Digest, const N: usize> Hkdf<D, N> {
+    /// 이 HKDF 구현에서 사용하는 기반 해시 함수의 출력 크기 (바이트)
+    pub const HASH_LEN: usize = N;
+    /// RFC 5869에 따른 OKM 최대 출력 크기 제한 (255 * HashLen)
+    pub const MAX_OKM_LEN: usize = 255 * N;
+
+    /// 새로운 HKDF 인스턴스를 생성합니다.
+    #[inline(always)]
+    pub fn new() -> Self {
+        const {
+            assert!(N == D::OUTPUT_LEN, "HKDF hash length mismatch");
+        }
+        Self {
+            _digest: PhantomData,
+        }
+    }
+
+    /// Extract (추출)
+    ///
+    /// 입력된 키 구성 물질(IKM)과 Salt를 사용하여 고정된 길이의 의사난수 키(PRK)를 생성합니다.
+    ///
+    /// 반환되는 PRK는 이 HKDF 인스턴스 전용으로 태그된 [`SecretKey`]로, 다른 해시 함수의
+    /// `expand`에 전달할 수 없습니다. 내부적으로 `SecureBuffer`에 보관되어
+    /// 메모리 스왑 방지 및 사용 후 즉각적인 Zeroization이 보장됩니다.
+    pub fn extract(
+        &self,
+        salt: Option<&[u8]>,
+        ikm: &[u8],
+    ) -> Result<SecretKey<Self, N>, HKDFState> {
+        let default_salt = [0u8; N];
+        let actual_salt = salt.unwrap_or(&default_salt);
+
+        let mut hmac = Hmac::<D>::new(actual_salt).map_err(hmac_state)?;
+        hmac.update(ikm);
+        let prk_mac = hmac.finalize().map_err(hmac_state)?;
+
+        // MAC 결과 버퍼를 복사 없이 PRK로 이전
+        SecretKey::try_from(SecureBuffer::from(prk_mac)).map_err(|_| HKDFState::InvalidLength)
+    }
+
+    /// Expand (확장)
+    ///
+    /// PRK와 컨텍스트(info)를 결합하여 원하는 길이(length)의 출력 키 물질(OKM)을 생성합니다.
+    ///
+    /// 외부에서 전달받은 PRK는 `SecretKey::from_slice` 또는 `SecretKey::try_from`으로 변환하여 전달합니다.
+    pub fn expand(
+        &self,
+        prk: &SecretKey<Self, N>,
+        okm: &mut [u8],
+        info: &[u8],
+        length: usize,
+    ) -> Result<(), HKDFState> {
+        // 입력 길이 및 버퍼 크기에 대한 엄격한 검증
+        if length > Self::MAX_OKM_LEN || okm.len() < length {
+            return Err(HKDFState::InvalidLength);
         }
 
-        impl $struct_name {
-            /// 이 HKDF 구현에서 사용하는 기반 해시 함수의 출력 크기 (바이트)
-            pub const HASH_LEN: usize = $hash_len;
-            /// RFC 5869에 따른 OKM 최대 출력 크기 제한 (255 * HashLen)
-            pub const MAX_OKM_LEN: usize = 255 * $hash_len;
+        let mut t = [0u8; N];
+        let mut okm_offset = 0;
 
-            /// 새로운 HKDF 인스턴스를 생성합니다.
-            #[inline(always)]
-            pub fn new() -> Self {
-                Self
-            }
+        // MAX_OKM_LEN 검증으로 블록 수는 255 이하가 보장됨
+        let n = length.div_ceil(Self::HASH_LEN) as u8;
 
-            /// Extract (추출)
-            ///
-            /// 입력된 키 구성 물질(IKM)과 Salt를 사용하여 고정된 길이의 의사난수 키(PRK)를 생성합니다.
-            ///
-            /// 반환되는 PRK는 `SecureBuffer`에 안전하게 보관되어,
-            /// 메모리 스왑 방지 및 사용 후 즉각적인 Zeroization이 보장됩니다.
-            pub fn extract(
-                &self,
-                salt: Option<&[u8]>,
-                ikm: &[u8],
-            ) -> Result<SecureBuffer, HKDFState> {
-                let default_salt = [0u8; $hash_len];
-                let actual_salt = salt.unwrap_or(&default_salt);
-
-                let mut hmac = <$hmac_type>::new(actual_salt).map_err(|_| HKDFState::HmacError)?;
-                hmac.update(ikm);
-                let prk_mac = hmac.finalize().map_err(|_| HKDFState::HmacError)?;
-
-                // PRK는 SecureBuffer를 통해 관리
-                let mut prk_buffer =
-                    SecureBuffer::new_owned($hash_len).map_err(|_| HKDFState::AllocationFailed)?;
-                prk_buffer
-                    .as_mut_slice()
-                    .copy_from_slice(prk_mac.as_slice());
-
-                Ok(prk_buffer)
-            }
+        for block_index in 1..=n {
+            let mut hmac = Hmac::<D>::new(prk.expose_secret()).map_err(hmac_state)?;
 
-            /// Expand (확장)
-            ///
-            /// PRK와 컨텍스트(info)를 결합하여 원하는 길이(length)의 출력 키 물질(OKM)을 생성합니다.
-            pub fn expand(
-                &self,
-                prk: &SecureBuffer,
-                okm: &mut [u8],
-                info: &[u8],
-                length: usize,
-            ) -> Result<(), HKDFState> {
-                // 입력 길이 및 버퍼 크기에 대한 엄격한 검증
-                if length > Self::MAX_OKM_LEN || okm.len() < length {
-                    return Err(HKDFState::InvalidLength);
-                }
-                if prk.len() < Self::HASH_LEN {
-                    return Err(HKDFState::InvalidLength);
-                }
-
-                let mut t = [0u8; $hash_len];
-                let mut okm_offset = 0;
-                let mut block_index: u8 = 1;
-
-                let n = length.div_ceil(Self::HASH_LEN);
-
-                for i in 0..n {
-                    let mut hmac =
-                        <$hmac_type>::new(prk.as_slice()).map_err(|_| HKDFState::HmacError)?;
-
-                    if i > 0 {
-                        hmac.update(&t);
-                    }
-                    hmac.update(info);
-                    hmac.update(&[block_index]);
-
-                    let mac = hmac.finalize().map_err(|_| HKDFState::HmacError)?;
-                    t.copy_from_slice(mac.as_slice());
-
-                    let copy_len = min(Self::HASH_LEN, length - okm_offset);
-                    okm[okm_offset..okm_offset + copy_len].copy_from_slice(&t[..copy_len]);
-
-                    okm_offset += copy_len;
-                    block_index += 1;
-                }
-
-                // T 블록 강제 소거
-                for byte in &mut t {
-                    unsafe {
-                        write_volatile(byte, 0);
-                    }
-                }
-
-                Ok(())
+            if block_index > 1 {
+                hmac.update(&t);
             }
+            hmac.update(info);
+            hmac.update(&[block_index]);
 
-            /// 단일 단계 키 유도 (Extract-then-Expand)
-            ///
-            /// IKM과 Salt를 사용해 내부적으로 PRK를 추출한 뒤, 즉시 Context(info)와 결합하여
-            /// 원하는 길이의 출력 키 물질(OKM)을 생성합니다.
-            ///
-            /// # Security Rationale
-            /// 내부적으로 생성된 PRK(`SecureBuffer`)는 반환과 동시에 스코프를 벗어나며,
-            /// `Drop` 트레이트를 통해 즉시 하드웨어 수준에서 강제 소거(Zeroize)됩니다.
-            pub fn derive_key(
-                &self,
-                salt: Option<&[u8]>,
-                ikm: &[u8],
-                okm: &mut [u8],
-                info: &[u8],
-                length: usize,
-            ) -> Result<(), HKDFState> {
-                // Extract (PRK 생성 및 잠긴 메모리에 보관)
-                let prk_buffer = self.extract(salt, ikm)?;
-
-                // Expand (PRK를 사용하여 OKM 생성)
-                // okm 버퍼의 크기 검증 등은 내부 expand 메소드의 Zero-Trust 로직에 위임
-                self.expand(&prk_buffer, okm, info, length)
-            }
+            let mac = hmac.finalize().map_err(hmac_state)?;
+            t.copy_from_slice(mac.as_slice());
+
+            let copy_len = min(Self::HASH_LEN, length - okm_offset);
+            okm[okm_offset..okm_offset + copy_len].copy_from_slice(&t[..copy_len]);
+
+            okm_offset += copy_len;
         }
-    };
-}
 
-impl_hkdf!(HKDFSHA224, HMACSHA224, 28);
-impl_hkdf!(HKDFSHA256, HMACSHA256, 32);
-impl_hkdf!(HKDFSHA384, HMACSHA384, 48);
-impl_hkdf!(HKDFSHA512, HMACSHA512, 64);
+        // T 블록 강제 소거
+        t.secure_zeroize();
+
+        Ok(())
+    }
+
+    /// 단일 단계 키 유도 (Extract-then-Expand)
+    ///
+    /// IKM과 Salt를 사용해 내부적으로 PRK를 추출한 뒤, 즉시 Context(info)와 결합하여
+    /// 원하는 길이의 출력 키 물질(OKM)을 생성합니다.
+    ///
+    /// # Security Rationale
+    /// 내부적으로 생성된 PRK(`SecretKey`)는 반환과 동시에 스코프를 벗어나며,
+    /// `Drop` 트레이트를 통해 즉시 하드웨어 수준에서 강제 소거(Zeroize)됩니다.
+    pub fn derive_key(
+        &self,
+        salt: Option<&[u8]>,
+        ikm: &[u8],
+        okm: &mut [u8],
+        info: &[u8],
+        length: usize,
+    ) -> Result<(), HKDFState> {
+        // Extract (PRK 생성 및 잠긴 메모리에 보관)
+        let prk_buffer = self.extract(salt, ikm)?;
+
+        // Expand (PRK를 사용하여 OKM 생성)
+        // okm 버퍼의 크기 검증 등은 내부 expand 메소드의 Zero-Trust 로직에 위임
+        self.expand(&prk_buffer, okm, info, length)
+    }
+}
 
-impl_hkdf!(HKDFSHA3_224, HMACSHA3_224, 28);
-impl_hkdf!(HKDFSHA3_256, HMACSHA3_256, 32);
-impl_hkdf!(HKDFSHA3_384, HMACSHA3_384, 48);
-impl_hkdf!(HKDFSHA3_512, HMACSHA3_512, 64);
+/// HKDF-SHA224
+pub type HKDFSHA224 = Hkdf<SHA224, 28>;
+/// HKDF-SHA256
+pub type HKDFSHA256 = Hkdf<SHA256, 32>;
+/// HKDF-SHA384
+pub type HKDFSHA384 = Hkdf<SHA384, 48>;
+/// HKDF-SHA512
+pub type HKDFSHA512 = Hkdf<SHA512, 64>;
+/// HKDF-SHA512/224
+pub type HKDFSHA512_224 = Hkdf<SHA512_224, 28>;
+/// HKDF-SHA512/256
+pub type HKDFSHA512_256 = Hkdf<SHA512_256, 32>;
+
+/// HKDF-SHA3-224
+pub type HKDFSHA3_224 = Hkdf<SHA3_224, 28>;
+/// HKDF-SHA3-256
+pub type HKDFSHA3_256 = Hkdf<SHA3_256, 32>;
+/// HKDF-SHA3-384
+pub type HKDFSHA3_384 = Hkdf<SHA3_384, 48>;
+/// HKDF-SHA3-512
+pub type HKDFSHA3_512 = Hkdf<SHA3_512, 64>;
@@ -0,0 +1,96 @@
+use entlib_native_hkdf::{HKDFSHA256, HKDFSHA512_256, HKDFState};
+use entlib_native_secure_buffer::SecretKey;
+
+//
+// RFC 5869 Test Case 1 (HKDF-SHA-256)
+//
+
+const IKM: [u8; 22] = [0x0b; 22];
+const INFO: [u8; 10] = [0xf0, 0xf1, 0xf2, 0xf3, 0xf4, 0xf5, 0xf6, 0xf7, 0xf8, 0xf9];
+const PRK: [u8; 32] = [
+    0x07, 0x77, 0x09, 0x36, 0x2c, 0x2e, 0x32, 0xdf, 0x0d, 0xdc, 0x3f, 0x0d, 0xc4, 0x7b, 0xba, 0x63,
+    0x90, 0xb6, 0xc7, 0x3b, 0xb5, 0x0f, 0x9c, 0x31, 0x22, 0xec, 0x84, 0x4a, 0xd7, 0xc2, 0xb3, 0xe5,
+];
+const OKM: [u8; 42] = [
+    0x3c, 0xb2, 0x5f, 0x25, 0xfa, 0xac, 0xd5, 0x7a, 0x90, 0x43, 0x4f, 0x64, 0xd0, 0x36, 0x2f, 0x2a,
+    0x2d, 0x2d, 0x0a, 0x90, 0xcf, 0x1a, 0x5a, 0x4c, 0x5d, 0xb0, 0x2d, 0x56, 0xec, 0xc4, 0xc5, 0xbf,
+    0x34, 0x00, 0x72, 0x08, 0xd5, 0xb8, 0x87, 0x18, 0x58, 0x65,
+];
+
+/// Expand: 외부 PRK를 SecretKey로 변환하여 전달
+///
+/// TC1의 13바이트 Salt는 HMAC 최소 키 길이(14바이트) 미달로 Extract가 거부되므로 Expand만 검증
+#[test]
+fn sha256_expand_with_imported_prk() {
+    let hkdf = HKDFSHA256::new();
+    let prk = SecretKey::<HKDFSHA256, 32>::from_slice(&PRK).unwrap();
+
+    let mut okm = [0u8; 42];
+    hkdf.expand(&prk, &mut okm, &INFO, 42).unwrap();
+    assert_eq!(okm, OKM);
+}
+
+//
+// RFC 5869 Test Case 3 (HKDF-SHA-256, Salt/Info 없음)
+//
+
+const TC3_PRK: [u8; 32] = [
+    0x19, 0xef, 0x24, 0xa3, 0x2c, 0x71, 0x7b, 0x16, 0x7f, 0x33, 0xa9, 0x1d, 0x6f, 0x64, 0x8b, 0xdf,
+    0x96, 0x59, 0x67, 0x76, 0xaf, 0xdb, 0x63, 0x77, 0xac, 0x43, 0x4c, 0x1c, 0x29, 0x3c, 0xcb, 0x04,
+];
+const TC3_OKM: [u8; 42] = [
+    0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c, 0x5a, 0x31,
+    0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f, 0x3c, 0x73, 0x8d, 0x2d,
+    0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a, 0x96, 0xc8,
+];
+
+/// Extract → 타입 태그된 PRK, Expand → OKM
+#[test]
+fn sha256_rfc5869_tc3() {
+    let hkdf = HKDFSHA256::new();
+    let prk = hkdf.extract(None, &IKM).unwrap();
+    assert_eq!(prk.expose_secret(), &TC3_PRK);
+
+    let mut okm = [0u8; 42];
+    hkdf.expand(&prk, &mut okm, &[], 42).unwrap();
+    assert_eq!(okm, TC3_OKM);
+}
+
+/// 출력 버퍼보다 긴 OKM 요청 거부
+#[test]
+fn sha256_expand_rejects_short_output() {
+    let hkdf = HKDFSHA256::new();
+    let prk = SecretKey::<HKDFSHA256, 32>::from_slice(&PRK).unwrap();
+
+    let mut okm = [0u8; 16];
+    assert_eq!(
+        hkdf.expand(&prk, &mut okm, &INFO, 42),
+        Err(HKDFState::InvalidLength)
+    );
+}
+
+//
+// HKDF-SHA-512/256 (RFC 5869 TC3 입력, 기대값은 OpenSSL로 생성)
+//
+
+const SHA512_256_PRK: [u8; 32] = [
+    0xa4, 0x57, 0x95, 0x4b, 0x03, 0x29, 0x2e, 0x63, 0xb7, 0x46, 0x39, 0x67, 0x17, 0x1d, 0x1c, 0x0c,
+    0xa3, 0xae, 0x24, 0x6b, 0x8b, 0x96, 0x7c, 0xde, 0x4d, 0x05, 0xff, 0x55, 0x1c, 0xdf, 0xc0, 0x22,
+];
+const SHA512_256_OKM: [u8; 42] = [
+    0xfa, 0x6f, 0xf4, 0x5b, 0x2f, 0xc4, 0xf0, 0xf4, 0x98, 0x83, 0xd9, 0xc4, 0xc9, 0xf9, 0xed, 0xfb,
+    0x53, 0xce, 0xbb, 0x3f, 0x9f, 0xaa, 0xc5, 0x71, 0x31, 0x9c, 0x7b, 0xd1, 0x7d, 0x37, 0x1a, 0x0a,
+    0xbc, 0xa6, 0x5d, 0x85, 0xeb, 0x3d, 0x41, 0x49, 0x51, 0x58,
+];
+
+/// 절단된 SHA-512 변형에서도 PRK 길이가 다이제스트 길이(32바이트)와 일치하는지 검증
+#[test]
+fn sha512_256_extract_and_expand() {
+    let hkdf = HKDFSHA512_256::new();
+    let prk = hkdf.extract(None, &IKM).unwrap();
+    assert_eq!(prk.expose_secret(), &SHA512_256_PRK);
+
+    let mut okm = [0u8; 42];
+    hkdf.expand(&prk, &mut okm, &[], 42).unwrap();
+    assert_eq!(okm, SHA512_256_OKM);
+}
@@ -0,0 +1,48 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-hkdf --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_hkdf::HKDFSHA256;
+use entlib_native_test_support::taint::{classify_secret, declassify};
+
+/// RFC 5869 TC3 — IKM: 0x0b×22 / Salt, Info 없음
+const TC3_OKM: [u8; 42] = [
+    0x8d, 0xa4, 0xe7, 0x75, 0xa5, 0x63, 0xc1, 0x8f, 0x71, 0x5f, 0x80, 0x2a, 0x06, 0x3c, 0x5a, 0x31,
+    0xb8, 0xa1, 0x1f, 0x5c, 0x5e, 0xe1, 0x87, 0x9e, 0xc3, 0x45, 0x4e, 0x5f, 0x3c, 0x73, 0x8d, 0x2d,
+    0x9d, 0x20, 0x13, 0x95, 0xfa, 0xa4, 0xb6, 0x1a, 0x96, 0xc8,
+];
+
+#[test]
+fn audit_taint_flow_hkdf_extract_expand() {
+    let ikm = [0x0bu8; 22];
+    classify_secret(&ikm);
+
+    let hkdf = HKDFSHA256::new();
+    let prk = hkdf.extract(None, &ikm).unwrap();
+    let mut okm = [0u8; 42];
+    hkdf.expand(&prk, &mut okm, &[], 42).unwrap();
+
+    declassify(&okm);
+    assert_eq!(okm, TC3_OKM);
+}
+
+#[test]
+fn audit_taint_flow_hkdf_derive_key_with_secret_salt() {
+    let ikm = [0x0bu8; 22];
+    let salt = [0x00u8; 32];
+    classify_secret(&ikm);
+    classify_secret(&salt);
+
+    // 길이가 HashLen인 0 Salt는 Salt 생략과 동일
+    let mut okm = [0u8; 42];
+    HKDFSHA256::new()
+        .derive_key(Some(&salt), &ikm, &mut okm, &[], 42)
+        .unwrap();
+
+    declassify(&okm);
+    assert_eq!(okm, TC3_OKM);
+}
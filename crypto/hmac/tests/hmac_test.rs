@@ -1,4 +1,8 @@
-use entlib_native_hmac::{HMACSHA256, HMACSHA512, HmacError};
+use entlib_native_digest::Digest;
+use entlib_native_hmac::{HMACSHA256, HMACSHA512, HMACSHA512_224, HMACSHA512_256, Hmac, HmacError};
+use entlib_native_secure_buffer::{SecretKey, SecureArena};
+use entlib_native_sha2::api::{SHA256, SHA512};
+use entlib_native_sha3::api::SHA3_256;
 
 //
 // 헬퍼
@@ -403,3 +407,104 @@ fn different_data_different_macs() {
     assert_ne!(hmac256(&key, &[b"msg_a"]), hmac256(&key, &[b"msg_b"]));
     assert_ne!(hmac512(&key, &[b"msg_a"]), hmac512(&key, &[b"msg_b"]));
 }
+
+/// 아레나 기반 인스턴스 → 일반 인스턴스와 동일한 MAC, 종료 후 슬롯 전부 반환
+#[test]
+fn arena_backed_macs_match() {
+    let arena = SecureArena::new(128, 16).unwrap();
+    let key = [0x0bu8; 20];
+    let data = b"arena backed hmac";
+
+    let mut h = HMACSHA256::new_in(&key, &arena).expect("HMACSHA256 초기화 실패");
+    h.update(data);
+    let mac = h.finalize().expect("HMACSHA256 finalize 실패");
+    assert_eq!(mac.as_slice(), hmac256(&key, &[data]).as_slice());
+    drop(mac);
+
+    let mut h = HMACSHA512::new_in(&key, &arena).expect("HMACSHA512 초기화 실패");
+    h.update(data);
+    let mac = h.finalize().expect("HMACSHA512 finalize 실패");
+    assert_eq!(mac.as_slice(), hmac512(&key, &[data]).as_slice());
+    drop(mac);
+
+    assert_eq!(arena.stats().used_slots, 0);
+}
+
+/// 타입 태그된 키로 초기화 → 슬라이스 키와 동일한 MAC, 최소 키 길이 검증 유지
+#[test]
+fn typed_key_constructor_matches_slice_key() {
+    let data = b"typed key";
+    let key = SecretKey::<HMACSHA256, 32>::from_slice(&[0x0bu8; 32]).unwrap();
+    let mut h = HMACSHA256::from_key(&key).expect("HMACSHA256 초기화 실패");
+    h.update(data);
+    let mac = h.finalize().expect("HMACSHA256 finalize 실패");
+    assert_eq!(mac.as_slice(), hmac256(&[0x0bu8; 32], &[data]).as_slice());
+
+    let weak = SecretKey::<HMACSHA512, 13>::from_slice(&[0x42u8; 13]).unwrap();
+    assert!(matches!(
+        HMACSHA512::from_key(&weak),
+        Err(HmacError::WeakKeyLength)
+    ));
+}
+
+/// 해시 함수에 대해 제네릭한 HMAC이 타입 별칭과 동일한 결과를 생성하는지 검증 (RFC 4231 TC2)
+#[test]
+fn generic_hmac_over_digest() {
+    fn mac<D: Digest>(key: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut h = Hmac::<D>::new(key).expect("HMAC 초기화 실패");
+        h.update(data);
+        let mac = h.finalize().expect("HMAC finalize 실패");
+        assert_eq!(mac.as_slice().len(), Hmac::<D>::MAC_LEN);
+        mac.as_slice().to_vec()
+    }
+
+    let key = b"Jefe-Jefe-Jefe";
+    let data = b"what do ya want for nothing?";
+    assert_eq!(mac::<SHA256>(key, data), hmac256(key, &[data]));
+    assert_eq!(mac::<SHA512>(key, data), hmac512(key, &[data]));
+    assert_eq!(mac::<SHA3_256>(key, data).len(), 32);
+}
+
+//
+// HMAC-SHA-512/224, HMAC-SHA-512/256 (RFC 4231 TC1 입력, 기대값은 OpenSSL로 생성)
+//
+
+/// TC1 — Key: 0x0b×20 / Data: "Hi There"
+#[test]
+fn sha512_t_tc1() {
+    let key = [0x0bu8; 20];
+
+    let mut h = HMACSHA512_224::new(&key).unwrap();
+    h.update(b"Hi There");
+    assert_eq!(
+        h.finalize().unwrap().as_slice(),
+        [
+            0xb2, 0x44, 0xba, 0x01, 0x30, 0x7c, 0x0e, 0x7a, 0x8c, 0xca, 0xad, 0x13, 0xb1, 0x06,
+            0x7a, 0x4c, 0xf6, 0xb9, 0x61, 0xfe, 0x0c, 0x6a, 0x20, 0xbd, 0xa3, 0xd9, 0x20, 0x39,
+        ]
+    );
+
+    let mut h = HMACSHA512_256::new(&key).unwrap();
+    h.update(b"Hi There");
+    assert_eq!(
+        h.finalize().unwrap().as_slice(),
+        [
+            0x9f, 0x91, 0x26, 0xc3, 0xd9, 0xc3, 0xc3, 0x30, 0xd7, 0x60, 0x42, 0x5c, 0xa8, 0xa2,
+            0x17, 0xe3, 0x1f, 0xea, 0xe3, 0x1b, 0xfe, 0x70, 0x19, 0x6f, 0xf8, 0x16, 0x42, 0xb8,
+            0x68, 0x40, 0x2e, 0xab,
+        ]
+    );
+}
+
+/// 키가 준비된 인스턴스의 복제본은 원본과 독립적으로 같은 키의 MAC을 생성
+#[test]
+fn cloned_keyed_instance_matches_fresh() {
+    let key = [0x0bu8; 20];
+    let keyed = HMACSHA256::new(&key).unwrap();
+
+    for data in [&b"Hi There"[..], b"another message"] {
+        let mut h = keyed.clone();
+        h.update(data);
+        assert_eq!(h.finalize().unwrap().as_slice(), hmac256(&key, &[data]));
+    }
+}
@@ -0,0 +1,68 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-hmac --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_hmac::{HMACSHA256, MacResult};
+use entlib_native_test_support::taint::{classify_secret, declassified, declassify};
+
+fn hmac256(key: &[u8], data: &[u8]) -> MacResult {
+    let mut hmac = HMACSHA256::new(key).unwrap();
+    hmac.update(data);
+    hmac.finalize().unwrap()
+}
+
+/// RFC 4231 TC1 — Key: 0x0b×20 / Data: "Hi There"
+#[test]
+fn audit_taint_flow_hmac_sha256() {
+    let key = [0x0bu8; 20];
+    let data = *b"Hi There";
+    classify_secret(&key);
+    classify_secret(&data);
+
+    let mac = hmac256(&key, &data);
+    declassify(mac.as_slice());
+    assert_eq!(
+        mac.as_slice(),
+        &[
+            0xb0, 0x34, 0x4c, 0x61, 0xd8, 0xdb, 0x38, 0x53, 0x5c, 0xa8, 0xaf, 0xce, 0xaf, 0x0b,
+            0xf1, 0x2b, 0x88, 0x1d, 0xc2, 0x00, 0xc9, 0x83, 0x3d, 0xa7, 0x26, 0xe9, 0x37, 0x6c,
+            0x2e, 0x32, 0xcf, 0xf7,
+        ]
+    );
+}
+
+/// RFC 4231 TC6 — 블록 크기보다 긴 비밀 키 (키 해시 경로)
+#[test]
+fn audit_taint_flow_hmac_sha256_long_key() {
+    let key = [0xaau8; 131];
+    classify_secret(&key);
+
+    let mac = hmac256(
+        &key,
+        b"Test Using Larger Than Block-Size Key - Hash Key First",
+    );
+    declassify(mac.as_slice());
+    assert_eq!(
+        mac.as_slice(),
+        &[
+            0x60, 0xe4, 0x31, 0x59, 0x1e, 0xe0, 0xb6, 0x7f, 0x0d, 0x8a, 0x26, 0xaa, 0xcb, 0xf5,
+            0xb7, 0x7f, 0x8e, 0x0b, 0xc6, 0x21, 0x37, 0x28, 0xc5, 0x14, 0x05, 0x46, 0x04, 0x0f,
+            0x0e, 0xe3, 0x7f, 0x54,
+        ]
+    );
+}
+
+/// 태그 검증 (`MacResult::eq`): 비교 결과만 공개
+#[test]
+fn audit_taint_flow_hmac_sha256_verify() {
+    let key = [0x42u8; 32];
+    classify_secret(&key);
+    let expected = hmac256(&key, b"message");
+
+    assert!(declassified(hmac256(&key, b"message") == expected));
+    assert!(!declassified(hmac256(&key, b"messagf") == expected));
+}
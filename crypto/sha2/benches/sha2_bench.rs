@@ -0,0 +1,42 @@
+//! SHA-2 하드웨어 가속/이식형 압축 함수 처리량 벤치마크입니다.
+//!
+//! ```bash
+//! $ cargo bench -p entlib-native-sha2 --bench sha2_bench
+//! ```
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use entlib_native_sha2::api::{SHA256, SHA512};
+use std::hint::black_box;
+
+macro_rules! bench_backends {
+    ($c:expr, $name:literal, $hasher:ty) => {{
+        let mut group = $c.benchmark_group(concat!("throughput.sha2.", $name));
+        for (label, size) in [("16KiB", 16 * 1024), ("1MiB", 1024 * 1024)] {
+            let input: Vec<u8> = (0..size).map(|i| (i as u8).wrapping_mul(167)).collect();
+            group.throughput(Throughput::Bytes(size as u64));
+            // 가속 경로는 CPU 기능을 지원하지 않으면 이식형 구현으로 대체됩니다.
+            group.bench_with_input(BenchmarkId::new("accelerated", label), &input, |b, input| {
+                b.iter(|| {
+                    let mut h = <$hasher>::new();
+                    h.update(black_box(input));
+                    h.finalize().unwrap()
+                })
+            });
+            group.bench_with_input(BenchmarkId::new("portable", label), &input, |b, input| {
+                b.iter(|| {
+                    let mut h = <$hasher>::new_portable();
+                    h.update(black_box(input));
+                    h.finalize().unwrap()
+                })
+            });
+        }
+        group.finish();
+    }};
+}
+
+fn bench_sha2(c: &mut Criterion) {
+    bench_backends!(c, "sha256", SHA256);
+    bench_backends!(c, "sha512", SHA512);
+}
+
+criterion_group!(benches, bench_sha2);
+criterion_main!(benches);
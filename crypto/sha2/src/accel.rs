@@ -0,0 +1,318 @@
+//! SHA-2 압축 함수의 하드웨어 가속 구현입니다.
+//!
+//! | 아키텍처 | SHA-256                           | SHA-512                     |
+//! |----------|-----------------------------------|-----------------------------|
+//! | x86_64   | SHA-NI (`sha`, `sse4.1`, `ssse3`) | 없음 (이식형 구현 사용)     |
+//! | aarch64  | ARMv8 Crypto Extensions (`sha2`)  | ARMv8.2 SHA-512 (`sha3`)    |
+//!
+//! CPU 기능은 상태 생성 시점에 런타임으로 감지되며, 결과는 상태 구조체에 공개 정보로 보관됩니다.
+//! 전용 명령어는 데이터 의존 분기나 메모리 룩업 없이 고정된 지연 시간으로 동작하므로
+//! 이식형 구현과 동일한 상수-시간 특성을 유지합니다. `force_scalar` 기능을 활성화하면
+//! 가속 경로가 제외되고 항상 이식형 구현이 사용됩니다.
+//!
+//! 메시지 스케줄은 벡터 레지스터에만 존재하므로 이식형 구현과 달리 별도의 스택 소거가
+//! 필요하지 않습니다.
+
+//
+// SHA-256 - start
+//
+
+/// 현재 CPU에서 SHA-256 가속 압축 함수를 사용할 수 있는지 반환합니다.
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+pub(crate) fn sha256_available() -> bool {
+    std::is_x86_feature_detected!("sha")
+        && std::is_x86_feature_detected!("sse2")
+        && std::is_x86_feature_detected!("ssse3")
+        && std::is_x86_feature_detected!("sse4.1")
+}
+
+/// 현재 CPU에서 SHA-256 가속 압축 함수를 사용할 수 있는지 반환합니다.
+#[cfg(all(target_arch = "aarch64", not(feature = "force_scalar")))]
+pub(crate) fn sha256_available() -> bool {
+    std::arch::is_aarch64_feature_detected!("sha2")
+}
+
+/// 가속 경로가 없는 환경(또는 `force_scalar` 기능)에서는 항상 `false`입니다.
+#[cfg(not(all(
+    any(target_arch = "x86_64", target_arch = "aarch64"),
+    not(feature = "force_scalar")
+)))]
+pub(crate) fn sha256_available() -> bool {
+    false
+}
+
+/// 64바이트 배수 길이의 `blocks`를 순서대로 압축합니다.
+///
+/// 호출자는 [`sha256_available`]이 `true`인 경우에만 호출해야 합니다.
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+pub(crate) fn compress256(state: &mut [u32; 8], blocks: &[u8]) {
+    debug_assert!(blocks.len().is_multiple_of(64) && sha256_available());
+    // SAFETY: 상태 생성 시 SHA-NI 및 SSE4.1 지원 여부를 런타임에 확인하였습니다.
+    unsafe { x86::compress256(state, blocks) }
+}
+
+/// 64바이트 배수 길이의 `blocks`를 순서대로 압축합니다.
+///
+/// 호출자는 [`sha256_available`]이 `true`인 경우에만 호출해야 합니다.
+#[cfg(all(target_arch = "aarch64", not(feature = "force_scalar")))]
+pub(crate) fn compress256(state: &mut [u32; 8], blocks: &[u8]) {
+    debug_assert!(blocks.len().is_multiple_of(64) && sha256_available());
+    // SAFETY: 상태 생성 시 SHA-2 확장 지원 여부를 런타임에 확인하였습니다.
+    unsafe { aarch64::compress256(state, blocks) }
+}
+
+#[cfg(not(all(
+    any(target_arch = "x86_64", target_arch = "aarch64"),
+    not(feature = "force_scalar")
+)))]
+pub(crate) fn compress256(_state: &mut [u32; 8], _blocks: &[u8]) {
+    unreachable!("SHA-256 hardware acceleration is not available")
+}
+//
+// SHA-256 - end
+//
+
+//
+// SHA-512 - start
+//
+
+/// 현재 CPU에서 SHA-512 가속 압축 함수를 사용할 수 있는지 반환합니다.
+#[cfg(all(target_arch = "aarch64", not(feature = "force_scalar")))]
+pub(crate) fn sha512_available() -> bool {
+    std::arch::is_aarch64_feature_detected!("sha3")
+}
+
+/// 가속 경로가 없는 환경(또는 `force_scalar` 기능)에서는 항상 `false`입니다.
+#[cfg(not(all(target_arch = "aarch64", not(feature = "force_scalar"))))]
+pub(crate) fn sha512_available() -> bool {
+    false
+}
+
+/// 128바이트 배수 길이의 `blocks`를 순서대로 압축합니다.
+///
+/// 호출자는 [`sha512_available`]이 `true`인 경우에만 호출해야 합니다.
+#[cfg(all(target_arch = "aarch64", not(feature = "force_scalar")))]
+pub(crate) fn compress512(state: &mut [u64; 8], blocks: &[u8]) {
+    debug_assert!(blocks.len().is_multiple_of(128) && sha512_available());
+    // SAFETY: 상태 생성 시 SHA-512 확장 지원 여부를 런타임에 확인하였습니다.
+    unsafe { aarch64::compress512(state, blocks) }
+}
+
+#[cfg(not(all(target_arch = "aarch64", not(feature = "force_scalar"))))]
+pub(crate) fn compress512(_state: &mut [u64; 8], _blocks: &[u8]) {
+    unreachable!("SHA-512 hardware acceleration is not available")
+}
+//
+// SHA-512 - end
+//
+
+#[cfg(all(target_arch = "x86_64", not(feature = "force_scalar")))]
+mod x86 {
+    use crate::sha2_256::SHA_256_K;
+    use core::arch::x86_64::*;
+
+    /// W[t-16..t] 네 벡터로부터 다음 네 개의 메시지 워드 W[t..t+4]를 계산합니다.
+    #[inline]
+    #[target_feature(enable = "sha,sse2,ssse3,sse4.1")]
+    fn schedule(w0: __m128i, w1: __m128i, w2: __m128i, w3: __m128i) -> __m128i {
+        let t = _mm_sha256msg1_epu32(w0, w1);
+        let t = _mm_add_epi32(t, _mm_alignr_epi8::<4>(w3, w2));
+        _mm_sha256msg2_epu32(t, w3)
+    }
+
+    /// SHA-NI 명령어를 사용하는 SHA-256 압축 함수
+    ///
+    /// `sha256rnds2`는 상태를 (ABEF, CDGH) 레지스터 쌍으로 요구하므로 블록 처리 전후에
+    /// 표준 (ABCD, EFGH) 배치와 상호 변환합니다.
+    #[target_feature(enable = "sha,sse2,ssse3,sse4.1")]
+    pub(super) fn compress256(state: &mut [u32; 8], blocks: &[u8]) {
+        // 32비트 워드 단위 빅엔디안 → 리틀엔디안 바이트 재배치
+        let be_mask = _mm_set_epi64x(0x0C0D_0E0F_0809_0A0B, 0x0405_0607_0001_0203);
+
+        // SAFETY: state는 32바이트이며 비정렬 적재를 사용합니다.
+        let (dcba, hgfe) = unsafe {
+            let ptr = state.as_ptr().cast::<__m128i>();
+            (_mm_loadu_si128(ptr), _mm_loadu_si128(ptr.add(1)))
+        };
+        let cdab = _mm_shuffle_epi32::<0xB1>(dcba);
+        let efgh = _mm_shuffle_epi32::<0x1B>(hgfe);
+        let mut abef = _mm_alignr_epi8::<8>(cdab, efgh);
+        let mut cdgh = _mm_blend_epi16::<0xF0>(efgh, cdab);
+
+        for block in blocks.chunks_exact(64) {
+            let abef_save = abef;
+            let cdgh_save = cdgh;
+
+            // SAFETY: block은 정확히 64바이트입니다.
+            let mut w = unsafe {
+                let ptr = block.as_ptr().cast::<__m128i>();
+                [
+                    _mm_shuffle_epi8(_mm_loadu_si128(ptr), be_mask),
+                    _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(1)), be_mask),
+                    _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(2)), be_mask),
+                    _mm_shuffle_epi8(_mm_loadu_si128(ptr.add(3)), be_mask),
+                ]
+            };
+
+            for i in 0..16 {
+                if i >= 4 {
+                    w[i % 4] = schedule(w[i % 4], w[(i + 1) % 4], w[(i + 2) % 4], w[(i + 3) % 4]);
+                }
+                // SAFETY: i * 4 + 4 <= 64
+                let k = unsafe { _mm_loadu_si128(SHA_256_K.as_ptr().add(i * 4).cast()) };
+                let wk = _mm_add_epi32(w[i % 4], k);
+                cdgh = _mm_sha256rnds2_epu32(cdgh, abef, wk);
+                abef = _mm_sha256rnds2_epu32(abef, cdgh, _mm_shuffle_epi32::<0x0E>(wk));
+            }
+
+            abef = _mm_add_epi32(abef, abef_save);
+            cdgh = _mm_add_epi32(cdgh, cdgh_save);
+        }
+
+        let feba = _mm_shuffle_epi32::<0x1B>(abef);
+        let dchg = _mm_shuffle_epi32::<0xB1>(cdgh);
+        let dcba = _mm_blend_epi16::<0xF0>(feba, dchg);
+        let hgef = _mm_alignr_epi8::<8>(dchg, feba);
+
+        // SAFETY: state는 32바이트이며 비정렬 저장을 사용합니다.
+        unsafe {
+            let ptr = state.as_mut_ptr().cast::<__m128i>();
+            _mm_storeu_si128(ptr, dcba);
+            _mm_storeu_si128(ptr.add(1), hgef);
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", not(feature = "force_scalar")))]
+mod aarch64 {
+    use crate::sha2_256::SHA_256_K;
+    use crate::sha2_512::SHA_512_K;
+    use core::arch::aarch64::*;
+
+    /// ARMv8 SHA-2 확장 명령어를 사용하는 SHA-256 압축 함수
+    #[target_feature(enable = "sha2")]
+    pub(super) fn compress256(state: &mut [u32; 8], blocks: &[u8]) {
+        // SAFETY: state는 8개의 u32 워드입니다.
+        let (mut abcd, mut efgh) =
+            unsafe { (vld1q_u32(state.as_ptr()), vld1q_u32(state.as_ptr().add(4))) };
+
+        for block in blocks.chunks_exact(64) {
+            let abcd_save = abcd;
+            let efgh_save = efgh;
+
+            // SAFETY: block은 정확히 64바이트입니다.
+            let mut w = unsafe {
+                let ptr = block.as_ptr();
+                [
+                    vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(ptr))),
+                    vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(ptr.add(16)))),
+                    vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(ptr.add(32)))),
+                    vreinterpretq_u32_u8(vrev32q_u8(vld1q_u8(ptr.add(48)))),
+                ]
+            };
+
+            for i in 0..16 {
+                if i >= 4 {
+                    w[i % 4] = vsha256su1q_u32(
+                        vsha256su0q_u32(w[i % 4], w[(i + 1) % 4]),
+                        w[(i + 2) % 4],
+                        w[(i + 3) % 4],
+                    );
+                }
+                // SAFETY: i * 4 + 4 <= 64
+                let k = unsafe { vld1q_u32(SHA_256_K.as_ptr().add(i * 4)) };
+                let wk = vaddq_u32(w[i % 4], k);
+                let abcd_prev = abcd;
+                abcd = vsha256hq_u32(abcd_prev, efgh, wk);
+                efgh = vsha256h2q_u32(efgh, abcd_prev, wk);
+            }
+
+            abcd = vaddq_u32(abcd, abcd_save);
+            efgh = vaddq_u32(efgh, efgh_save);
+        }
+
+        // SAFETY: state는 8개의 u32 워드입니다.
+        unsafe {
+            vst1q_u32(state.as_mut_ptr(), abcd);
+            vst1q_u32(state.as_mut_ptr().add(4), efgh);
+        }
+    }
+
+    /// ARMv8.2 SHA-512 확장 명령어를 사용하는 SHA-512 압축 함수
+    ///
+    /// 상태는 (AB, CD, EF, GH) 네 레지스터에 보관되며, 두 라운드마다 각 레지스터의 역할이
+    /// 한 칸씩 회전합니다. 40회의 2-라운드 단계 후에는 원래 배치로 돌아옵니다.
+    #[target_feature(enable = "sha3")]
+    pub(super) fn compress512(state: &mut [u64; 8], blocks: &[u8]) {
+        // SAFETY: state는 8개의 u64 워드입니다.
+        let mut v = unsafe {
+            let ptr = state.as_ptr();
+            [
+                vld1q_u64(ptr),
+                vld1q_u64(ptr.add(2)),
+                vld1q_u64(ptr.add(4)),
+                vld1q_u64(ptr.add(6)),
+            ]
+        };
+
+        for block in blocks.chunks_exact(128) {
+            let save = v;
+
+            // SAFETY: block은 정확히 128바이트입니다.
+            let mut w = unsafe {
+                let ptr = block.as_ptr();
+                let load =
+                    |offset: usize| vreinterpretq_u64_u8(vrev64q_u8(vld1q_u8(ptr.add(offset))));
+                [
+                    load(0),
+                    load(16),
+                    load(32),
+                    load(48),
+                    load(64),
+                    load(80),
+                    load(96),
+                    load(112),
+                ]
+            };
+
+            for t in 0..40 {
+                if t >= 8 {
+                    w[t % 8] = vsha512su1q_u64(
+                        vsha512su0q_u64(w[t % 8], w[(t + 1) % 8]),
+                        w[(t + 7) % 8],
+                        vextq_u64::<1>(w[(t + 4) % 8], w[(t + 5) % 8]),
+                    );
+                }
+
+                // 이번 단계에서 (AB, CD, EF, GH) 역할을 맡는 레지스터 인덱스
+                let r = t % 4;
+                let (ab, cd, ef, gh) = ((4 - r) % 4, (5 - r) % 4, (6 - r) % 4, (7 - r) % 4);
+
+                // SAFETY: t * 2 + 2 <= 80
+                let k = unsafe { vld1q_u64(SHA_512_K.as_ptr().add(t * 2)) };
+                let wk = vaddq_u64(w[t % 8], k);
+                let sum = vaddq_u64(vextq_u64::<1>(wk, wk), v[gh]);
+                let intermed = vsha512hq_u64(
+                    sum,
+                    vextq_u64::<1>(v[ef], v[gh]),
+                    vextq_u64::<1>(v[cd], v[ef]),
+                );
+                v[gh] = vsha512h2q_u64(intermed, v[cd], v[ab]);
+                v[cd] = vaddq_u64(v[cd], intermed);
+            }
+
+            for (reg, saved) in v.iter_mut().zip(save) {
+                *reg = vaddq_u64(*reg, saved);
+            }
+        }
+
+        // SAFETY: state는 8개의 u64 워드입니다.
+        unsafe {
+            let ptr = state.as_mut_ptr();
+            vst1q_u64(ptr, v[0]);
+            vst1q_u64(ptr.add(2), v[1]);
+            vst1q_u64(ptr.add(4), v[2]);
+            vst1q_u64(ptr.add(6), v[3]);
+        }
+    }
+}
@@ -1,9 +1,12 @@
-use crate::{Sha256State, Sha512State};
-use entlib_native_secure_buffer::SecureBuffer;
+use crate::{Sha256State, Sha512State, Sha512Variant};
+use entlib_native_digest::midstate::Algorithm;
+use entlib_native_digest::{Digest, Midstate, Reset};
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer};
 
 //
 // SHA224 - start
 //
+#[derive(Clone)]
 pub struct SHA224(Sha256State);
 impl SHA224 {
     // 인스턴스 초기화
@@ -11,6 +14,24 @@ impl SHA224 {
         Self(Sha256State::new(true))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha256State::new_in(true, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha256State::new_in(true, Some(arena))?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha256State::new(true);
+        state.accelerated = false;
+        Self(state)
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -20,6 +41,16 @@ impl SHA224 {
     pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
         self.0.finalize()
     }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
 }
 
 impl Default for SHA224 {
@@ -27,6 +58,63 @@ impl Default for SHA224 {
         Self::new()
     }
 }
+
+impl Digest for SHA224 {
+    const OUTPUT_LEN: usize = 28;
+    const BLOCK_LEN: usize = 64;
+
+    fn new() -> Self {
+        SHA224::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA224::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA224::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA224::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA224::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA224::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA224 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA224 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha224, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha256State::import_midstate(true, Algorithm::Sha224, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha256State::import_midstate(true, Algorithm::Sha224, blob, key, Some(arena)).map(Self)
+    }
+}
 //
 // SHA224 - end
 //
@@ -34,6 +122,7 @@ impl Default for SHA224 {
 //
 // SHA256 - start
 //
+#[derive(Clone)]
 pub struct SHA256(Sha256State);
 impl SHA256 {
     // 인스턴스 초기화
@@ -41,6 +130,24 @@ impl SHA256 {
         Self(Sha256State::new(false))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha256State::new_in(false, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha256State::new_in(false, Some(arena))?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha256State::new(false);
+        state.accelerated = false;
+        Self(state)
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -50,6 +157,16 @@ impl SHA256 {
     pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
         self.0.finalize()
     }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
 }
 
 impl Default for SHA256 {
@@ -57,6 +174,63 @@ impl Default for SHA256 {
         Self::new()
     }
 }
+
+impl Digest for SHA256 {
+    const OUTPUT_LEN: usize = 32;
+    const BLOCK_LEN: usize = 64;
+
+    fn new() -> Self {
+        SHA256::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA256::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA256::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA256::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA256::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA256::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA256 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA256 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha256, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha256State::import_midstate(false, Algorithm::Sha256, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha256State::import_midstate(false, Algorithm::Sha256, blob, key, Some(arena)).map(Self)
+    }
+}
 //
 // SHA256 - end
 //
@@ -64,11 +238,33 @@ impl Default for SHA256 {
 //
 // SHA384 - start
 //
+#[derive(Clone)]
 pub struct SHA384(Sha512State);
 impl SHA384 {
     // 인스턴스 초기화
     pub fn new() -> Self {
-        Self(Sha512State::new(true))
+        Self(Sha512State::new(Sha512Variant::Sha384))
+    }
+
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(Sha512Variant::Sha384, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(
+            Sha512Variant::Sha384,
+            Some(arena),
+        )?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha512State::new(Sha512Variant::Sha384);
+        state.accelerated = false;
+        Self(state)
     }
 
     // 해시 대상 데이터 주입
@@ -80,6 +276,16 @@ impl SHA384 {
     pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
         self.0.finalize()
     }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
 }
 
 impl Default for SHA384 {
@@ -87,6 +293,71 @@ impl Default for SHA384 {
         Self::new()
     }
 }
+
+impl Digest for SHA384 {
+    const OUTPUT_LEN: usize = 48;
+    const BLOCK_LEN: usize = 128;
+
+    fn new() -> Self {
+        SHA384::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA384::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA384::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA384::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA384::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA384::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA384 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA384 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha384, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(Sha512Variant::Sha384, Algorithm::Sha384, blob, key, None)
+            .map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha384,
+            Algorithm::Sha384,
+            blob,
+            key,
+            Some(arena),
+        )
+        .map(Self)
+    }
+}
 //
 // SHA384 - end
 //
@@ -94,11 +365,33 @@ impl Default for SHA384 {
 //
 // SHA512 - start
 //
+#[derive(Clone)]
 pub struct SHA512(Sha512State);
 impl SHA512 {
     // 인스턴스 초기화
     pub fn new() -> Self {
-        Self(Sha512State::new(false))
+        Self(Sha512State::new(Sha512Variant::Sha512))
+    }
+
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(Sha512Variant::Sha512, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(
+            Sha512Variant::Sha512,
+            Some(arena),
+        )?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha512State::new(Sha512Variant::Sha512);
+        state.accelerated = false;
+        Self(state)
     }
 
     // 해시 대상 데이터 주입
@@ -110,6 +403,16 @@ impl SHA512 {
     pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
         self.0.finalize()
     }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
 }
 
 impl Default for SHA512 {
@@ -117,6 +420,337 @@ impl Default for SHA512 {
         Self::new()
     }
 }
+
+impl Digest for SHA512 {
+    const OUTPUT_LEN: usize = 64;
+    const BLOCK_LEN: usize = 128;
+
+    fn new() -> Self {
+        SHA512::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA512::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA512::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA512::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA512::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA512::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA512 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA512 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha512, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(Sha512Variant::Sha512, Algorithm::Sha512, blob, key, None)
+            .map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha512,
+            Algorithm::Sha512,
+            blob,
+            key,
+            Some(arena),
+        )
+        .map(Self)
+    }
+}
 //
 // SHA512 - end
 //
+
+//
+// SHA512/224 - start
+//
+#[derive(Clone)]
+pub struct SHA512_224(Sha512State);
+impl SHA512_224 {
+    // 인스턴스 초기화
+    pub fn new() -> Self {
+        Self(Sha512State::new(Sha512Variant::Sha512_224))
+    }
+
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(Sha512Variant::Sha512_224, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(
+            Sha512Variant::Sha512_224,
+            Some(arena),
+        )?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha512State::new(Sha512Variant::Sha512_224);
+        state.accelerated = false;
+        Self(state)
+    }
+
+    // 해시 대상 데이터 주입
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    // 해시 연산 완료 및 다이제스트 반환
+    pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize()
+    }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
+}
+
+impl Default for SHA512_224 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Digest for SHA512_224 {
+    const OUTPUT_LEN: usize = 28;
+    const BLOCK_LEN: usize = 128;
+
+    fn new() -> Self {
+        SHA512_224::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA512_224::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA512_224::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA512_224::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA512_224::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA512_224::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA512_224 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA512_224 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha512_224, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha512_224,
+            Algorithm::Sha512_224,
+            blob,
+            key,
+            None,
+        )
+        .map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha512_224,
+            Algorithm::Sha512_224,
+            blob,
+            key,
+            Some(arena),
+        )
+        .map(Self)
+    }
+}
+//
+// SHA512/224 - end
+//
+
+//
+// SHA512/256 - start
+//
+#[derive(Clone)]
+pub struct SHA512_256(Sha512State);
+impl SHA512_256 {
+    // 인스턴스 초기화
+    pub fn new() -> Self {
+        Self(Sha512State::new(Sha512Variant::Sha512_256))
+    }
+
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(Sha512Variant::Sha512_256, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(Sha512State::new_in(
+            Sha512Variant::Sha512_256,
+            Some(arena),
+        )?))
+    }
+
+    // 가속 경로 교차 검증용: 하드웨어 지원 여부와 무관하게 이식형 압축 함수만 사용하는 인스턴스 초기화
+    #[doc(hidden)]
+    pub fn new_portable() -> Self {
+        let mut state = Sha512State::new(Sha512Variant::Sha512_256);
+        state.accelerated = false;
+        Self(state)
+    }
+
+    // 해시 대상 데이터 주입
+    pub fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    // 해시 연산 완료 및 다이제스트 반환
+    pub fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize()
+    }
+
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_into(out)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset()
+    }
+}
+
+impl Default for SHA512_256 {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Digest for SHA512_256 {
+    const OUTPUT_LEN: usize = 32;
+    const BLOCK_LEN: usize = 128;
+
+    fn new() -> Self {
+        SHA512_256::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA512_256::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA512_256::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA512_256::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA512_256::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA512_256::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA512_256 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA512_256 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha512_256, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha512_256,
+            Algorithm::Sha512_256,
+            blob,
+            key,
+            None,
+        )
+        .map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        Sha512State::import_midstate(
+            Sha512Variant::Sha512_256,
+            Algorithm::Sha512_256,
+            blob,
+            key,
+            Some(arena),
+        )
+        .map(Self)
+    }
+}
+//
+// SHA512/256 - end
+//
@@ -1,50 +1,65 @@
+mod accel;
 pub mod api;
 mod sha2_256;
 mod sha2_512;
 
-use core::ptr::write_volatile;
-use core::sync::atomic::{Ordering, compiler_fence};
-use entlib_native_secure_buffer::SecureBuffer;
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer, SecureZeroize, ZeroizeOnDrop};
 
 /// SHA-256 및 SHA-224를 위한 32비트 내부 상태 구조체(internal state structure)
+///
+/// `Drop` 시점에 체인 값, 블록 버퍼 및 길이 카운터가 소거됩니다.
+#[derive(SecureZeroize, ZeroizeOnDrop)]
 pub(crate) struct Sha256State {
     pub(crate) state: [u32; 8],
     pub(crate) buffer: SecureBuffer,
     pub(crate) buffer_len: usize,
     pub(crate) total_len: u64,
+    #[zeroize(skip)]
     pub(crate) is_224: bool,
+    #[zeroize(skip)]
+    pub(crate) arena: Option<SecureArena>,
+    /// 하드웨어 가속 압축 함수 사용 여부 (CPU 기능 감지 결과, 공개 정보)
+    #[zeroize(skip)]
+    pub(crate) accelerated: bool,
 }
 
-/// SHA-512 및 SHA-384를 위한 64비트 내부 상태 구조체(internal state structure)
+/// SHA-512 계열 변형 (FIPS 180-4 5.3.4 ~ 5.3.6)
+///
+/// 변형마다 초기 해시 값과 다이제스트 절단 길이만 다르며, 압축 함수와 패딩은 동일합니다.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Sha512Variant {
+    Sha384,
+    Sha512,
+    Sha512_224,
+    Sha512_256,
+}
+
+impl Sha512Variant {
+    /// 다이제스트 크기 (바이트)
+    pub(crate) const fn digest_len(self) -> usize {
+        match self {
+            Sha512Variant::Sha384 => 48,
+            Sha512Variant::Sha512 => 64,
+            Sha512Variant::Sha512_224 => 28,
+            Sha512Variant::Sha512_256 => 32,
+        }
+    }
+}
+
+/// SHA-512, SHA-384 및 SHA-512/t를 위한 64비트 내부 상태 구조체(internal state structure)
+///
+/// `Drop` 시점에 체인 값, 블록 버퍼 및 길이 카운터가 소거됩니다.
+#[derive(SecureZeroize, ZeroizeOnDrop)]
 pub(crate) struct Sha512State {
     pub(crate) state: [u64; 8],
     pub(crate) buffer: SecureBuffer,
     pub(crate) buffer_len: usize,
     pub(crate) total_len: u128,
-    pub(crate) is_384: bool,
+    #[zeroize(skip)]
+    pub(crate) variant: Sha512Variant,
+    #[zeroize(skip)]
+    pub(crate) arena: Option<SecureArena>,
+    /// 하드웨어 가속 압축 함수 사용 여부 (CPU 기능 감지 결과, 공개 정보)
+    #[zeroize(skip)]
+    pub(crate) accelerated: bool,
 }
-
-macro_rules! impl_zeroize_drop {
-    ($type:ty, $state_type:ty, $state_len:expr, $buf_len:expr) => {
-        impl Drop for $type {
-            fn drop(&mut self) {
-                // 내부 상태 배열 및 버퍼 소거(zeroization of internal state and buffer)
-                for i in 0..$state_len {
-                    unsafe {
-                        write_volatile(&mut self.state[i], 0);
-                    }
-                }
-                // Q. T. Felix NOTE: 이 Drop 트레이트가 호출되는 시점에 SecureBuffer 구조체의 Drop 트레이트도 호출되지 않나?
-                //                   만약 그렇지 않다면, Drop 트레이트를 이 곳에서 수동으로 직접 호출해주어야 함.
-                unsafe {
-                    write_volatile(&mut self.buffer_len, 0);
-                    write_volatile(&mut self.total_len, 0);
-                }
-                compiler_fence(Ordering::SeqCst);
-            }
-        }
-    };
-}
-
-impl_zeroize_drop!(Sha256State, u32, 8, 64);
-impl_zeroize_drop!(Sha512State, u64, 8, 128);
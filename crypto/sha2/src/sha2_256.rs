@@ -1,10 +1,11 @@
 use crate::Sha256State;
-use core::ptr::write_volatile;
-use core::sync::atomic::{Ordering, compiler_fence};
-use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeSelect};
-use entlib_native_secure_buffer::SecureBuffer;
+use crate::accel;
+use crate::api::SHA256;
+use entlib_native_constant_time::traits::{ConstantTimeOrd, ConstantTimeSelect};
+use entlib_native_digest::midstate::{self, Algorithm};
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer, SecureZeroize};
 
-const SHA_256_K: [u32; 64] = [
+pub(crate) const SHA_256_K: [u32; 64] = [
     0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
     0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
     0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
@@ -17,7 +18,25 @@ const SHA_256_K: [u32; 64] = [
 
 impl Sha256State {
     pub(crate) fn new(is_224: bool) -> Self {
-        let state = if is_224 {
+        Self::new_in(is_224, None).expect("SecureBuffer allocate failed")
+    }
+
+    /// 내부 버퍼와 다이제스트를 `arena`의 슬롯에서 할당하는 상태를 생성합니다.
+    pub(crate) fn new_in(is_224: bool, arena: Option<&SecureArena>) -> Result<Self, &'static str> {
+        Ok(Self {
+            state: Self::initial_state(is_224),
+            buffer: SecureBuffer::new_in(64, arena)?,
+            buffer_len: 0,
+            total_len: 0,
+            is_224,
+            arena: arena.cloned(),
+            accelerated: accel::sha256_available(),
+        })
+    }
+
+    /// 초기 해시 값(initial hash value, FIPS 180-4 5.3.2, 5.3.3)
+    fn initial_state(is_224: bool) -> [u32; 8] {
+        if is_224 {
             [
                 0xc1059ed8, 0x367cd507, 0x3070dd17, 0xf70e5939, 0xffc00b31, 0x68581511, 0x64f98fa7,
                 0xbefa4fa4,
@@ -27,18 +46,38 @@ impl Sha256State {
                 0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
                 0x5be0cd19,
             ]
-        };
-        Self {
-            state,
-            buffer: SecureBuffer::new_owned(64).expect("SecureBuffer allocate failed"),
-            buffer_len: 0,
-            total_len: 0,
-            is_224,
         }
     }
 
+    /// 주입된 데이터와 체인 값을 소거하고 초기 상태로 되돌립니다. 버퍼 할당은 유지됩니다.
+    pub(crate) fn reset(&mut self) {
+        self.state.secure_zeroize();
+        self.state = Self::initial_state(self.is_224);
+        self.buffer.secure_zeroize();
+        self.buffer_len = 0;
+        self.total_len = 0;
+    }
+
     /// 64바이트 데이터 블록을 처리하는 압축 함수(compression function)
     fn process_block(&mut self, block: &[u8; 64]) {
+        self.compress_blocks(block);
+    }
+
+    /// 64바이트 배수 길이의 블록열을 압축합니다.
+    ///
+    /// 가속 경로 선택 여부는 CPU 기능에서 유래한 공개 정보이므로 분기를 허용합니다.
+    fn compress_blocks(&mut self, blocks: &[u8]) {
+        if self.accelerated {
+            accel::compress256(&mut self.state, blocks);
+        } else {
+            for block in blocks.chunks_exact(64) {
+                self.process_block_portable(block.try_into().unwrap());
+            }
+        }
+    }
+
+    /// 이식형(portable) 압축 함수
+    fn process_block_portable(&mut self, block: &[u8; 64]) {
         let mut w = [0u32; 64];
 
         // 메시지 스케줄(message schedule) 구성
@@ -101,12 +140,7 @@ impl Sha256State {
         self.state[7] = self.state[7].wrapping_add(h);
 
         // 연산 후 메시지 스케줄 w의 메모리 안전한 소거(zeroization)
-        for item in w.iter_mut() {
-            unsafe {
-                write_volatile(item, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
+        w.secure_zeroize();
     }
 
     pub(crate) fn update(&mut self, data: &[u8]) {
@@ -114,6 +148,14 @@ impl Sha256State {
         let mut i = 0;
 
         while i < data.len() {
+            // 버퍼가 비어 있으면 완전한 블록들을 입력에서 직접 압축 (길이는 공개 정보)
+            let bulk = (data.len() - i) / 64 * 64;
+            if self.buffer_len == 0 && bulk > 0 {
+                self.compress_blocks(&data[i..i + bulk]);
+                i += bulk;
+                continue;
+            }
+
             let fill = 64 - self.buffer_len;
 
             let remain = data.len() - i;
@@ -136,7 +178,21 @@ impl Sha256State {
         }
     }
 
-    pub(crate) fn finalize(mut self) -> Result<SecureBuffer, &'static str> {
+    /// 다이제스트 크기 (바이트)
+    fn digest_len(&self) -> usize {
+        if self.is_224 { 28 } else { 32 }
+    }
+
+    /// 패딩과 마지막 압축을 수행하여 다이제스트를 `out`에 기록하고 초기 상태로 되돌립니다.
+    ///
+    /// 출력 버퍼를 호출자가 제공하므로 메모리 할당이 발생하지 않습니다.
+    pub(crate) fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        // 출력 길이는 알고리즘에 따른 공개 정보
+        let digest_size = self.digest_len();
+        if out.len() != digest_size {
+            return Err("Output buffer length mismatch");
+        }
+
         let len = self.buffer_len;
         self.buffer.as_mut_slice()[len] = 0x80;
         self.buffer_len += 1;
@@ -175,25 +231,10 @@ impl Sha256State {
         }
 
         // 스택에 할당된 임시 데이터 소거
-        for b in &mut block1 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        for b in &mut block2 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
+        block1.secure_zeroize();
+        block2.secure_zeroize();
 
-        // 다이제스트 생성을 위한 SecureBuffer 할당
-        let digest_size = if self.is_224 { 28 } else { 32 };
-        // 실패 시 &'static str 에러를 반환(?)하여 안전하게 에러 핸들링
-        let mut digest_buf = SecureBuffer::new_owned(digest_size)?;
-
-        // 가변 슬라이스를 통해 상태(state) 배열의 값을 다이제스트 버퍼로 전송
-        let digest_slice = digest_buf.as_mut_slice();
+        // 상태(state) 배열의 값을 출력 버퍼로 전송
         for (i, &s) in self.state.iter().enumerate() {
             let start = i * 4;
             // SHA-224 절단 시점을 넘어선 데이터는 복사하지 않음
@@ -209,12 +250,106 @@ impl Sha256State {
             let end = usize::ct_select(&digest_size, &max_end, is_end_ge);
             let copy_len = end - start;
 
-            digest_slice[start..end].copy_from_slice(&bytes[..copy_len]);
+            out[start..end].copy_from_slice(&bytes[..copy_len]);
         }
 
+        // 최종 체인 값과 버퍼를 소거하고 다음 메시지를 위해 초기 상태로 복귀
+        self.reset();
+        Ok(())
+    }
+
+    /// 다이제스트를 새로 할당한 [`SecureBuffer`]로 반환하고 초기 상태로 되돌립니다.
+    ///
+    /// 할당에 실패하면 상태는 변경되지 않습니다.
+    pub(crate) fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        let mut digest_buf = SecureBuffer::new_in(self.digest_len(), self.arena.as_ref())?;
+        self.finalize_into(digest_buf.as_mut_slice())?;
+        Ok(digest_buf)
+    }
+
+    pub(crate) fn finalize(mut self) -> Result<SecureBuffer, &'static str> {
         // 함수 종료 시 `mut self`가 범위를 벗어나 다음 수행
         // 1. self.buffer (SecureBuffer)의 Drop 발동 -> 소거 및 OS 메모리 잠금 해제
         // 2. self.state 등 내부 데이터 파기
-        Ok(digest_buf)
+        self.finalize_reset()
+    }
+}
+
+/// 중간 상태 본문 길이: 체인 값(32) + 비트 길이(8) + 버퍼 길이(1) + 버퍼(64)
+const MIDSTATE_BODY_LEN: usize = 105;
+
+impl Sha256State {
+    /// 현재 상태를 HMAC-SHA-256 태그로 인증하여 직렬화합니다.
+    pub(crate) fn export_midstate(
+        &self,
+        algorithm: Algorithm,
+        key: &[u8],
+    ) -> Result<SecureBuffer, &'static str> {
+        midstate::seal::<SHA256>(
+            algorithm,
+            key,
+            MIDSTATE_BODY_LEN,
+            self.arena.as_ref(),
+            |body| {
+                for (chunk, word) in body[..32].chunks_exact_mut(4).zip(&self.state) {
+                    chunk.copy_from_slice(&word.to_be_bytes());
+                }
+                body[32..40].copy_from_slice(&self.total_len.to_be_bytes());
+                body[40] = self.buffer_len as u8;
+                // 버퍼 길이 이후의 이전 블록 잔여 바이트는 내보내지 않음
+                body[41..41 + self.buffer_len]
+                    .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+                body[41 + self.buffer_len..].fill(0);
+            },
+        )
+    }
+
+    /// 직렬화된 상태를 검증하고 복원합니다.
+    pub(crate) fn import_midstate(
+        is_224: bool,
+        algorithm: Algorithm,
+        blob: &[u8],
+        key: &[u8],
+        arena: Option<&SecureArena>,
+    ) -> Result<Self, &'static str> {
+        let body = midstate::open::<SHA256>(algorithm, key, blob, MIDSTATE_BODY_LEN)?;
+
+        let total_len = u64::from_be_bytes(body[32..40].try_into().unwrap());
+        let buffer_len = body[40] as usize;
+        // 버퍼 길이는 누적 바이트 수를 블록 크기로 나눈 나머지와 일치해야 함
+        if buffer_len >= 64 || total_len % 8 != 0 || (total_len / 8) % 64 != buffer_len as u64 {
+            return Err("Midstate field out of range");
+        }
+
+        let mut restored = Self::new_in(is_224, arena)?;
+        for (word, chunk) in restored.state.iter_mut().zip(body[..32].chunks_exact(4)) {
+            *word = u32::from_be_bytes(chunk.try_into().unwrap());
+        }
+        restored.total_len = total_len;
+        restored.buffer_len = buffer_len;
+        restored.buffer.as_mut_slice()[..buffer_len].copy_from_slice(&body[41..41 + buffer_len]);
+        Ok(restored)
+    }
+}
+
+impl Clone for Sha256State {
+    /// 버퍼를 원본과 같은 아레나(또는 개별 잠긴 메모리 블록)에 새로 할당하여 복제합니다.
+    ///
+    /// # Panics
+    /// 버퍼 할당에 실패한 경우 (`new`와 동일)
+    fn clone(&self) -> Self {
+        let mut buffer =
+            SecureBuffer::new_in(64, self.arena.as_ref()).expect("SecureBuffer allocate failed");
+        buffer.as_mut_slice()[..self.buffer_len]
+            .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+        Self {
+            state: self.state,
+            buffer,
+            buffer_len: self.buffer_len,
+            total_len: self.total_len,
+            is_224: self.is_224,
+            arena: self.arena.clone(),
+            accelerated: self.accelerated,
+        }
     }
 }
@@ -1,10 +1,11 @@
-use crate::Sha512State;
-use core::ptr::write_volatile;
-use core::sync::atomic::{Ordering, compiler_fence};
-use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeSelect};
-use entlib_native_secure_buffer::SecureBuffer;
+use crate::accel;
+use crate::api::SHA256;
+use crate::{Sha512State, Sha512Variant};
+use entlib_native_constant_time::traits::{ConstantTimeOrd, ConstantTimeSelect};
+use entlib_native_digest::midstate::{self, Algorithm};
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer, SecureZeroize};
 
-const SHA_512_K: [u64; 80] = [
+pub(crate) const SHA_512_K: [u64; 80] = [
     0x428a2f98d728ae22,
     0x7137449123ef65cd,
     0xb5c0fbcfec4d3b2f,
@@ -88,9 +89,30 @@ const SHA_512_K: [u64; 80] = [
 ];
 
 impl Sha512State {
-    pub(crate) fn new(is_384: bool) -> Self {
-        let state = if is_384 {
-            [
+    pub(crate) fn new(variant: Sha512Variant) -> Self {
+        Self::new_in(variant, None).expect("SecureBuffer allocate failed")
+    }
+
+    /// 내부 버퍼와 다이제스트를 `arena`의 슬롯에서 할당하는 상태를 생성합니다.
+    pub(crate) fn new_in(
+        variant: Sha512Variant,
+        arena: Option<&SecureArena>,
+    ) -> Result<Self, &'static str> {
+        Ok(Self {
+            state: Self::initial_state(variant),
+            buffer: SecureBuffer::new_in(128, arena)?,
+            buffer_len: 0,
+            total_len: 0,
+            variant,
+            arena: arena.cloned(),
+            accelerated: accel::sha512_available(),
+        })
+    }
+
+    /// 초기 해시 값(initial hash value, FIPS 180-4 5.3.4 ~ 5.3.6)
+    fn initial_state(variant: Sha512Variant) -> [u64; 8] {
+        match variant {
+            Sha512Variant::Sha384 => [
                 0xcbbb9d5dc1059ed8,
                 0x629a292a367cd507,
                 0x9159015a3070dd17,
@@ -99,9 +121,8 @@ impl Sha512State {
                 0x8eb44a8768581511,
                 0xdb0c2e0d64f98fa7,
                 0x47b5481dbefa4fa4,
-            ]
-        } else {
-            [
+            ],
+            Sha512Variant::Sha512 => [
                 0x6a09e667f3bcc908,
                 0xbb67ae8584caa73b,
                 0x3c6ef372fe94f82b,
@@ -110,19 +131,61 @@ impl Sha512State {
                 0x9b05688c2b3e6c1f,
                 0x1f83d9abfb41bd6b,
                 0x5be0cd19137e2179,
-            ]
-        };
-        Self {
-            state,
-            buffer: SecureBuffer::new_owned(128).expect("SecureBuffer allocate failed"),
-            buffer_len: 0,
-            total_len: 0,
-            is_384,
+            ],
+            // SHA-512/t IV 생성 함수(5.3.6)로 "SHA-512/224"에서 유도된 값
+            Sha512Variant::Sha512_224 => [
+                0x8c3d37c819544da2,
+                0x73e1996689dcd4d6,
+                0x1dfab7ae32ff9c82,
+                0x679dd514582f9fcf,
+                0x0f6d2b697bd44da8,
+                0x77e36f7304c48942,
+                0x3f9d85a86a1d36c8,
+                0x1112e6ad91d692a1,
+            ],
+            // SHA-512/t IV 생성 함수(5.3.6)로 "SHA-512/256"에서 유도된 값
+            Sha512Variant::Sha512_256 => [
+                0x22312194fc2bf72c,
+                0x9f555fa3c84c64c2,
+                0x2393b86b6f53b151,
+                0x963877195940eabd,
+                0x96283ee2a88effe3,
+                0xbe5e1e2553863992,
+                0x2b0199fc2c85b8aa,
+                0x0eb72ddc81c52ca2,
+            ],
         }
     }
 
+    /// 주입된 데이터와 체인 값을 소거하고 초기 상태로 되돌립니다. 버퍼 할당은 유지됩니다.
+    pub(crate) fn reset(&mut self) {
+        self.state.secure_zeroize();
+        self.state = Self::initial_state(self.variant);
+        self.buffer.secure_zeroize();
+        self.buffer_len = 0;
+        self.total_len = 0;
+    }
+
     /// 128바이트 데이터 블록을 처리하는 압축 함수
     fn process_block(&mut self, block: &[u8; 128]) {
+        self.compress_blocks(block);
+    }
+
+    /// 128바이트 배수 길이의 블록열을 압축합니다.
+    ///
+    /// 가속 경로 선택 여부는 CPU 기능에서 유래한 공개 정보이므로 분기를 허용합니다.
+    fn compress_blocks(&mut self, blocks: &[u8]) {
+        if self.accelerated {
+            accel::compress512(&mut self.state, blocks);
+        } else {
+            for block in blocks.chunks_exact(128) {
+                self.process_block_portable(block.try_into().unwrap());
+            }
+        }
+    }
+
+    /// 이식형(portable) 압축 함수
+    fn process_block_portable(&mut self, block: &[u8; 128]) {
         let mut w = [0u64; 80];
 
         // 128바이트 블록을 16개의 64비트 빅 엔디안(big-endian) 워드로 변환
@@ -191,12 +254,7 @@ impl Sha512State {
         self.state[7] = self.state[7].wrapping_add(h);
 
         // 메시지 스케줄 w의 메모리 파기
-        for item in w.iter_mut() {
-            unsafe {
-                write_volatile(item, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
+        w.secure_zeroize();
     }
 
     /// 임의의 길이 데이터를 내부 버퍼에 누적 및 처리
@@ -205,6 +263,14 @@ impl Sha512State {
         let mut i = 0;
 
         while i < data.len() {
+            // 버퍼가 비어 있으면 완전한 블록들을 입력에서 직접 압축 (길이는 공개 정보)
+            let bulk = (data.len() - i) / 128 * 128;
+            if self.buffer_len == 0 && bulk > 0 {
+                self.compress_blocks(&data[i..i + bulk]);
+                i += bulk;
+                continue;
+            }
+
             let fill = 128 - self.buffer_len;
 
             let remain = data.len() - i;
@@ -227,8 +293,21 @@ impl Sha512State {
         }
     }
 
-    /// 해시 연산 종료 및 다이제스트(digest) 반환
-    pub(crate) fn finalize(mut self) -> Result<SecureBuffer, &'static str> {
+    /// 다이제스트 크기 (바이트)
+    fn digest_len(&self) -> usize {
+        self.variant.digest_len()
+    }
+
+    /// 패딩과 마지막 압축을 수행하여 다이제스트를 `out`에 기록하고 초기 상태로 되돌립니다.
+    ///
+    /// 출력 버퍼를 호출자가 제공하므로 메모리 할당이 발생하지 않습니다.
+    pub(crate) fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        // 출력 길이는 알고리즘에 따른 공개 정보
+        let digest_size = self.digest_len();
+        if out.len() != digest_size {
+            return Err("Output buffer length mismatch");
+        }
+
         let len = self.buffer_len;
         self.buffer.as_mut_slice()[len] = 0x80;
         self.buffer_len += 1;
@@ -268,24 +347,10 @@ impl Sha512State {
         }
 
         // 스택에 할당된 임시 데이터 소거
-        for b in &mut block1 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        for b in &mut block2 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
-
-        // 다이제스트 생성을 위한 SecureBuffer 할당
-        let digest_size = if self.is_384 { 48 } else { 64 };
-        // 실패 시 &'static str 에러를 반환(?)하여 안전하게 에러 핸들링
-        let mut digest_buf = SecureBuffer::new_owned(digest_size)?;
+        block1.secure_zeroize();
+        block2.secure_zeroize();
 
-        let digest_slice = digest_buf.as_mut_slice();
+        // 상태(state) 배열의 값을 출력 버퍼로 전송
         for (i, &s) in self.state.iter().enumerate() {
             let start = i * 8;
             if start >= digest_size {
@@ -299,9 +364,103 @@ impl Sha512State {
             let end = usize::ct_select(&digest_size, &max_end, is_end_ge);
             let copy_len = end - start;
 
-            digest_slice[start..end].copy_from_slice(&bytes[..copy_len]);
+            out[start..end].copy_from_slice(&bytes[..copy_len]);
         }
 
+        // 최종 체인 값과 버퍼를 소거하고 다음 메시지를 위해 초기 상태로 복귀
+        self.reset();
+        Ok(())
+    }
+
+    /// 다이제스트를 새로 할당한 [`SecureBuffer`]로 반환하고 초기 상태로 되돌립니다.
+    ///
+    /// 할당에 실패하면 상태는 변경되지 않습니다.
+    pub(crate) fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        let mut digest_buf = SecureBuffer::new_in(self.digest_len(), self.arena.as_ref())?;
+        self.finalize_into(digest_buf.as_mut_slice())?;
         Ok(digest_buf)
     }
+
+    pub(crate) fn finalize(mut self) -> Result<SecureBuffer, &'static str> {
+        self.finalize_reset()
+    }
+}
+
+/// 중간 상태 본문 길이: 체인 값(64) + 비트 길이(16) + 버퍼 길이(1) + 버퍼(128)
+const MIDSTATE_BODY_LEN: usize = 209;
+
+impl Sha512State {
+    /// 현재 상태를 HMAC-SHA-256 태그로 인증하여 직렬화합니다.
+    pub(crate) fn export_midstate(
+        &self,
+        algorithm: Algorithm,
+        key: &[u8],
+    ) -> Result<SecureBuffer, &'static str> {
+        midstate::seal::<SHA256>(
+            algorithm,
+            key,
+            MIDSTATE_BODY_LEN,
+            self.arena.as_ref(),
+            |body| {
+                for (chunk, word) in body[..64].chunks_exact_mut(8).zip(&self.state) {
+                    chunk.copy_from_slice(&word.to_be_bytes());
+                }
+                body[64..80].copy_from_slice(&self.total_len.to_be_bytes());
+                body[80] = self.buffer_len as u8;
+                // 버퍼 길이 이후의 이전 블록 잔여 바이트는 내보내지 않음
+                body[81..81 + self.buffer_len]
+                    .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+                body[81 + self.buffer_len..].fill(0);
+            },
+        )
+    }
+
+    /// 직렬화된 상태를 검증하고 복원합니다.
+    pub(crate) fn import_midstate(
+        variant: Sha512Variant,
+        algorithm: Algorithm,
+        blob: &[u8],
+        key: &[u8],
+        arena: Option<&SecureArena>,
+    ) -> Result<Self, &'static str> {
+        let body = midstate::open::<SHA256>(algorithm, key, blob, MIDSTATE_BODY_LEN)?;
+
+        let total_len = u128::from_be_bytes(body[64..80].try_into().unwrap());
+        let buffer_len = body[80] as usize;
+        // 버퍼 길이는 누적 바이트 수를 블록 크기로 나눈 나머지와 일치해야 함
+        if buffer_len >= 128 || total_len % 8 != 0 || (total_len / 8) % 128 != buffer_len as u128 {
+            return Err("Midstate field out of range");
+        }
+
+        let mut restored = Self::new_in(variant, arena)?;
+        for (word, chunk) in restored.state.iter_mut().zip(body[..64].chunks_exact(8)) {
+            *word = u64::from_be_bytes(chunk.try_into().unwrap());
+        }
+        restored.total_len = total_len;
+        restored.buffer_len = buffer_len;
+        restored.buffer.as_mut_slice()[..buffer_len].copy_from_slice(&body[81..81 + buffer_len]);
+        Ok(restored)
+    }
+}
+
+impl Clone for Sha512State {
+    /// 버퍼를 원본과 같은 아레나(또는 개별 잠긴 메모리 블록)에 새로 할당하여 복제합니다.
+    ///
+    /// # Panics
+    /// 버퍼 할당에 실패한 경우 (`new`와 동일)
+    fn clone(&self) -> Self {
+        let mut buffer =
+            SecureBuffer::new_in(128, self.arena.as_ref()).expect("SecureBuffer allocate failed");
+        buffer.as_mut_slice()[..self.buffer_len]
+            .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+        Self {
+            state: self.state,
+            buffer,
+            buffer_len: self.buffer_len,
+            total_len: self.total_len,
+            variant: self.variant,
+            arena: self.arena.clone(),
+            accelerated: self.accelerated,
+        }
+    }
 }
@@ -0,0 +1,110 @@
+use entlib_native_digest::{Digest, Reset};
+use entlib_native_secure_buffer::SecureArena;
+use entlib_native_sha2::api::*;
+
+//
+// 헬퍼
+//
+
+fn oneshot<D: Digest>(data: &[u8]) -> Vec<u8> {
+    let mut h = D::new();
+    h.update(data);
+    h.finalize().unwrap().as_slice().to_vec()
+}
+
+/// 트레이트 경로와 고유 메서드 경로의 결과 및 상수 일치, 리셋 후 재사용 검증
+fn check_digest<D: Digest + Reset>(expected_abc: &[u8]) {
+    assert_eq!(D::OUTPUT_LEN, expected_abc.len());
+    assert_eq!(oneshot::<D>(b"abc"), expected_abc);
+
+    // 오류를 반환하는 생성 경로도 동일한 초기 상태를 가짐
+    let mut h = D::try_new().unwrap();
+    h.update(b"abc");
+    assert_eq!(h.finalize().unwrap().as_slice(), expected_abc);
+
+    // 블록 경계를 넘는 데이터를 주입한 뒤 리셋하면 새 인스턴스와 동일해야 함
+    let mut h = D::new();
+    h.update(&vec![0xA5u8; D::BLOCK_LEN * 2 + 7]);
+    h.reset();
+    h.update(b"abc");
+    assert_eq!(h.finalize().unwrap().as_slice(), expected_abc);
+
+    // 아레나 슬롯에서 할당된 인스턴스도 리셋 후 동일한 슬롯을 재사용
+    let arena = SecureArena::new(256, 8).unwrap();
+    let mut h = D::new_in(&arena).unwrap();
+    h.update(b"discarded");
+    h.reset();
+    assert_eq!(arena.stats().used_slots, 1);
+    h.update(b"abc");
+    let digest = h.finalize().unwrap();
+    assert_eq!(digest.as_slice(), expected_abc);
+    drop(digest);
+    assert_eq!(arena.stats().used_slots, 0);
+}
+
+/// 비소비 종료(`finalize_into`, `finalize_reset`) 후 인스턴스 재사용 및 무할당 검증
+fn check_finalize_reuse<D: Digest>() {
+    let messages: [&[u8]; 3] = [b"abc", b"", &[0x6Bu8; 300]];
+
+    let mut h = D::new();
+    let mut out = vec![0u8; D::OUTPUT_LEN];
+    for msg in messages {
+        h.update(msg);
+        h.finalize_into(&mut out).unwrap();
+        assert_eq!(out, oneshot::<D>(msg));
+
+        h.update(msg);
+        assert_eq!(h.finalize_reset().unwrap().as_slice(), oneshot::<D>(msg));
+    }
+
+    // 출력 길이 불일치는 상태를 변경하지 않고 거부
+    h.update(b"ab");
+    let mut short = vec![0u8; D::OUTPUT_LEN - 1];
+    assert_eq!(
+        h.finalize_into(&mut short),
+        Err("Output buffer length mismatch")
+    );
+    h.update(b"c");
+    h.finalize_into(&mut out).unwrap();
+    assert_eq!(out, oneshot::<D>(b"abc"));
+
+    // 아레나 인스턴스는 반복 종료에도 추가 슬롯을 사용하지 않음
+    let arena = SecureArena::new(256, 4).unwrap();
+    let mut h = D::new_in(&arena).unwrap();
+    for i in 0..64u8 {
+        h.update(&[i]);
+        h.finalize_into(&mut out).unwrap();
+        assert_eq!(out, oneshot::<D>(&[i]));
+    }
+    assert_eq!(arena.stats().used_slots, 1);
+}
+
+#[test]
+fn sha2_digest_trait_matches_fips180_vectors() {
+    check_digest::<SHA224>(b"\x23\x09\x7d\x22\x34\x05\xd8\x22\x86\x42\xa4\x77\xbd\xa2\x55\xb3\x2a\xad\xbc\xe4\xbd\xa0\xb3\xf7\xe3\x6c\x9d\xa7");
+    check_digest::<SHA256>(b"\xba\x78\x16\xbf\x8f\x01\xcf\xea\x41\x41\x40\xde\x5d\xae\x22\x23\xb0\x03\x61\xa3\x96\x17\x7a\x9c\xb4\x10\xff\x61\xf2\x00\x15\xad");
+    check_digest::<SHA384>(b"\xcb\x00\x75\x3f\x45\xa3\x5e\x8b\xb5\xa0\x3d\x69\x9a\xc6\x50\x07\x27\x2c\x32\xab\x0e\xde\xd1\x63\x1a\x8b\x60\x5a\x43\xff\x5b\xed\x80\x86\x07\x2b\xa1\xe7\xcc\x23\x58\xba\xec\xa1\x34\xc8\x25\xa7");
+    check_digest::<SHA512>(b"\xdd\xaf\x35\xa1\x93\x61\x7a\xba\xcc\x41\x73\x49\xae\x20\x41\x31\x12\xe6\xfa\x4e\x89\xa9\x7e\xa2\x0a\x9e\xee\xe6\x4b\x55\xd3\x9a\x21\x92\x99\x2a\x27\x4f\xc1\xa8\x36\xba\x3c\x23\xa3\xfe\xeb\xbd\x45\x4d\x44\x23\x64\x3c\xe8\x0e\x2a\x9a\xc9\x4f\xa5\x4c\xa4\x9f");
+    check_digest::<SHA512_224>(b"\x46\x34\x27\x0f\x70\x7b\x6a\x54\xda\xae\x75\x30\x46\x08\x42\xe2\x0e\x37\xed\x26\x5c\xee\xe9\xa4\x3e\x89\x24\xaa");
+    check_digest::<SHA512_256>(b"\x53\x04\x8e\x26\x81\x94\x1e\xf9\x9b\x2e\x29\xb7\x6b\x4c\x7d\xab\xe4\xc2\xd0\xc6\x34\xfc\x6d\x46\xe0\xe2\xf1\x31\x07\xe7\xaf\x23");
+}
+
+#[test]
+fn sha2_block_lengths() {
+    assert_eq!(<SHA224 as Digest>::BLOCK_LEN, 64);
+    assert_eq!(<SHA256 as Digest>::BLOCK_LEN, 64);
+    assert_eq!(<SHA384 as Digest>::BLOCK_LEN, 128);
+    assert_eq!(<SHA512 as Digest>::BLOCK_LEN, 128);
+    assert_eq!(<SHA512_224 as Digest>::BLOCK_LEN, 128);
+    assert_eq!(<SHA512_256 as Digest>::BLOCK_LEN, 128);
+}
+
+#[test]
+fn sha2_finalize_into_and_reset_reuse_instance() {
+    check_finalize_reuse::<SHA224>();
+    check_finalize_reuse::<SHA256>();
+    check_finalize_reuse::<SHA384>();
+    check_finalize_reuse::<SHA512>();
+    check_finalize_reuse::<SHA512_224>();
+    check_finalize_reuse::<SHA512_256>();
+}
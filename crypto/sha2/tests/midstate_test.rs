@@ -0,0 +1,117 @@
+use entlib_native_digest::{Digest, Midstate, midstate};
+use entlib_native_secure_buffer::SecureArena;
+use entlib_native_sha2::api::*;
+
+const KEY: [u8; 32] = [0x42; 32];
+
+//
+// 헬퍼
+//
+
+fn oneshot<D: Digest>(data: &[u8]) -> Vec<u8> {
+    let mut h = D::new();
+    h.update(data);
+    h.finalize().unwrap().as_slice().to_vec()
+}
+
+/// 접두사를 흡수한 상태의 복제, 내보내기/복원 후 이어서 해싱한 결과가 단일 해싱과 일치하는지 검증
+fn check_midstate<D: Digest + Midstate + Clone>() {
+    let prefix = vec![0x3Cu8; D::BLOCK_LEN + 5];
+    let full = |suffix: &[u8]| oneshot::<D>(&[prefix.as_slice(), suffix].concat());
+
+    let mut base = D::new();
+    base.update(&prefix);
+
+    // 복제본과 원본은 서로 독립적으로 진행
+    let mut cloned = base.clone();
+    cloned.update(b"left");
+    assert_eq!(cloned.finalize().unwrap().as_slice(), full(b"left"));
+
+    let blob = base.export_midstate(&KEY).unwrap();
+    base.update(b"right");
+    assert_eq!(base.finalize().unwrap().as_slice(), full(b"right"));
+
+    let mut restored = D::import_midstate(blob.as_slice(), &KEY).unwrap();
+    restored.update(b"restored");
+    assert_eq!(restored.finalize().unwrap().as_slice(), full(b"restored"));
+
+    // 아레나에서 복원한 인스턴스와 그 복제본은 같은 아레나의 슬롯을 사용
+    let arena = SecureArena::new(256, 8).unwrap();
+    let mut restored = D::import_midstate_in(blob.as_slice(), &KEY, &arena).unwrap();
+    let twin = restored.clone();
+    assert_eq!(arena.stats().used_slots, 2);
+    drop(twin);
+    restored.update(b"arena");
+    assert_eq!(restored.finalize().unwrap().as_slice(), full(b"arena"));
+}
+
+/// 잘못된 키, 변조, 절단, 버전 불일치 거부
+fn check_rejects_tampering<D: Digest + Midstate>() {
+    let mut h = D::new();
+    h.update(b"prefix");
+    let blob = h.export_midstate(&KEY).unwrap();
+    let bytes = blob.as_slice();
+    let err = |blob: &[u8], key: &[u8]| D::import_midstate(blob, key).err().unwrap();
+
+    assert_eq!(err(bytes, &[0x43; 32]), "Midstate authentication failed");
+    assert_eq!(err(bytes, &KEY[..13]), "Midstate key is too short");
+    assert_eq!(
+        err(&bytes[..bytes.len() - 1], &KEY),
+        "Midstate length mismatch"
+    );
+
+    // 본문 및 태그의 단일 비트 변조
+    for index in [midstate::HEADER_LEN, bytes.len() - 1] {
+        let mut tampered = bytes.to_vec();
+        tampered[index] ^= 0x01;
+        assert_eq!(err(&tampered, &KEY), "Midstate authentication failed");
+    }
+
+    let mut other_version = bytes.to_vec();
+    other_version[2] = midstate::VERSION + 1;
+    assert_eq!(err(&other_version, &KEY), "Unsupported midstate version");
+}
+
+#[test]
+fn clone_and_midstate_resume_matches_oneshot() {
+    check_midstate::<SHA224>();
+    check_midstate::<SHA256>();
+    check_midstate::<SHA384>();
+    check_midstate::<SHA512>();
+    check_midstate::<SHA512_224>();
+    check_midstate::<SHA512_256>();
+}
+
+#[test]
+fn midstate_rejects_tampering() {
+    check_rejects_tampering::<SHA256>();
+    check_rejects_tampering::<SHA512>();
+}
+
+/// 상태 크기가 같은 알고리즘 사이의 복원 거부
+#[test]
+fn midstate_rejects_algorithm_mismatch() {
+    let blob = SHA256::new().export_midstate(&KEY).unwrap();
+    assert_eq!(
+        SHA224::import_midstate(blob.as_slice(), &KEY).err(),
+        Some("Midstate algorithm mismatch")
+    );
+
+    let blob = SHA512::new().export_midstate(&KEY).unwrap();
+    assert_eq!(
+        SHA512_256::import_midstate(blob.as_slice(), &KEY).err(),
+        Some("Midstate algorithm mismatch")
+    );
+}
+
+/// 버전 1 포맷의 헤더와 길이 고정
+#[test]
+fn midstate_v1_layout() {
+    let blob = SHA256::new().export_midstate(&KEY).unwrap();
+    assert_eq!(&blob.as_slice()[..4], b"EM\x01\x02");
+    assert_eq!(blob.len(), 4 + 105 + 32);
+
+    let blob = SHA512_224::new().export_midstate(&KEY).unwrap();
+    assert_eq!(&blob.as_slice()[..4], b"EM\x01\x05");
+    assert_eq!(blob.len(), 4 + 209 + 32);
+}
@@ -1,3 +1,4 @@
+use entlib_native_secure_buffer::SecureArena;
 use entlib_native_sha2::api::*;
 
 // yo thanks for Legion of the BouncyCastle Inc
@@ -55,4 +56,55 @@ mod tests {
         let digest_single = hasher_single.finalize().unwrap();
         assert_eq!(digest_chunked.as_slice(), digest_single.as_slice());
     }
+
+    #[test]
+    fn test_arena_backed_hasher() {
+        // 아레나 슬롯 기반 인스턴스는 일반 인스턴스와 동일한 다이제스트를 산출해야 함
+        let arena = SecureArena::new(64, 8).unwrap();
+
+        let mut hasher_arena = SHA256::new_in(&arena).unwrap();
+        hasher_arena.update(b"abc");
+        let digest_arena = hasher_arena.finalize().unwrap();
+
+        let mut hasher_heap = SHA256::new();
+        hasher_heap.update(b"abc");
+        let digest_heap = hasher_heap.finalize().unwrap();
+        assert_eq!(digest_arena.as_slice(), digest_heap.as_slice());
+        assert!(digest_arena.is_arena_slot());
+
+        // 다이제스트 해제 후 모든 슬롯이 반환되어야 함
+        drop(digest_arena);
+        assert_eq!(arena.stats().used_slots, 0);
+    }
+
+    /// 하드웨어 가속 경로와 이식형 경로의 결과 일치 (블록 경계 및 분할 주입 포함)
+    #[test]
+    fn accelerated_matches_portable() {
+        let msg: Vec<u8> = (0..300u32).map(|i| (i * 131 + 7) as u8).collect();
+        for len in 0..msg.len() {
+            let split = len / 3;
+            {
+                let mut h = SHA224::new();
+                h.update(&msg[..split]);
+                h.update(&msg[split..len]);
+                let mut p = SHA224::new_portable();
+                p.update(&msg[..len]);
+                assert_eq!(
+                    h.finalize().unwrap().as_slice(),
+                    p.finalize().unwrap().as_slice()
+                );
+            }
+            {
+                let mut h = SHA256::new();
+                h.update(&msg[..split]);
+                h.update(&msg[split..len]);
+                let mut p = SHA256::new_portable();
+                p.update(&msg[..len]);
+                assert_eq!(
+                    h.finalize().unwrap().as_slice(),
+                    p.finalize().unwrap().as_slice()
+                );
+            }
+        }
+    }
 }
@@ -55,4 +55,35 @@ mod tests {
         let digest_single = hasher_single.finalize().unwrap();
         assert_eq!(digest_chunked.as_slice(), digest_single.as_slice());
     }
+
+    /// 하드웨어 가속 경로와 이식형 경로의 결과 일치 (블록 경계 및 분할 주입 포함)
+    #[test]
+    fn accelerated_matches_portable() {
+        let msg: Vec<u8> = (0..520u32).map(|i| (i * 131 + 7) as u8).collect();
+        for len in 0..msg.len() {
+            let split = len / 3;
+            {
+                let mut h = SHA384::new();
+                h.update(&msg[..split]);
+                h.update(&msg[split..len]);
+                let mut p = SHA384::new_portable();
+                p.update(&msg[..len]);
+                assert_eq!(
+                    h.finalize().unwrap().as_slice(),
+                    p.finalize().unwrap().as_slice()
+                );
+            }
+            {
+                let mut h = SHA512::new();
+                h.update(&msg[..split]);
+                h.update(&msg[split..len]);
+                let mut p = SHA512::new_portable();
+                p.update(&msg[..len]);
+                assert_eq!(
+                    h.finalize().unwrap().as_slice(),
+                    p.finalize().unwrap().as_slice()
+                );
+            }
+        }
+    }
 }
@@ -7,6 +7,7 @@ mod kcmvp_cavp_test {
 
     pub trait CavpHash {
         fn new() -> Self;
+        fn new_portable() -> Self;
         fn update(&mut self, data: &[u8]);
         fn finalize(self) -> Result<SecureBuffer, &'static str>;
     }
@@ -17,6 +18,9 @@ mod kcmvp_cavp_test {
                 fn new() -> Self {
                     Self::new()
                 }
+                fn new_portable() -> Self {
+                    Self::new_portable()
+                }
                 fn update(&mut self, data: &[u8]) {
                     self.update(data);
                 }
@@ -43,6 +47,24 @@ mod kcmvp_cavp_test {
             .collect()
     }
 
+    /// 기본 경로(하드웨어 가속 가능)와 이식형 경로로 각각 해시하고 두 결과의 일치를 교차 검증
+    fn digest_cross_checked<T: CavpHash>(msg: &[u8]) -> Vec<u8> {
+        let mut hasher = T::new();
+        hasher.update(msg);
+        let md = hasher.finalize().expect("Hash finalization failed");
+
+        let mut portable = T::new_portable();
+        portable.update(msg);
+        let md_portable = portable.finalize().expect("Hash finalization failed");
+
+        assert_eq!(
+            md.as_slice(),
+            md_portable.as_slice(),
+            "accelerated and portable compression disagree"
+        );
+        md.as_slice().to_vec()
+    }
+
     fn bytes_to_hex(bytes: &[u8]) -> String {
         bytes.iter().map(|b| format!("{:02X}", b)).collect()
     }
@@ -72,10 +94,7 @@ mod kcmvp_cavp_test {
                     msg_bytes.truncate(byte_len);
                 }
 
-                let mut hasher = T::new();
-                hasher.update(&msg_bytes);
-                let md_buffer = hasher.finalize().expect("Hash finalization failed");
-                let md_hex = bytes_to_hex(md_buffer.as_slice());
+                let md_hex = bytes_to_hex(&digest_cross_checked::<T>(&msg_bytes));
 
                 writeln!(rsp_file, "{}", trimmed)?;
                 writeln!(rsp_file, "MD = {}", md_hex)?;
@@ -124,10 +143,7 @@ mod kcmvp_cavp_test {
                         m_i.extend_from_slice(&md_2);
 
                         // MD_i = SHA2(M_i)
-                        let mut hasher = T::new();
-                        hasher.update(&m_i);
-                        let digest = hasher.finalize().expect("Hash finalization failed");
-                        let md_i = digest.as_slice().to_vec();
+                        let md_i = digest_cross_checked::<T>(&m_i);
 
                         // 윈도우 시프트 (다음 루프를 위해 상태 업데이트)
                         md_0 = md_1;
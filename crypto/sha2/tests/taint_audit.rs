@@ -0,0 +1,65 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-sha2 --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_sha2::api::{SHA256, SHA512};
+use entlib_native_test_support::taint::{classify_secret, declassify};
+
+/// 여러 블록에 걸친 비밀 메시지 (패딩 경계 포함)
+fn secret_message() -> [u8; 200] {
+    let message: [u8; 200] = core::array::from_fn(|i| (i as u8).wrapping_mul(37));
+    classify_secret(&message);
+    message
+}
+
+#[test]
+fn audit_taint_flow_sha256() {
+    let message = secret_message();
+    let mut hasher = SHA256::new();
+    hasher.update(&message[..3]);
+    hasher.update(&message[3..]);
+    let digest = hasher.finalize().unwrap();
+    declassify(digest.as_slice());
+    declassify(&message);
+
+    let mut reference = SHA256::new();
+    reference.update(&message);
+    assert_eq!(digest.as_slice(), reference.finalize().unwrap().as_slice());
+}
+
+#[test]
+fn audit_taint_flow_sha512() {
+    let message = secret_message();
+    let mut hasher = SHA512::new();
+    hasher.update(&message);
+    let digest = hasher.finalize().unwrap();
+    declassify(digest.as_slice());
+    declassify(&message);
+
+    let mut reference = SHA512::new();
+    reference.update(&message[..100]);
+    reference.update(&message[100..]);
+    assert_eq!(digest.as_slice(), reference.finalize().unwrap().as_slice());
+}
+
+#[test]
+fn audit_taint_flow_sha256_known_answer() {
+    let message = *b"abc";
+    classify_secret(&message);
+    let mut hasher = SHA256::new();
+    hasher.update(&message);
+    let digest = hasher.finalize().unwrap();
+    declassify(digest.as_slice());
+    assert_eq!(
+        digest.as_slice(),
+        &[
+            0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+            0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+            0xf2, 0x00, 0x15, 0xad,
+        ]
+    );
+}
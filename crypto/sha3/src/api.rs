@@ -18,11 +18,14 @@
 #![allow(non_camel_case_types)]
 
 use crate::KeccakState;
-use entlib_native_secure_buffer::SecureBuffer;
+use entlib_native_digest::midstate::Algorithm;
+use entlib_native_digest::{Digest, ExtendableOutput, Midstate, Reset};
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer};
 
 //
 // SHA3-224 - start
 //
+#[derive(Clone)]
 pub struct SHA3_224(KeccakState);
 impl SHA3_224 {
     // 인스턴스 초기화
@@ -30,6 +33,16 @@ impl SHA3_224 {
         Self(KeccakState::new(1152, 0x06))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1152, 0x06, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1152, 0x06, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -40,6 +53,16 @@ impl SHA3_224 {
         self.0.finalize(28, None)
     }
 
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_fixed_into(out, 28)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(28, None)
+    }
+
     // last_bits: 0~7 사이의 값. 마지막 바이트의 유효 비트 개수
     pub fn finalize_bits(
         self,
@@ -55,6 +78,64 @@ impl Default for SHA3_224 {
         Self::new()
     }
 }
+
+impl Digest for SHA3_224 {
+    const OUTPUT_LEN: usize = 28;
+    const BLOCK_LEN: usize = 144;
+
+    fn new() -> Self {
+        SHA3_224::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA3_224::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA3_224::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA3_224::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA3_224::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA3_224::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA3_224 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA3_224 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha3_224, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1152, 0x06, Algorithm::Sha3_224, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1152, 0x06, Algorithm::Sha3_224, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHA3-224 - end
 //
@@ -62,6 +143,7 @@ impl Default for SHA3_224 {
 //
 // SHA3-256 - start
 //
+#[derive(Clone)]
 pub struct SHA3_256(KeccakState);
 impl SHA3_256 {
     // 인스턴스 초기화
@@ -69,6 +151,16 @@ impl SHA3_256 {
         Self(KeccakState::new(1088, 0x06))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1088, 0x06, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1088, 0x06, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -79,6 +171,16 @@ impl SHA3_256 {
         self.0.finalize(32, None)
     }
 
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_fixed_into(out, 32)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(32, None)
+    }
+
     // last_bits: 0~7 사이의 값. 마지막 바이트의 유효 비트 개수
     pub fn finalize_bits(
         self,
@@ -94,6 +196,64 @@ impl Default for SHA3_256 {
         Self::new()
     }
 }
+
+impl Digest for SHA3_256 {
+    const OUTPUT_LEN: usize = 32;
+    const BLOCK_LEN: usize = 136;
+
+    fn new() -> Self {
+        SHA3_256::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA3_256::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA3_256::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA3_256::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA3_256::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA3_256::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA3_256 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA3_256 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha3_256, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1088, 0x06, Algorithm::Sha3_256, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1088, 0x06, Algorithm::Sha3_256, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHA3-256 - end
 //
@@ -101,6 +261,7 @@ impl Default for SHA3_256 {
 //
 // SHA3-384 - start
 //
+#[derive(Clone)]
 pub struct SHA3_384(KeccakState);
 impl SHA3_384 {
     // 인스턴스 초기화
@@ -108,6 +269,16 @@ impl SHA3_384 {
         Self(KeccakState::new(832, 0x06))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(832, 0x06, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(832, 0x06, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -118,6 +289,16 @@ impl SHA3_384 {
         self.0.finalize(48, None)
     }
 
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_fixed_into(out, 48)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(48, None)
+    }
+
     // last_bits: 0~7 사이의 값. 마지막 바이트의 유효 비트 개수
     pub fn finalize_bits(
         self,
@@ -133,6 +314,64 @@ impl Default for SHA3_384 {
         Self::new()
     }
 }
+
+impl Digest for SHA3_384 {
+    const OUTPUT_LEN: usize = 48;
+    const BLOCK_LEN: usize = 104;
+
+    fn new() -> Self {
+        SHA3_384::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA3_384::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA3_384::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA3_384::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA3_384::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA3_384::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA3_384 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA3_384 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha3_384, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(832, 0x06, Algorithm::Sha3_384, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(832, 0x06, Algorithm::Sha3_384, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHA3-384 - end
 //
@@ -140,6 +379,7 @@ impl Default for SHA3_384 {
 //
 // SHA3-512 - start
 //
+#[derive(Clone)]
 pub struct SHA3_512(KeccakState);
 impl SHA3_512 {
     // 인스턴스 초기화
@@ -147,6 +387,16 @@ impl SHA3_512 {
         Self(KeccakState::new(576, 0x06))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(576, 0x06, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(576, 0x06, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -157,6 +407,16 @@ impl SHA3_512 {
         self.0.finalize(64, None)
     }
 
+    // 다이제스트를 `out`에 기록하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        self.0.finalize_fixed_into(out, 64)
+    }
+
+    // 다이제스트 반환 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(64, None)
+    }
+
     // last_bits: 0~7 사이의 값. 마지막 바이트의 유효 비트 개수
     pub fn finalize_bits(
         self,
@@ -172,6 +432,64 @@ impl Default for SHA3_512 {
         Self::new()
     }
 }
+
+impl Digest for SHA3_512 {
+    const OUTPUT_LEN: usize = 64;
+    const BLOCK_LEN: usize = 72;
+
+    fn new() -> Self {
+        SHA3_512::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHA3_512::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHA3_512::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize(self) -> Result<SecureBuffer, &'static str> {
+        SHA3_512::finalize(self)
+    }
+
+    fn finalize_into(&mut self, out: &mut [u8]) -> Result<(), &'static str> {
+        SHA3_512::finalize_into(self, out)
+    }
+
+    fn finalize_reset(&mut self) -> Result<SecureBuffer, &'static str> {
+        SHA3_512::finalize_reset(self)
+    }
+}
+
+impl Reset for SHA3_512 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHA3_512 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Sha3_512, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(576, 0x06, Algorithm::Sha3_512, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(576, 0x06, Algorithm::Sha3_512, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHA3-512 - end
 //
@@ -179,6 +497,7 @@ impl Default for SHA3_512 {
 //
 // SHAKE128 - start
 //
+#[derive(Clone)]
 pub struct SHAKE128(KeccakState);
 impl SHAKE128 {
     // 인스턴스 초기화
@@ -186,6 +505,16 @@ impl SHAKE128 {
         Self(KeccakState::new(1344, 0x1f))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1344, 0x1f, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1344, 0x1f, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -196,6 +525,16 @@ impl SHAKE128 {
         self.0.finalize(output_len, None)
     }
 
+    // `out` 길이만큼 출력하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) {
+        self.0.finalize_into(out, None);
+    }
+
+    // `output_len` 바이트 출력 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(output_len, None)
+    }
+
     // 불완전한 마지막 바이트(last_byte)와 그 유효 비트 수(valid_bits)를 함께 받음
     pub fn finalize_bits(
         self,
@@ -212,6 +551,63 @@ impl Default for SHAKE128 {
         Self::new()
     }
 }
+
+impl ExtendableOutput for SHAKE128 {
+    const BLOCK_LEN: usize = 168;
+
+    fn new() -> Self {
+        SHAKE128::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHAKE128::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHAKE128::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize_xof(self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        SHAKE128::finalize(self, output_len)
+    }
+
+    fn finalize_xof_into(&mut self, out: &mut [u8]) {
+        SHAKE128::finalize_into(self, out);
+    }
+
+    fn finalize_xof_reset(&mut self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        SHAKE128::finalize_reset(self, output_len)
+    }
+}
+
+impl Reset for SHAKE128 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHAKE128 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Shake128, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1344, 0x1f, Algorithm::Shake128, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1344, 0x1f, Algorithm::Shake128, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHAKE128 - end
 //
@@ -219,6 +615,7 @@ impl Default for SHAKE128 {
 //
 // SHAKE256 - start
 //
+#[derive(Clone)]
 pub struct SHAKE256(KeccakState);
 impl SHAKE256 {
     // 인스턴스 초기화
@@ -226,6 +623,16 @@ impl SHAKE256 {
         Self(KeccakState::new(1088, 0x1f))
     }
 
+    // 보안 메모리 할당 실패 시 패닉 대신 오류를 반환하는 인스턴스 초기화
+    pub fn try_new() -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1088, 0x1f, None)?))
+    }
+
+    // 내부 버퍼 및 다이제스트를 보안 아레나 슬롯에서 할당하는 인스턴스 초기화
+    pub fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        Ok(Self(KeccakState::new_in(1088, 0x1f, Some(arena))?))
+    }
+
     // 해시 대상 데이터 주입
     pub fn update(&mut self, data: &[u8]) {
         self.0.update(data);
@@ -236,6 +643,16 @@ impl SHAKE256 {
         self.0.finalize(output_len, None)
     }
 
+    // `out` 길이만큼 출력하고 초기 상태로 복귀 (메모리 할당 없음)
+    pub fn finalize_into(&mut self, out: &mut [u8]) {
+        self.0.finalize_into(out, None);
+    }
+
+    // `output_len` 바이트 출력 후 초기 상태로 복귀
+    pub fn finalize_reset(&mut self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        self.0.finalize_reset(output_len, None)
+    }
+
     // 불완전한 마지막 바이트(last_byte)와 그 유효 비트 수(valid_bits)를 함께 받음
     pub fn finalize_bits(
         self,
@@ -252,6 +669,63 @@ impl Default for SHAKE256 {
         Self::new()
     }
 }
+
+impl ExtendableOutput for SHAKE256 {
+    const BLOCK_LEN: usize = 136;
+
+    fn new() -> Self {
+        SHAKE256::new()
+    }
+
+    fn try_new() -> Result<Self, &'static str> {
+        SHAKE256::try_new()
+    }
+
+    fn new_in(arena: &SecureArena) -> Result<Self, &'static str> {
+        SHAKE256::new_in(arena)
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+
+    fn finalize_xof(self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        SHAKE256::finalize(self, output_len)
+    }
+
+    fn finalize_xof_into(&mut self, out: &mut [u8]) {
+        SHAKE256::finalize_into(self, out);
+    }
+
+    fn finalize_xof_reset(&mut self, output_len: usize) -> Result<SecureBuffer, &'static str> {
+        SHAKE256::finalize_reset(self, output_len)
+    }
+}
+
+impl Reset for SHAKE256 {
+    fn reset(&mut self) {
+        self.0.reset();
+    }
+}
+
+impl Midstate for SHAKE256 {
+    fn export_midstate(&self, key: &[u8]) -> Result<SecureBuffer, &'static str> {
+        self.0.export_midstate(Algorithm::Shake256, key)
+    }
+
+    fn import_midstate(blob: &[u8], key: &[u8]) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1088, 0x1f, Algorithm::Shake256, blob, key, None).map(Self)
+    }
+
+    fn import_midstate_in(
+        blob: &[u8],
+        key: &[u8],
+        arena: &SecureArena,
+    ) -> Result<Self, &'static str> {
+        KeccakState::import_midstate(1088, 0x1f, Algorithm::Shake256, blob, key, Some(arena))
+            .map(Self)
+    }
+}
 //
 // SHAKE256 - end
 //
@@ -1,8 +1,8 @@
 use crate::KeccakState;
-use core::ptr::write_volatile;
-use core::sync::atomic::{Ordering, compiler_fence};
-use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeSelect};
-use entlib_native_secure_buffer::SecureBuffer;
+use crate::api::SHA3_256;
+use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeOrd, ConstantTimeSelect};
+use entlib_native_digest::midstate::{self, Algorithm};
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer, SecureZeroize};
 
 const KECCAK_ROUND_CONSTANTS: [u64; 24] = [
     0x0000000000000001,
@@ -41,13 +41,32 @@ const PI_INDICES: [usize; 25] = [
 
 impl KeccakState {
     pub(crate) fn new(rate_bits: usize, domain: u8) -> Self {
-        Self {
+        Self::new_in(rate_bits, domain, None).expect("SecureBuffer allocate failed")
+    }
+
+    /// 내부 버퍼와 다이제스트를 `arena`의 슬롯에서 할당하는 상태를 생성합니다.
+    ///
+    /// 슬롯 크기를 초과하는 XOF 출력은 아레나 대신 개별 잠긴 메모리 블록으로 할당됩니다.
+    pub(crate) fn new_in(
+        rate_bits: usize,
+        domain: u8,
+        arena: Option<&SecureArena>,
+    ) -> Result<Self, &'static str> {
+        Ok(Self {
             state: [0; 25],
             rate_bytes: rate_bits / 8,
-            buffer: SecureBuffer::new_owned(200).expect("SecureBuffer allocate failed"),
+            buffer: SecureBuffer::new_in(200, arena)?,
             buffer_len: 0,
             domain,
-        }
+            arena: arena.cloned(),
+        })
+    }
+
+    /// 흡수된 스펀지 상태와 버퍼를 소거하여 초기 상태로 되돌립니다. 버퍼 할당은 유지됩니다.
+    pub(crate) fn reset(&mut self) {
+        self.state.secure_zeroize();
+        self.buffer.secure_zeroize();
+        self.buffer_len = 0;
     }
 
     /// Keccak-f[1600] 순열(permutation) 함수
@@ -88,12 +107,7 @@ impl KeccakState {
         }
 
         // 임시 상태의 메모리 안전한 소거(zeroization)
-        for item in next_state.iter_mut() {
-            unsafe {
-                write_volatile(item, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
+        next_state.secure_zeroize();
     }
 
     fn process_buffer(&mut self, block: &[u8]) {
@@ -163,7 +177,7 @@ impl KeccakState {
 
         for i in 0..rate {
             // i < len
-            let is_i_less_len = i.ct_is_ge(&len).choice_not();
+            let is_i_less_len = i.ct_is_lt(&len);
             let is_i_eq_len = i.ct_eq(&len);
             let is_i_eq_len_plus_1 = i.ct_eq(&(len + 1));
 
@@ -219,38 +233,23 @@ impl KeccakState {
         }
 
         // 스택에 할당된 임시 패딩 데이터 완전 소거
-        for b in &mut block1 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        for b in &mut block2 {
-            unsafe {
-                write_volatile(b, 0);
-            }
-        }
-        compiler_fence(Ordering::SeqCst);
+        block1.secure_zeroize();
+        block2.secure_zeroize();
 
         self.buffer_len = 0;
     }
 
-    /// 해시 연산 종료 및 다이제스트(digest) 반환
+    /// 해시 연산을 종료하여 `out`의 길이만큼 출력하고 초기 상태로 되돌립니다.
+    ///
+    /// 출력 버퍼를 호출자가 제공하므로 메모리 할당이 발생하지 않습니다.
     ///
     /// # Arguments
     /// - last_byte_bits 마지막 바이트의 유효 비트 수 (0 = 바이트 정렬)
-    pub(crate) fn finalize(
-        mut self,
-        output_len: usize,
-        last_byte_opt: Option<(u8, usize)>,
-    ) -> Result<SecureBuffer, &'static str> {
+    pub(crate) fn finalize_into(&mut self, out: &mut [u8], last_byte_opt: Option<(u8, usize)>) {
+        // 출력 길이는 알고리즘 또는 호출자가 정한 공개 정보
+        let output_len = out.len();
         self.pad(last_byte_opt);
 
-        let mut out_buf = SecureBuffer::new_owned(output_len)?;
-        if output_len == 0 {
-            return Ok(out_buf);
-        }
-
-        let out_slice = out_buf.as_mut_slice();
         let mut out_idx = 0;
         let rate_words = self.rate_bytes / 8;
 
@@ -268,7 +267,7 @@ impl KeccakState {
                 let take = usize::ct_select(&8, &remain, is_ge);
 
                 // 계산될 길이만큼 버퍼에 복사
-                out_slice[out_idx..out_idx + take].copy_from_slice(&word_bytes[..take]);
+                out[out_idx..out_idx + take].copy_from_slice(&word_bytes[..take]);
                 out_idx += take;
             }
 
@@ -277,6 +276,121 @@ impl KeccakState {
                 Self::keccak_f1600(&mut self.state);
             }
         }
+
+        // 스퀴즈가 끝난 상태를 소거하고 다음 메시지를 위해 초기 상태로 복귀
+        self.reset();
+    }
+
+    /// 고정 길이 다이제스트를 `out`에 기록하고 초기 상태로 되돌립니다.
+    pub(crate) fn finalize_fixed_into(
+        &mut self,
+        out: &mut [u8],
+        digest_len: usize,
+    ) -> Result<(), &'static str> {
+        if out.len() != digest_len {
+            return Err("Output buffer length mismatch");
+        }
+        self.finalize_into(out, None);
+        Ok(())
+    }
+
+    /// `output_len` 바이트를 새로 할당한 [`SecureBuffer`]로 반환하고 초기 상태로 되돌립니다.
+    ///
+    /// 할당에 실패하면 상태는 변경되지 않습니다.
+    pub(crate) fn finalize_reset(
+        &mut self,
+        output_len: usize,
+        last_byte_opt: Option<(u8, usize)>,
+    ) -> Result<SecureBuffer, &'static str> {
+        let mut out_buf = SecureBuffer::new_in(output_len, self.arena.as_ref())?;
+        self.finalize_into(out_buf.as_mut_slice(), last_byte_opt);
         Ok(out_buf)
     }
+
+    /// 해시 연산 종료 및 다이제스트(digest) 반환
+    pub(crate) fn finalize(
+        mut self,
+        output_len: usize,
+        last_byte_opt: Option<(u8, usize)>,
+    ) -> Result<SecureBuffer, &'static str> {
+        self.finalize_reset(output_len, last_byte_opt)
+    }
+}
+
+impl KeccakState {
+    /// 중간 상태 본문 길이: 스펀지 상태(200) + 버퍼 길이(1) + rate 크기의 버퍼
+    fn midstate_body_len(rate_bytes: usize) -> usize {
+        200 + 1 + rate_bytes
+    }
+
+    /// 현재 상태를 HMAC-SHA3-256 태그로 인증하여 직렬화합니다.
+    pub(crate) fn export_midstate(
+        &self,
+        algorithm: Algorithm,
+        key: &[u8],
+    ) -> Result<SecureBuffer, &'static str> {
+        midstate::seal::<SHA3_256>(
+            algorithm,
+            key,
+            Self::midstate_body_len(self.rate_bytes),
+            self.arena.as_ref(),
+            |body| {
+                for (chunk, lane) in body[..200].chunks_exact_mut(8).zip(&self.state) {
+                    chunk.copy_from_slice(&lane.to_le_bytes());
+                }
+                body[200] = self.buffer_len as u8;
+                // 버퍼 길이 이후의 이전 블록 잔여 바이트는 내보내지 않음
+                body[201..201 + self.buffer_len]
+                    .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+                body[201 + self.buffer_len..].fill(0);
+            },
+        )
+    }
+
+    /// 직렬화된 상태를 검증하고 복원합니다.
+    pub(crate) fn import_midstate(
+        rate_bits: usize,
+        domain: u8,
+        algorithm: Algorithm,
+        blob: &[u8],
+        key: &[u8],
+        arena: Option<&SecureArena>,
+    ) -> Result<Self, &'static str> {
+        let mut restored = Self::new_in(rate_bits, domain, arena)?;
+        let body_len = Self::midstate_body_len(restored.rate_bytes);
+        let body = midstate::open::<SHA3_256>(algorithm, key, blob, body_len)?;
+
+        let buffer_len = body[200] as usize;
+        if buffer_len >= restored.rate_bytes {
+            return Err("Midstate field out of range");
+        }
+
+        for (lane, chunk) in restored.state.iter_mut().zip(body[..200].chunks_exact(8)) {
+            *lane = u64::from_le_bytes(chunk.try_into().unwrap());
+        }
+        restored.buffer_len = buffer_len;
+        restored.buffer.as_mut_slice()[..buffer_len].copy_from_slice(&body[201..201 + buffer_len]);
+        Ok(restored)
+    }
+}
+
+impl Clone for KeccakState {
+    /// 버퍼를 원본과 같은 아레나(또는 개별 잠긴 메모리 블록)에 새로 할당하여 복제합니다.
+    ///
+    /// # Panics
+    /// 버퍼 할당에 실패한 경우 (`new`와 동일)
+    fn clone(&self) -> Self {
+        let mut buffer =
+            SecureBuffer::new_in(200, self.arena.as_ref()).expect("SecureBuffer allocate failed");
+        buffer.as_mut_slice()[..self.buffer_len]
+            .copy_from_slice(&self.buffer.as_slice()[..self.buffer_len]);
+        Self {
+            state: self.state,
+            rate_bytes: self.rate_bytes,
+            buffer,
+            buffer_len: self.buffer_len,
+            domain: self.domain,
+            arena: self.arena.clone(),
+        }
+    }
 }
@@ -1,30 +1,20 @@
 pub mod api;
 mod keccak;
 
-use core::ptr::write_volatile;
-use core::sync::atomic::{Ordering, compiler_fence};
-use entlib_native_secure_buffer::SecureBuffer;
+use entlib_native_secure_buffer::{SecureArena, SecureBuffer, SecureZeroize, ZeroizeOnDrop};
 
 /// Keccak 스펀지 함수의 내부 상태 구조체(internal state structure)
+///
+/// `Drop` 시점에 스펀지 상태와 흡수 버퍼가 소거됩니다.
+#[derive(SecureZeroize, ZeroizeOnDrop)]
 pub(crate) struct KeccakState {
     pub(crate) state: [u64; 25],
+    #[zeroize(skip)]
     pub(crate) rate_bytes: usize,
     pub(crate) buffer: SecureBuffer, // 200
     pub(crate) buffer_len: usize,
+    #[zeroize(skip)]
     pub(crate) domain: u8,
-}
-
-impl Drop for KeccakState {
-    fn drop(&mut self) {
-        // 내부 상태 배열 및 버퍼 소거(zeroization of internal state and buffer)
-        for i in 0..25 {
-            unsafe {
-                write_volatile(&mut self.state[i], 0);
-            }
-        }
-        unsafe {
-            write_volatile(&mut self.buffer_len, 0);
-        }
-        compiler_fence(Ordering::SeqCst);
-    }
+    #[zeroize(skip)]
+    pub(crate) arena: Option<SecureArena>,
 }
@@ -0,0 +1,154 @@
+use entlib_native_digest::{Digest, ExtendableOutput, Reset};
+use entlib_native_secure_buffer::SecureArena;
+use entlib_native_sha3::api::*;
+
+//
+// 헬퍼
+//
+
+/// 트레이트 경로의 결과 및 상수 일치, 리셋 후 재사용 검증
+fn check_digest<D: Digest + Reset>(expected_abc: &[u8]) {
+    assert_eq!(D::OUTPUT_LEN, expected_abc.len());
+
+    // 오류를 반환하는 생성 경로도 동일한 초기 상태를 가짐
+    let mut h = D::try_new().unwrap();
+    h.update(b"abc");
+    assert_eq!(h.finalize().unwrap().as_slice(), expected_abc);
+
+    // rate 경계를 넘는 데이터를 흡수한 뒤 리셋하면 새 인스턴스와 동일해야 함
+    let mut h = D::new();
+    h.update(&vec![0xA5u8; D::BLOCK_LEN * 2 + 7]);
+    h.reset();
+    h.update(b"abc");
+    assert_eq!(h.finalize().unwrap().as_slice(), expected_abc);
+
+    // 아레나 슬롯에서 할당된 인스턴스도 리셋 후 동일한 슬롯을 재사용
+    let arena = SecureArena::new(256, 8).unwrap();
+    let mut h = D::new_in(&arena).unwrap();
+    h.update(b"discarded");
+    h.reset();
+    assert_eq!(arena.stats().used_slots, 1);
+    h.update(b"abc");
+    let digest = h.finalize().unwrap();
+    assert_eq!(digest.as_slice(), expected_abc);
+    drop(digest);
+    assert_eq!(arena.stats().used_slots, 0);
+}
+
+fn check_xof<X: ExtendableOutput + Reset>(expected_empty: &[u8]) {
+    let out = X::try_new()
+        .unwrap()
+        .finalize_xof(expected_empty.len())
+        .unwrap();
+    assert_eq!(out.as_slice(), expected_empty);
+
+    let mut h = X::new();
+    h.update(&vec![0x5Au8; X::BLOCK_LEN + 1]);
+    h.reset();
+    let out = h.finalize_xof(expected_empty.len()).unwrap();
+    assert_eq!(out.as_slice(), expected_empty);
+}
+
+fn oneshot<D: Digest>(data: &[u8]) -> Vec<u8> {
+    let mut h = D::new();
+    h.update(data);
+    h.finalize().unwrap().as_slice().to_vec()
+}
+
+/// 비소비 종료(`finalize_into`, `finalize_reset`) 후 인스턴스 재사용 및 무할당 검증
+fn check_finalize_reuse<D: Digest>() {
+    let messages: [&[u8]; 3] = [b"abc", b"", &[0x6Bu8; 300]];
+
+    let mut h = D::new();
+    let mut out = vec![0u8; D::OUTPUT_LEN];
+    for msg in messages {
+        h.update(msg);
+        h.finalize_into(&mut out).unwrap();
+        assert_eq!(out, oneshot::<D>(msg));
+
+        h.update(msg);
+        assert_eq!(h.finalize_reset().unwrap().as_slice(), oneshot::<D>(msg));
+    }
+
+    // 출력 길이 불일치는 상태를 변경하지 않고 거부
+    h.update(b"ab");
+    let mut short = vec![0u8; D::OUTPUT_LEN - 1];
+    assert_eq!(
+        h.finalize_into(&mut short),
+        Err("Output buffer length mismatch")
+    );
+    h.update(b"c");
+    h.finalize_into(&mut out).unwrap();
+    assert_eq!(out, oneshot::<D>(b"abc"));
+
+    // 아레나 인스턴스는 반복 종료에도 추가 슬롯을 사용하지 않음
+    let arena = SecureArena::new(256, 4).unwrap();
+    let mut h = D::new_in(&arena).unwrap();
+    for i in 0..64u8 {
+        h.update(&[i]);
+        h.finalize_into(&mut out).unwrap();
+        assert_eq!(out, oneshot::<D>(&[i]));
+    }
+    assert_eq!(arena.stats().used_slots, 1);
+}
+
+/// XOF 비소비 종료 후 인스턴스 재사용 검증 (출력 길이는 호출마다 다를 수 있음)
+fn check_xof_reuse<X: ExtendableOutput>() {
+    let oneshot_xof = |msg: &[u8], len: usize| {
+        let mut h = X::new();
+        h.update(msg);
+        h.finalize_xof(len).unwrap().as_slice().to_vec()
+    };
+
+    let mut h = X::new();
+    for (msg, len) in [
+        (&b"abc"[..], 16),
+        (b"", 0),
+        (&[0x6Bu8; 300], X::BLOCK_LEN + 9),
+    ] {
+        let mut out = vec![0u8; len];
+        h.update(msg);
+        h.finalize_xof_into(&mut out);
+        assert_eq!(out, oneshot_xof(msg, len));
+
+        h.update(msg);
+        assert_eq!(
+            h.finalize_xof_reset(len).unwrap().as_slice(),
+            oneshot_xof(msg, len)
+        );
+    }
+}
+
+#[test]
+fn sha3_digest_trait_matches_fips202_vectors() {
+    check_digest::<SHA3_224>(b"\xe6\x42\x82\x4c\x3f\x8c\xf2\x4a\xd0\x92\x34\xee\x7d\x3c\x76\x6f\xc9\xa3\xa5\x16\x8d\x0c\x94\xad\x73\xb4\x6f\xdf");
+    check_digest::<SHA3_256>(b"\x3a\x98\x5d\xa7\x4f\xe2\x25\xb2\x04\x5c\x17\x2d\x6b\xd3\x90\xbd\x85\x5f\x08\x6e\x3e\x9d\x52\x5b\x46\xbf\xe2\x45\x11\x43\x15\x32");
+    check_digest::<SHA3_384>(b"\xec\x01\x49\x82\x88\x51\x6f\xc9\x26\x45\x9f\x58\xe2\xc6\xad\x8d\xf9\xb4\x73\xcb\x0f\xc0\x8c\x25\x96\xda\x7c\xf0\xe4\x9b\xe4\xb2\x98\xd8\x8c\xea\x92\x7a\xc7\xf5\x39\xf1\xed\xf2\x28\x37\x6d\x25");
+    check_digest::<SHA3_512>(b"\xb7\x51\x85\x0b\x1a\x57\x16\x8a\x56\x93\xcd\x92\x4b\x6b\x09\x6e\x08\xf6\x21\x82\x74\x44\xf7\x0d\x88\x4f\x5d\x02\x40\xd2\x71\x2e\x10\xe1\x16\xe9\x19\x2a\xf3\xc9\x1a\x7e\xc5\x76\x47\xe3\x93\x40\x57\x34\x0b\x4c\xf4\x08\xd5\xa5\x65\x92\xf8\x27\x4e\xec\x53\xf0");
+}
+
+#[test]
+fn shake_extendable_output_trait_matches_fips202_vectors() {
+    check_xof::<SHAKE128>(b"\x7f\x9c\x2b\xa4\xe8\x8f\x82\x7d\x61\x60\x45\x50\x76\x05\x85\x3e\xd7\x3b\x80\x93\xf6\xef\xbc\x88\xeb\x1a\x6e\xac\xfa\x66\xef\x26");
+    check_xof::<SHAKE256>(b"\x46\xb9\xdd\x2b\x0b\xa8\x8d\x13\x23\x3b\x3f\xeb\x74\x3e\xeb\x24\x3f\xcd\x52\xea\x62\xb8\x1b\x82\xb5\x0c\x27\x64\x6e\xd5\x76\x2f");
+}
+
+#[test]
+fn sha3_block_lengths_equal_rate() {
+    assert_eq!(<SHA3_224 as Digest>::BLOCK_LEN, 144);
+    assert_eq!(<SHA3_256 as Digest>::BLOCK_LEN, 136);
+    assert_eq!(<SHA3_384 as Digest>::BLOCK_LEN, 104);
+    assert_eq!(<SHA3_512 as Digest>::BLOCK_LEN, 72);
+    assert_eq!(<SHAKE128 as ExtendableOutput>::BLOCK_LEN, 168);
+    assert_eq!(<SHAKE256 as ExtendableOutput>::BLOCK_LEN, 136);
+}
+
+#[test]
+fn sha3_finalize_into_and_reset_reuse_instance() {
+    check_finalize_reuse::<SHA3_224>();
+    check_finalize_reuse::<SHA3_256>();
+    check_finalize_reuse::<SHA3_384>();
+    check_finalize_reuse::<SHA3_512>();
+    check_xof_reuse::<SHAKE128>();
+    check_xof_reuse::<SHAKE256>();
+}
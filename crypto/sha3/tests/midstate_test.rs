@@ -0,0 +1,114 @@
+use entlib_native_digest::{Digest, ExtendableOutput, Midstate, midstate};
+use entlib_native_secure_buffer::SecureArena;
+use entlib_native_sha3::api::*;
+
+const KEY: [u8; 32] = [0x42; 32];
+
+//
+// 헬퍼
+//
+
+fn oneshot<D: Digest>(data: &[u8]) -> Vec<u8> {
+    let mut h = D::new();
+    h.update(data);
+    h.finalize().unwrap().as_slice().to_vec()
+}
+
+fn oneshot_xof<X: ExtendableOutput>(data: &[u8], output_len: usize) -> Vec<u8> {
+    let mut h = X::new();
+    h.update(data);
+    h.finalize_xof(output_len).unwrap().as_slice().to_vec()
+}
+
+/// 접두사를 흡수한 상태의 복제, 내보내기/복원 후 이어서 해싱한 결과가 단일 해싱과 일치하는지 검증
+fn check_midstate<D: Digest + Midstate + Clone>() {
+    let prefix = vec![0x3Cu8; D::BLOCK_LEN + 5];
+    let full = |suffix: &[u8]| oneshot::<D>(&[prefix.as_slice(), suffix].concat());
+
+    let mut base = D::new();
+    base.update(&prefix);
+
+    // 복제본과 원본은 서로 독립적으로 진행
+    let mut cloned = base.clone();
+    cloned.update(b"left");
+    assert_eq!(cloned.finalize().unwrap().as_slice(), full(b"left"));
+
+    let blob = base.export_midstate(&KEY).unwrap();
+    base.update(b"right");
+    assert_eq!(base.finalize().unwrap().as_slice(), full(b"right"));
+
+    let mut restored = D::import_midstate(blob.as_slice(), &KEY).unwrap();
+    restored.update(b"restored");
+    assert_eq!(restored.finalize().unwrap().as_slice(), full(b"restored"));
+
+    // 아레나에서 복원한 인스턴스와 그 복제본은 같은 아레나의 슬롯을 사용
+    let arena = SecureArena::new(256, 8).unwrap();
+    let mut restored = D::import_midstate_in(blob.as_slice(), &KEY, &arena).unwrap();
+    let twin = restored.clone();
+    assert_eq!(arena.stats().used_slots, 2);
+    drop(twin);
+    restored.update(b"arena");
+    assert_eq!(restored.finalize().unwrap().as_slice(), full(b"arena"));
+}
+
+fn check_xof_midstate<X: ExtendableOutput + Midstate + Clone>() {
+    let prefix = vec![0x3Cu8; X::BLOCK_LEN + 5];
+    let full = |suffix: &[u8]| oneshot_xof::<X>(&[prefix.as_slice(), suffix].concat(), 64);
+
+    let mut base = X::new();
+    base.update(&prefix);
+
+    let mut cloned = base.clone();
+    cloned.update(b"left");
+    assert_eq!(cloned.finalize_xof(64).unwrap().as_slice(), full(b"left"));
+
+    let blob = base.export_midstate(&KEY).unwrap();
+    let mut restored = X::import_midstate(blob.as_slice(), &KEY).unwrap();
+    restored.update(b"restored");
+    assert_eq!(
+        restored.finalize_xof(64).unwrap().as_slice(),
+        full(b"restored")
+    );
+}
+
+#[test]
+fn clone_and_midstate_resume_matches_oneshot() {
+    check_midstate::<SHA3_224>();
+    check_midstate::<SHA3_256>();
+    check_midstate::<SHA3_384>();
+    check_midstate::<SHA3_512>();
+    check_xof_midstate::<SHAKE128>();
+    check_xof_midstate::<SHAKE256>();
+}
+
+/// 잘못된 키 및 변조 거부
+#[test]
+fn midstate_rejects_tampering() {
+    let mut h = SHA3_256::new();
+    h.update(b"prefix");
+    let blob = h.export_midstate(&KEY).unwrap();
+    let bytes = blob.as_slice();
+
+    assert_eq!(
+        SHA3_256::import_midstate(bytes, &[0x43; 32]).err(),
+        Some("Midstate authentication failed")
+    );
+    for index in [midstate::HEADER_LEN, bytes.len() - 1] {
+        let mut tampered = bytes.to_vec();
+        tampered[index] ^= 0x01;
+        assert_eq!(
+            SHA3_256::import_midstate(&tampered, &KEY).err(),
+            Some("Midstate authentication failed")
+        );
+    }
+}
+
+/// rate가 같은 SHA3-256과 SHAKE256 사이의 복원 거부 (도메인 분리 바이트가 다름)
+#[test]
+fn midstate_rejects_algorithm_mismatch() {
+    let blob = SHA3_256::new().export_midstate(&KEY).unwrap();
+    assert_eq!(
+        SHAKE256::import_midstate(blob.as_slice(), &KEY).err(),
+        Some("Midstate algorithm mismatch")
+    );
+}
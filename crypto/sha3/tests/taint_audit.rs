@@ -0,0 +1,62 @@
+//! Valgrind Memcheck 오염 추적 감사입니다.
+//!
+//! ```bash
+//! $ cargo valgrind test -p entlib-native-sha3 --test taint_audit --features valgrind_taint_audit
+//! ```
+#![cfg(target_os = "linux")]
+#![cfg(feature = "valgrind_taint_audit")]
+
+use entlib_native_sha3::api::{SHA3_256, SHAKE128};
+use entlib_native_test_support::taint::{classify_secret, declassify};
+
+#[test]
+fn audit_taint_flow_sha3_256_known_answer() {
+    let message = *b"abc";
+    classify_secret(&message);
+    let mut hasher = SHA3_256::new();
+    hasher.update(&message);
+    let digest = hasher.finalize().unwrap();
+    declassify(digest.as_slice());
+    assert_eq!(
+        digest.as_slice(),
+        &[
+            0x3a, 0x98, 0x5d, 0xa7, 0x4f, 0xe2, 0x25, 0xb2, 0x04, 0x5c, 0x17, 0x2d, 0x6b, 0xd3,
+            0x90, 0xbd, 0x85, 0x5f, 0x08, 0x6e, 0x3e, 0x9d, 0x52, 0x5b, 0x46, 0xbf, 0xe2, 0x45,
+            0x11, 0x43, 0x15, 0x32,
+        ]
+    );
+}
+
+#[test]
+fn audit_taint_flow_sha3_256_multi_block() {
+    // rate(136바이트)를 넘는 비밀 메시지
+    let message: [u8; 300] = core::array::from_fn(|i| (i as u8) ^ 0x5A);
+    classify_secret(&message);
+    let mut hasher = SHA3_256::new();
+    hasher.update(&message[..137]);
+    hasher.update(&message[137..]);
+    let digest = hasher.finalize().unwrap();
+    declassify(digest.as_slice());
+    declassify(&message);
+
+    let mut reference = SHA3_256::new();
+    reference.update(&message);
+    assert_eq!(digest.as_slice(), reference.finalize().unwrap().as_slice());
+}
+
+#[test]
+fn audit_taint_flow_shake128_squeeze() {
+    let seed = [0xA5u8; 32];
+    classify_secret(&seed);
+    let mut xof = SHAKE128::new();
+    xof.update(&seed);
+    // rate(168바이트)를 넘는 출력으로 여러 번의 순열 수행
+    let output = xof.finalize(400).unwrap();
+    declassify(output.as_slice());
+    declassify(&seed);
+
+    let mut reference = SHAKE128::new();
+    reference.update(&seed);
+    let prefix = reference.finalize(32).unwrap();
+    assert_eq!(&output.as_slice()[..32], prefix.as_slice());
+}
@@ -0,0 +1,88 @@
+//! 기성 대상 전체(또는 일부)에 대해 dudect 검정을 수행하고 JSON 보고서를 출력합니다.
+//!
+//! 측정 잡음을 줄이기 위해 가능하면 CPU 주파수를 고정한 베어메탈에서 실행해야 합니다.
+//! ```bash
+//! $ cargo bench -p entlib-native-dudect --bench dudect_suite -- --samples 1000000 --output dudect.json
+//! ```
+//!
+//! 종료 코드는 모든 대상이 통과하면 0, 누출이 판정되면 1, 인자 오류이면 2입니다.
+
+use entlib_native_dudect::targets::{self, TargetFn};
+use entlib_native_dudect::{Config, Report};
+use std::process::ExitCode;
+
+const USAGE: &str = "usage: dudect_suite [--samples N] [--batch N] [--threshold T] [--seed N] \
+[--no-abort] [--target NAME]... [--output PATH] [--list]";
+
+fn main() -> ExitCode {
+    match run() {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::from(1),
+        Err(msg) => {
+            eprintln!("error: {msg}\n{USAGE}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn run() -> Result<bool, String> {
+    let mut config = Config::default();
+    let mut selected: Vec<(&'static str, TargetFn)> = Vec::new();
+    let mut output = None;
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = |flag: &str| args.next().ok_or(format!("missing value for {flag}"));
+        match arg.as_str() {
+            "--samples" => config.samples = parse(&value("--samples")?)?,
+            "--batch" => config.batch_size = parse(&value("--batch")?)?,
+            "--threshold" => config.threshold = parse(&value("--threshold")?)?,
+            "--seed" => config.seed = parse(&value("--seed")?)?,
+            "--no-abort" => config.early_abort = false,
+            // `cargo bench`가 자동으로 덧붙이는 인자
+            "--bench" => {}
+            "--output" => output = Some(value("--output")?),
+            "--target" => {
+                let name = value("--target")?;
+                selected.push(targets::find(&name).ok_or(format!("unknown target `{name}`"))?);
+            }
+            "--list" => {
+                for (name, _) in targets::ALL {
+                    println!("{name}");
+                }
+                return Ok(true);
+            }
+            other => return Err(format!("unknown argument `{other}`")),
+        }
+    }
+    if config.batch_size == 0 {
+        return Err("--batch must be non-zero".into());
+    }
+    if selected.is_empty() {
+        selected.extend_from_slice(targets::ALL);
+    }
+
+    let mut report = Report::new(&config);
+    for (name, target) in selected {
+        eprint!("[dudect] {name} ... ");
+        let result = target(&config);
+        eprintln!(
+            "{} (max |t| = {:.2}{})",
+            result.verdict.as_str(),
+            result.max_t,
+            if result.aborted { ", aborted" } else { "" }
+        );
+        report.push(result);
+    }
+
+    let json = report.to_json();
+    match output {
+        Some(path) => std::fs::write(&path, &json).map_err(|e| format!("{path}: {e}"))?,
+        None => print!("{json}"),
+    }
+    Ok(report.is_pass())
+}
+
+fn parse<T: std::str::FromStr>(s: &str) -> Result<T, String> {
+    s.parse().map_err(|_| format!("invalid number `{s}`"))
+}
@@ -0,0 +1,187 @@
+//! 고정/무작위 클래스 측정 루프입니다.
+//!
+//! 측정은 배치 단위로 진행됩니다. 각 배치는 (1) 클래스를 무작위로 배정하고 입력을 미리 생성한 뒤,
+//! (2) 입력 생성 비용이 섞이지 않도록 대상 클로저의 실행 시간만 연속으로 측정합니다.
+//! 첫 배치는 워밍업 및 절단(Crop) 임계값 산출에만 사용되고 통계에는 포함되지 않습니다.
+
+use crate::report::{TargetReport, Verdict};
+use crate::rng::SplitMix64;
+use crate::stats::WelchTTest;
+use crate::timer;
+
+/// 절단 검정에 사용하는 백분위수 목록입니다.
+///
+/// 인터럽트나 문맥 교환으로 생긴 긴 꼬리(Tail)는 t 통계량을 희석시키므로, 원본 분포에 대한 검정과
+/// 함께 각 백분위수 이하의 측정값만으로 구성한 분포에 대해서도 별도의 검정을 수행합니다.
+pub const CROP_PERCENTILES: [f64; 5] = [0.50, 0.75, 0.90, 0.95, 0.99];
+
+/// 기본 t 통계량 임계값 (dudect 기준 "명백한 누출")
+pub const DEFAULT_THRESHOLD: f64 = 4.5;
+
+/// 측정 입력의 클래스
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Class {
+    /// 모든 측정에서 동일한 입력
+    Fixed,
+    /// 측정마다 새로 생성한 무작위 입력
+    Random,
+}
+
+impl Class {
+    #[inline(always)]
+    pub(crate) const fn index(self) -> usize {
+        match self {
+            Class::Fixed => 0,
+            Class::Random => 1,
+        }
+    }
+}
+
+/// 하니스 측정 설정
+#[derive(Clone, Debug)]
+pub struct Config {
+    /// 통계에 반영할 총 측정 횟수 (두 클래스 합계, 워밍업 배치 제외)
+    pub samples: usize,
+    /// 배치당 측정 횟수. 조기 중단 판정은 배치 경계마다 수행됩니다.
+    pub batch_size: usize,
+    /// 누출로 판정하는 |t| 임계값
+    pub threshold: f64,
+    /// `|t| > threshold`가 관측되면 남은 배치를 생략하고 즉시 중단할지 여부
+    pub early_abort: bool,
+    /// 입력 생성 및 클래스 배정에 사용하는 시드
+    pub seed: u64,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            samples: 1_000_000,
+            batch_size: 10_000,
+            threshold: DEFAULT_THRESHOLD,
+            early_abort: true,
+            seed: 0x656E_746C_6962,
+        }
+    }
+}
+
+/// 임의의 클로저에 대해 고정/무작위 클래스 t-검정을 수행합니다.
+///
+/// - `prepare`는 측정 전에 클래스별 입력을 생성합니다. 실행 시간은 측정되지 않습니다.
+/// - `run`은 측정 대상 연산이며, 입력을 참조로 받아 실행됩니다. 반환값이 없으므로
+///   결과가 최적화로 제거되지 않도록 `core::hint::black_box`를 사용해야 합니다.
+///
+/// # Panics
+/// `config.batch_size`가 0이면 패닉이 발생합니다.
+pub fn measure<I, P, R>(name: &str, config: &Config, mut prepare: P, mut run: R) -> TargetReport
+where
+    P: FnMut(Class, &mut SplitMix64) -> I,
+    R: FnMut(&I),
+{
+    assert!(config.batch_size > 0, "dudect batch size must be non-zero");
+
+    let mut rng = SplitMix64::new(config.seed);
+    let mut inputs = Vec::with_capacity(config.batch_size);
+    let mut timings = Vec::with_capacity(config.batch_size);
+
+    // 워밍업 배치: 절단 임계값 산출
+    run_batch(
+        config.batch_size,
+        &mut rng,
+        &mut prepare,
+        &mut run,
+        &mut inputs,
+        &mut timings,
+    );
+    let mut sorted: Vec<u64> = timings.iter().map(|&(_, t)| t).collect();
+    sorted.sort_unstable();
+    let cutoffs = CROP_PERCENTILES.map(|p| sorted[((sorted.len() - 1) as f64 * p) as usize]);
+
+    let mut tests = [WelchTTest::new(); 1 + CROP_PERCENTILES.len()];
+    let mut remaining = config.samples;
+    let mut aborted = false;
+
+    while remaining > 0 {
+        let n = remaining.min(config.batch_size);
+        run_batch(
+            n,
+            &mut rng,
+            &mut prepare,
+            &mut run,
+            &mut inputs,
+            &mut timings,
+        );
+        remaining -= n;
+
+        for &(class, t) in &timings {
+            tests[0].push(class, t as f64);
+            for (test, &cutoff) in tests[1..].iter_mut().zip(&cutoffs) {
+                if t <= cutoff {
+                    test.push(class, t as f64);
+                }
+            }
+        }
+
+        if config.early_abort && remaining > 0 && max_abs_t(&tests).1 > config.threshold {
+            aborted = true;
+            break;
+        }
+    }
+
+    let (index, max_t) = max_abs_t(&tests);
+    TargetReport {
+        name: name.into(),
+        verdict: if max_t > config.threshold {
+            Verdict::Leak
+        } else {
+            Verdict::Pass
+        },
+        max_t,
+        crop: index.checked_sub(1).map(|i| CROP_PERCENTILES[i]),
+        fixed_samples: tests[0].count(Class::Fixed),
+        random_samples: tests[0].count(Class::Random),
+        aborted,
+    }
+}
+
+/// `n`개의 입력을 생성한 뒤 각각의 실행 시간을 `timings`에 기록합니다.
+fn run_batch<I, P, R>(
+    n: usize,
+    rng: &mut SplitMix64,
+    prepare: &mut P,
+    run: &mut R,
+    inputs: &mut Vec<(Class, I)>,
+    timings: &mut Vec<(Class, u64)>,
+) where
+    P: FnMut(Class, &mut SplitMix64) -> I,
+    R: FnMut(&I),
+{
+    inputs.clear();
+    for _ in 0..n {
+        let class = if rng.next_u64() & 1 == 0 {
+            Class::Fixed
+        } else {
+            Class::Random
+        };
+        inputs.push((class, prepare(class, rng)));
+    }
+
+    timings.clear();
+    for (class, input) in inputs.iter() {
+        let start = timer::now();
+        run(core::hint::black_box(input));
+        let end = timer::now();
+        timings.push((*class, end.wrapping_sub(start)));
+    }
+}
+
+/// 가장 큰 |t|를 갖는 검정의 인덱스와 그 값을 반환합니다.
+fn max_abs_t(tests: &[WelchTTest]) -> (usize, f64) {
+    tests
+        .iter()
+        .map(|test| test.t().abs())
+        .enumerate()
+        .fold(
+            (0, 0.0),
+            |best, (i, t)| if t > best.1 { (i, t) } else { best },
+        )
+}
@@ -0,0 +1,39 @@
+//! dudect 방식의 통계적 타이밍 누출 검증 하니스입니다.
+//!
+//! 고정(Fixed) 입력과 무작위(Random) 입력 두 클래스의 실행 시간 분포를 Welch t-검정으로 비교하여,
+//! 비밀 값에 따라 실행 시간이 달라지는 구현을 탐지합니다. 임의의 클로저를 대상으로 삼을 수 있으며,
+//! HMAC 검증, Base64 디코딩, HKDF 확장, Keccak 및 SHA-2 압축 함수에 대한 기성 대상은
+//! [`targets`] 모듈에 정의되어 있습니다.
+//!
+//! 측정 결과는 [`Report::to_json`]으로 키 순서가 고정된 JSON을 생성하므로, CI에서 기준 보고서와
+//! 직접 비교(diff)할 수 있습니다.
+//!
+//! # Examples
+//! ```
+//! use entlib_native_dudect::{Class, Config, measure};
+//!
+//! let config = Config { samples: 2_000, batch_size: 1_000, ..Config::default() };
+//! let report = measure(
+//!     "xor_fold",
+//!     &config,
+//!     |class, rng| match class {
+//!         Class::Fixed => 0u64,
+//!         Class::Random => rng.next_u64(),
+//!     },
+//!     |x| {
+//!         core::hint::black_box(x.count_ones());
+//!     },
+//! );
+//! assert_eq!(report.fixed_samples + report.random_samples, 2_000);
+//! ```
+
+pub mod harness;
+pub mod report;
+pub mod rng;
+pub mod stats;
+pub mod targets;
+
+mod timer;
+
+pub use harness::{Class, Config, measure};
+pub use report::{Report, TargetReport, Verdict};
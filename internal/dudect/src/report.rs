@@ -0,0 +1,138 @@
+//! 측정 결과 보고서입니다.
+//!
+//! [`Report::to_json`]은 키 순서와 실수 자릿수가 고정된 JSON을 생성합니다. 대상 하나가
+//! 한 줄을 차지하므로, CI는 기준 보고서와의 `diff`로 판정 변화를 대상 단위로 확인할 수 있습니다.
+
+use crate::harness::Config;
+use core::fmt::Write;
+
+/// 대상 하나에 대한 판정
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    /// 모든 검정에서 |t|가 임계값 이하
+    Pass,
+    /// 하나 이상의 검정에서 |t|가 임계값 초과
+    Leak,
+}
+
+impl Verdict {
+    /// 보고서에 기록되는 소문자 문자열을 반환합니다.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Pass => "pass",
+            Verdict::Leak => "leak",
+        }
+    }
+}
+
+/// 대상 하나에 대한 측정 결과
+#[derive(Clone, Debug)]
+pub struct TargetReport {
+    /// 대상 이름
+    pub name: String,
+    /// 판정
+    pub verdict: Verdict,
+    /// 모든 검정 중 가장 큰 |t|
+    pub max_t: f64,
+    /// `max_t`가 관측된 절단 백분위수 (`None`이면 절단하지 않은 원본 분포)
+    pub crop: Option<f64>,
+    /// 고정 클래스 측정 횟수
+    pub fixed_samples: u64,
+    /// 무작위 클래스 측정 횟수
+    pub random_samples: u64,
+    /// 임계값 초과로 조기 중단되었는지 여부
+    pub aborted: bool,
+}
+
+/// 여러 대상의 측정 결과를 모은 보고서
+#[derive(Clone, Debug)]
+pub struct Report {
+    threshold: f64,
+    samples: usize,
+    seed: u64,
+    targets: Vec<TargetReport>,
+}
+
+impl Report {
+    /// `config`로 측정한 결과를 담을 빈 보고서를 생성합니다.
+    pub fn new(config: &Config) -> Self {
+        Self {
+            threshold: config.threshold,
+            samples: config.samples,
+            seed: config.seed,
+            targets: Vec::new(),
+        }
+    }
+
+    /// 대상의 측정 결과를 추가합니다.
+    pub fn push(&mut self, target: TargetReport) {
+        self.targets.push(target);
+    }
+
+    /// 추가된 대상의 측정 결과를 반환합니다.
+    pub fn targets(&self) -> &[TargetReport] {
+        &self.targets
+    }
+
+    /// 모든 대상이 [`Verdict::Pass`]이면 `true`를 반환합니다.
+    pub fn is_pass(&self) -> bool {
+        self.targets.iter().all(|t| t.verdict == Verdict::Pass)
+    }
+
+    /// 보고서를 JSON 문자열로 직렬화합니다.
+    ///
+    /// 실수는 소수점 둘째 자리까지 기록하며, 대상은 추가된 순서대로 한 줄에 하나씩 나열됩니다.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        out.push_str("{\n");
+        let _ = writeln!(out, "  \"threshold\": {:.2},", self.threshold);
+        let _ = writeln!(out, "  \"samples\": {},", self.samples);
+        let _ = writeln!(out, "  \"seed\": {},", self.seed);
+        let _ = writeln!(out, "  \"pass\": {},", self.is_pass());
+        out.push_str("  \"targets\": [\n");
+        for (i, t) in self.targets.iter().enumerate() {
+            out.push_str("    {\"name\": ");
+            push_json_str(&mut out, &t.name);
+            let _ = write!(
+                out,
+                ", \"verdict\": \"{}\", \"max_t\": {:.2}, \"crop\": ",
+                t.verdict.as_str(),
+                t.max_t
+            );
+            match t.crop {
+                Some(p) => {
+                    let _ = write!(out, "\"p{}\"", (p * 100.0).round() as u32);
+                }
+                None => out.push_str("null"),
+            }
+            let _ = write!(
+                out,
+                ", \"fixed\": {}, \"random\": {}, \"aborted\": {}}}",
+                t.fixed_samples, t.random_samples, t.aborted
+            );
+            out.push_str(if i + 1 < self.targets.len() {
+                ",\n"
+            } else {
+                "\n"
+            });
+        }
+        out.push_str("  ]\n}\n");
+        out
+    }
+}
+
+/// JSON 문자열 리터럴로 이스케이프하여 기록합니다.
+fn push_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
@@ -0,0 +1,40 @@
+//! 입력 생성용 결정적 의사난수 생성기입니다.
+//!
+//! 측정 입력은 비밀 값이 아니며 재현 가능해야 하므로, 시드 하나로 전체 실행을 재현할 수 있는
+//! SplitMix64를 사용합니다. 암호학적 난수가 필요한 용도에는 사용해서는 안 됩니다.
+
+/// SplitMix64 생성기
+#[derive(Clone, Debug)]
+pub struct SplitMix64(u64);
+
+impl SplitMix64 {
+    /// 주어진 시드로 생성기를 초기화합니다.
+    pub const fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// 다음 64비트 값을 반환합니다.
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// `dst`를 의사난수 바이트로 채웁니다.
+    pub fn fill_bytes(&mut self, dst: &mut [u8]) {
+        for chunk in dst.chunks_mut(8) {
+            let word = self.next_u64().to_le_bytes();
+            chunk.copy_from_slice(&word[..chunk.len()]);
+        }
+    }
+
+    /// `[0, bound)` 범위의 값을 반환합니다. 모듈로 편향은 입력 생성 용도에서 무시합니다.
+    ///
+    /// # Panics
+    /// `bound`가 0이면 패닉이 발생합니다.
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
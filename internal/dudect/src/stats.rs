@@ -0,0 +1,68 @@
+//! 온라인 Welch t-검정 누산기입니다.
+//!
+//! 측정값을 저장하지 않고 Welford 알고리즘으로 클래스별 평균과 분산을 갱신하므로,
+//! 표본 수와 무관하게 고정된 메모리로 동작합니다.
+
+use crate::harness::Class;
+
+/// 두 클래스에 대한 Welch t-검정 누산기
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WelchTTest {
+    count: [f64; 2],
+    mean: [f64; 2],
+    m2: [f64; 2],
+}
+
+impl WelchTTest {
+    /// 빈 누산기를 생성합니다.
+    pub const fn new() -> Self {
+        Self {
+            count: [0.0; 2],
+            mean: [0.0; 2],
+            m2: [0.0; 2],
+        }
+    }
+
+    /// `class`에 측정값 `x`를 추가합니다.
+    pub fn push(&mut self, class: Class, x: f64) {
+        let i = class.index();
+        self.count[i] += 1.0;
+        let delta = x - self.mean[i];
+        self.mean[i] += delta / self.count[i];
+        self.m2[i] += delta * (x - self.mean[i]);
+    }
+
+    /// `class`에 누적된 측정값 개수를 반환합니다.
+    pub fn count(&self, class: Class) -> u64 {
+        self.count[class.index()] as u64
+    }
+
+    /// `class`의 표본 평균을 반환합니다.
+    pub fn mean(&self, class: Class) -> f64 {
+        self.mean[class.index()]
+    }
+
+    /// `class`의 표본 분산(불편 추정량)을 반환합니다. 표본이 2개 미만이면 0입니다.
+    pub fn variance(&self, class: Class) -> f64 {
+        let i = class.index();
+        if self.count[i] < 2.0 {
+            return 0.0;
+        }
+        self.m2[i] / (self.count[i] - 1.0)
+    }
+
+    /// Welch t 통계량 `(μ_F - μ_R) / sqrt(s²_F/n_F + s²_R/n_R)`를 반환합니다.
+    ///
+    /// 어느 한 클래스의 표본이 2개 미만이거나 두 분산이 모두 0이면 0을 반환합니다.
+    pub fn t(&self) -> f64 {
+        if self.count[0] < 2.0 || self.count[1] < 2.0 {
+            return 0.0;
+        }
+        let se = self.variance(Class::Fixed) / self.count[0]
+            + self.variance(Class::Random) / self.count[1];
+        if se <= 0.0 {
+            return 0.0;
+        }
+        (self.mean[0] - self.mean[1]) / se.sqrt()
+    }
+}
@@ -0,0 +1,156 @@
+//! 크레이트 전반의 비밀 의존 연산에 대한 기성 측정 대상입니다.
+//!
+//! 각 대상은 [`Config`]를 받아 [`TargetReport`]를 반환하는 함수이며, [`ALL`]에 실행 순서대로
+//! 등록되어 있습니다.
+//!
+//! 측정 입력은 모두 일반 바이트 배열로 준비합니다. `SecureBuffer` 기반 입력을 측정마다 미리
+//! 할당하면 OS 메모리 잠금 한도를 초과하고, 소수의 버퍼를 재사용하면 버퍼(페이지)마다 다른
+//! 캐시 배치가 클래스와 상관되어 상수-시간 구현도 누출로 판정됩니다. 따라서 보안 버퍼가 필요한
+//! 대상은 버퍼 하나를 두 클래스가 공유하고, 측정 구간 안에서 입력을 복사합니다.
+//! 고정 길이 복사는 데이터와 무관한 비용이므로 t 값에 영향을 주지 않습니다.
+//!
+//! 해시 및 HMAC 대상은 인스턴스를 측정 구간 밖에서 [`SecureArena`] 슬롯에 한 번만 생성하고,
+//! 측정 구간에서는 초기화(`reset`) 또는 키 적용된 인스턴스의 복제 후 갱신/완료만 수행합니다.
+//! 측정마다 새 인스턴스를 할당하면 `mlock`/`munlock` 시스템 호출과 캐시 라인 플러시 비용이
+//! 압축 함수의 실행 시간을 압도하여 검정이 무의미해집니다.
+
+use crate::harness::{Class, Config, measure};
+use crate::report::TargetReport;
+use crate::rng::SplitMix64;
+use core::hint::black_box;
+use entlib_native_digest::Reset;
+use entlib_native_hkdf::HKDFSHA256;
+use entlib_native_hmac::{HMACSHA256, MacResult};
+use entlib_native_secure_buffer::{SecretArray, SecretKey, SecureArena, SecureBuffer};
+use entlib_native_sha2::api::{SHA256, SHA512};
+use entlib_native_sha3::api::SHA3_256;
+
+/// 기성 대상 함수의 형식
+pub type TargetFn = fn(&Config) -> TargetReport;
+
+/// 모든 기성 대상 (이름, 함수)
+pub const ALL: &[(&str, TargetFn)] = &[
+    ("hmac_sha256_verify", hmac_sha256_verify),
+    ("base64_decode", base64_decode),
+    ("hkdf_sha256_expand", hkdf_sha256_expand),
+    ("keccak_f1600", keccak_f1600),
+    ("sha256_compress", sha256_compress),
+    ("sha512_compress", sha512_compress),
+];
+
+/// 이름으로 기성 대상을 찾습니다.
+pub fn find(name: &str) -> Option<(&'static str, TargetFn)> {
+    ALL.iter().find(|(n, _)| *n == name).copied()
+}
+
+/// 고정 클래스는 0 블록, 무작위 클래스는 임의 블록을 생성합니다.
+fn block<const N: usize>(class: Class, rng: &mut SplitMix64) -> [u8; N] {
+    let mut block = [0u8; N];
+    if class == Class::Random {
+        rng.fill_bytes(&mut block);
+    }
+    block
+}
+
+const HMAC_KEY: [u8; 32] = [0x0B; 32];
+
+/// 측정 대상 인스턴스용 보안 아레나 (해시 상태, 복제된 HMAC 및 MAC 결과를 수용)
+fn target_arena() -> SecureArena {
+    SecureArena::new(256, 8).expect("arena allocation failed")
+}
+
+/// 키가 적용된 HMAC 인스턴스를 복제하여 MAC을 계산합니다. (키 처리 및 할당 시스템 호출 없음)
+fn hmac_sha256(keyed: &HMACSHA256, message: &[u8]) -> MacResult {
+    let mut hmac = keyed.clone();
+    hmac.update(message);
+    hmac.finalize().expect("HMAC computation failed")
+}
+
+/// HMAC-SHA256 태그 검증 (MAC 재계산 후 `MacResult::eq`)
+///
+/// 수신 메시지의 MAC을 재계산하여 기대 태그와 비교합니다. 고정 클래스는 기대 태그를 만든 메시지
+/// (일치), 무작위 클래스는 임의 메시지(불일치)입니다. 조기 반환(Early-Exit) 비교라면 일치하는
+/// 경우가 더 오래 걸리므로 누출로 판정됩니다.
+pub fn hmac_sha256_verify(config: &Config) -> TargetReport {
+    let arena = target_arena();
+    let keyed = HMACSHA256::new_in(&HMAC_KEY, &arena).expect("HMAC key setup failed");
+    let expected = hmac_sha256(&keyed, &[0u8; 64]);
+    measure("hmac_sha256_verify", config, block::<64>, |message| {
+        black_box(hmac_sha256(&keyed, black_box(message)) == expected);
+    })
+}
+
+/// Base64 디코딩 (`entlib_native_base64::decode`)
+///
+/// 고정 클래스는 0 바이트열의 인코딩(`AAAA...`), 무작위 클래스는 임의 48바이트의 인코딩을 디코딩합니다.
+pub fn base64_decode(config: &Config) -> TargetReport {
+    let mut input = SecureBuffer::new_owned(64).expect("buffer allocation failed");
+    measure(
+        "base64_decode",
+        config,
+        |class, rng| {
+            let mut raw = SecureBuffer::new_owned(48).expect("buffer allocation failed");
+            raw.as_mut_slice().copy_from_slice(&block::<48>(class, rng));
+            let encoded = entlib_native_base64::encode(&raw).expect("base64 encoding failed");
+            let mut ascii = [0u8; 64];
+            ascii.copy_from_slice(encoded.as_slice());
+            ascii
+        },
+        |ascii| {
+            input.as_mut_slice().copy_from_slice(ascii);
+            let _ = black_box(entlib_native_base64::decode(black_box(&input)));
+        },
+    )
+}
+
+/// HKDF-SHA256 확장 (`HKDFSHA256::expand`, 64바이트 OKM)
+///
+/// 고정 클래스는 0으로 채운 PRK, 무작위 클래스는 임의 PRK로 확장합니다.
+pub fn hkdf_sha256_expand(config: &Config) -> TargetReport {
+    let hkdf = HKDFSHA256::new();
+    let mut prk = Some(SecretArray::<32>::from_slice(&[0u8; 32]).expect("PRK allocation failed"));
+    measure("hkdf_sha256_expand", config, block::<32>, |bytes| {
+        // 동일한 보안 메모리를 재사용하기 위해 태그 부착/제거를 반복 (비용 없음)
+        let mut secret = prk.take().expect("PRK slot is always refilled");
+        secret.expose_secret_mut().copy_from_slice(bytes);
+        let key = SecretKey::<HKDFSHA256, 32>::from(secret);
+
+        let mut okm = [0u8; 64];
+        let _ = black_box(hkdf.expand(&key, &mut okm, b"entlib-dudect", 64));
+        black_box(okm);
+        prk = Some(key.into_secret());
+    })
+}
+
+/// Keccak-f\[1600\] 순열 (SHA3-256 rate 블록 하나 흡수)
+pub fn keccak_f1600(config: &Config) -> TargetReport {
+    let arena = target_arena();
+    let mut hasher = SHA3_256::new_in(&arena).expect("hasher allocation failed");
+    measure("keccak_f1600", config, block::<136>, |data| {
+        hasher.reset();
+        hasher.update(black_box(data));
+        black_box(&hasher);
+    })
+}
+
+/// SHA-256 압축 함수 (64바이트 블록 하나)
+pub fn sha256_compress(config: &Config) -> TargetReport {
+    let arena = target_arena();
+    let mut hasher = SHA256::new_in(&arena).expect("hasher allocation failed");
+    measure("sha256_compress", config, block::<64>, |data| {
+        hasher.reset();
+        hasher.update(black_box(data));
+        black_box(&hasher);
+    })
+}
+
+/// SHA-512 압축 함수 (128바이트 블록 하나)
+pub fn sha512_compress(config: &Config) -> TargetReport {
+    let arena = target_arena();
+    let mut hasher = SHA512::new_in(&arena).expect("hasher allocation failed");
+    measure("sha512_compress", config, block::<128>, |data| {
+        hasher.reset();
+        hasher.update(black_box(data));
+        black_box(&hasher);
+    })
+}
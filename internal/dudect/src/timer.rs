@@ -0,0 +1,27 @@
+//! 사이클 단위 타이머입니다.
+//!
+//! x86_64에서는 `lfence`로 직렬화한 `rdtsc`를, 그 외 아키텍처에서는 프로세스 시작 기준
+//! 단조 시계의 나노초 값을 사용합니다. t-검정은 두 분포의 상대적 차이만 보므로 단위는 무관합니다.
+
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+pub(crate) fn now() -> u64 {
+    use core::arch::x86_64::{_mm_lfence, _rdtsc};
+    // SAFETY: x86_64 기본 명령어 집합에 포함된 명령어로, 메모리에 접근하지 않습니다.
+    unsafe {
+        _mm_lfence();
+        let t = _rdtsc();
+        _mm_lfence();
+        t
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+#[inline(always)]
+pub(crate) fn now() -> u64 {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    EPOCH.get_or_init(Instant::now).elapsed().as_nanos() as u64
+}
@@ -0,0 +1,145 @@
+use entlib_native_dudect::stats::WelchTTest;
+use entlib_native_dudect::{Class, Config, Report, TargetReport, Verdict, measure, targets};
+
+#[test]
+fn welch_t_matches_closed_form() {
+    let mut test = WelchTTest::new();
+    for x in [1.0, 2.0, 3.0, 4.0] {
+        test.push(Class::Fixed, x);
+    }
+    for x in [2.0, 4.0, 6.0] {
+        test.push(Class::Random, x);
+    }
+
+    assert_eq!(test.count(Class::Fixed), 4);
+    assert_eq!(test.count(Class::Random), 3);
+    assert_eq!(test.mean(Class::Fixed), 2.5);
+    assert_eq!(test.mean(Class::Random), 4.0);
+    assert!((test.variance(Class::Fixed) - 5.0 / 3.0).abs() < 1e-12);
+    assert!((test.variance(Class::Random) - 4.0).abs() < 1e-12);
+
+    let expected = (2.5 - 4.0) / ((5.0 / 3.0) / 4.0 + 4.0 / 3.0f64).sqrt();
+    assert!((test.t() - expected).abs() < 1e-12);
+}
+
+#[test]
+fn welch_t_is_zero_without_enough_samples() {
+    let mut test = WelchTTest::new();
+    assert_eq!(test.t(), 0.0);
+    test.push(Class::Fixed, 1.0);
+    test.push(Class::Random, 9.0);
+    assert_eq!(test.t(), 0.0);
+}
+
+#[test]
+fn secret_dependent_loop_is_detected_and_aborted() {
+    let config = Config {
+        samples: 100_000,
+        batch_size: 2_000,
+        ..Config::default()
+    };
+    // 고정 클래스에서만 긴 루프를 도는 명백한 누출
+    let report = measure(
+        "leaky",
+        &config,
+        |class, _| match class {
+            Class::Fixed => 2_000u32,
+            Class::Random => 0,
+        },
+        |&n| {
+            for i in 0..n {
+                core::hint::black_box(i);
+            }
+        },
+    );
+
+    assert_eq!(report.verdict, Verdict::Leak);
+    assert!(report.max_t > config.threshold);
+    assert!(report.aborted);
+    assert!(report.fixed_samples + report.random_samples < config.samples as u64);
+}
+
+#[test]
+fn early_abort_can_be_disabled() {
+    let config = Config {
+        samples: 4_000,
+        batch_size: 1_000,
+        early_abort: false,
+        ..Config::default()
+    };
+    let report = measure(
+        "leaky",
+        &config,
+        |class, _| (class == Class::Fixed) as u32 * 2_000,
+        |&n| {
+            for i in 0..n {
+                core::hint::black_box(i);
+            }
+        },
+    );
+
+    assert_eq!(report.verdict, Verdict::Leak);
+    assert!(!report.aborted);
+    assert_eq!(report.fixed_samples + report.random_samples, 4_000);
+}
+
+#[test]
+fn builtin_targets_run() {
+    let config = Config {
+        samples: 200,
+        batch_size: 100,
+        early_abort: false,
+        ..Config::default()
+    };
+    for &(name, target) in targets::ALL {
+        let report = target(&config);
+        assert_eq!(report.name, name);
+        assert_eq!(report.fixed_samples + report.random_samples, 200);
+        assert_eq!(targets::find(name).map(|(n, _)| n), Some(name));
+    }
+    assert!(targets::find("unknown").is_none());
+}
+
+#[test]
+fn json_report_is_stable() {
+    let config = Config {
+        samples: 1_000,
+        seed: 7,
+        ..Config::default()
+    };
+    let mut report = Report::new(&config);
+    report.push(TargetReport {
+        name: "a\"b".into(),
+        verdict: Verdict::Pass,
+        max_t: 1.234,
+        crop: None,
+        fixed_samples: 501,
+        random_samples: 499,
+        aborted: false,
+    });
+    report.push(TargetReport {
+        name: "c".into(),
+        verdict: Verdict::Leak,
+        max_t: 12.0,
+        crop: Some(0.9),
+        fixed_samples: 10,
+        random_samples: 12,
+        aborted: true,
+    });
+
+    assert!(!report.is_pass());
+    assert_eq!(
+        report.to_json(),
+        r#"{
+  "threshold": 4.50,
+  "samples": 1000,
+  "seed": 7,
+  "pass": false,
+  "targets": [
+    {"name": "a\"b", "verdict": "pass", "max_t": 1.23, "crop": null, "fixed": 501, "random": 499, "aborted": false},
+    {"name": "c", "verdict": "leak", "max_t": 12.00, "crop": "p90", "fixed": 10, "random": 12, "aborted": true}
+  ]
+}
+"#
+    );
+}
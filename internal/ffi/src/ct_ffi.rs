@@ -0,0 +1,41 @@
+use crate::TYPE_ID;
+use entlib_native_constant_time::traits::ConstantTimeEq;
+use entlib_native_result::EntLibResult;
+
+/// 두 바이트 영역을 상수-시간으로 비교합니다.
+///
+/// Java 측의 MAC 태그 검증 등에서 사용하며, 길이(공개 정보)가 같으면 전체 바이트를
+/// 조기 종료 없이 스캔합니다. 비교 대상은 비밀 데이터의 사본이 아니므로 페이지 정렬을 요구하지 않습니다.
+///
+/// # Returns
+/// - 상태 코드 `0` - 비교 완료. `additional` 필드에 동일하면 `1`, 다르면 `0`을 기록합니다.
+/// - 상태 코드 `-1` - `Null` 포인터가 전달된 경우 (길이가 0인 경우는 허용)
+///
+/// # Safety
+/// - `a`와 `b`는 각각 `a_len`, `b_len` 바이트만큼 읽기 가능한 유효한 포인터여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn entlib_ffi_ct_eq(
+    a: *const u8,
+    a_len: usize,
+    b: *const u8,
+    b_len: usize,
+) -> EntLibResult {
+    if (a.is_null() && a_len != 0) || (b.is_null() && b_len != 0) {
+        return EntLibResult::new(TYPE_ID, -1);
+    }
+
+    let (a, b) = unsafe { (slice_or_empty(a, a_len), slice_or_empty(b, b_len)) };
+
+    // 0xFF(동일) 또는 0x00(상이) 마스크를 분기 없이 1 또는 0으로 변환
+    let equal = (a.ct_eq(b).unwrap_u8() & 1) as isize;
+    EntLibResult::new(TYPE_ID, 0).add_additional(equal)
+}
+
+/// 길이가 0인 영역은 `Null` 포인터일 수 있으므로 빈 슬라이스로 대체합니다.
+unsafe fn slice_or_empty<'a>(ptr: *const u8, len: usize) -> &'a [u8] {
+    if len == 0 {
+        &[]
+    } else {
+        unsafe { core::slice::from_raw_parts(ptr, len) }
+    }
+}
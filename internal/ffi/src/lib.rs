@@ -5,7 +5,9 @@ use std::mem::ManuallyDrop;
 pub(crate) const TYPE_ID: i8 = 1;
 
 mod base64_ffi;
+mod ct_ffi;
 mod hex_ffi;
+mod memory_ffi;
 mod sha_ffi;
 
 /// 얽힘 라이브러리 FFI 경계 통신 표준 구조체입니다.
@@ -0,0 +1,64 @@
+use crate::TYPE_ID;
+use entlib_native_result::EntLibResult;
+use entlib_native_secure_buffer::{LockPolicy, lock_policy, memory_stats, set_lock_policy};
+
+/// Java 측 메트릭 수집을 위한 보안 메모리 사용 현황 구조체입니다.
+///
+/// 플랫폼별 `usize` 크기 차이를 피하기 위해 모든 필드를 `u64`로 고정합니다.
+#[repr(C)]
+pub struct FFIMemoryStats {
+    /// 현재 잠겨 있는 메모리 크기 (바이트)
+    pub locked_bytes: u64,
+    /// 프로세스 시작 이후 동시에 잠긴 최대 메모리 크기 (바이트)
+    pub peak_locked_bytes: u64,
+    /// 현재 살아 있는 보안 버퍼 수
+    pub live_buffers: u64,
+    /// 잠금 없이 허용된 할당의 누적 횟수
+    pub unlocked_allocations: u64,
+}
+
+/// 프로세스 전역 메모리 잠금 정책을 설정합니다.
+///
+/// # Arguments
+/// - `policy` - `0` = FailHard, `1` = RaiseLimit, `2` = AllowUnlocked
+///
+/// # Returns
+/// 알 수 없는 정책 값이면 상태 코드 `-1`을 반환합니다.
+#[unsafe(no_mangle)]
+pub extern "C" fn entlib_ffi_memory_set_lock_policy(policy: u8) -> EntLibResult {
+    match LockPolicy::from_u8(policy) {
+        Some(policy) => {
+            set_lock_policy(policy);
+            EntLibResult::new(TYPE_ID, 0)
+        }
+        None => EntLibResult::new(TYPE_ID, -1),
+    }
+}
+
+/// 현재 프로세스 전역 메모리 잠금 정책 값을 반환합니다.
+#[unsafe(no_mangle)]
+pub extern "C" fn entlib_ffi_memory_lock_policy() -> u8 {
+    lock_policy() as u8
+}
+
+/// 프로세스 전역 보안 메모리 사용 현황을 `output`에 기록합니다.
+///
+/// # Safety
+/// - `output`은 쓰기 가능한 유효한 `FFIMemoryStats` 포인터여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn entlib_ffi_memory_stats(output: *mut FFIMemoryStats) -> EntLibResult {
+    if output.is_null() {
+        return EntLibResult::new(TYPE_ID, -1);
+    }
+
+    let stats = memory_stats();
+    unsafe {
+        output.write(FFIMemoryStats {
+            locked_bytes: stats.locked_bytes as u64,
+            peak_locked_bytes: stats.peak_locked_bytes as u64,
+            live_buffers: stats.live_buffers as u64,
+            unlocked_allocations: stats.unlocked_allocations as u64,
+        });
+    }
+    EntLibResult::new(TYPE_ID, 0)
+}
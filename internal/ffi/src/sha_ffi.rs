@@ -1,5 +1,5 @@
 use crate::FFIStandard;
-use entlib_native_constant_time::traits::{ConstantTimeEq, ConstantTimeSelect};
+use entlib_native_constant_time::traits::{ConstantTimeOrd, ConstantTimeSelect};
 use entlib_native_result::EntLibResult;
 use entlib_native_sha2::api::*;
 use entlib_native_sha3::api::*;
@@ -51,7 +51,7 @@ macro_rules! impl_ffi_hash_func {
                         // 상수-시간 FFI 패딩 복사
                         for i in 0..out_len {
                             // i < required_capacity 반별 (i >= required_capacity 의 NOT)
-                            let is_valid = i.ct_is_ge(&required_capacity).choice_not();
+                            let is_valid = i.ct_is_lt(&required_capacity);
 
                             // out-of-bounds 접근 방지를 위한 안전한 인덱스 선택
                             let safe_idx = usize::ct_select(&0, &i, is_valid);
@@ -123,7 +123,7 @@ macro_rules! impl_ffi_hash_bits_func {
                         // 상수-시간 FFI 패딩 복사
                         // 다이제스트 크기를 초과하는 버퍼 영역은 물리적으로 0x00 완전 소거
                         for i in 0..out_len {
-                            let is_valid = i.ct_is_ge(&required_capacity).choice_not();
+                            let is_valid = i.ct_is_lt(&required_capacity);
 
                             // Out-of-bounds 방지를 위해 인덱스를 상수-시간으로 0으로 폴백(Fallback)
                             let safe_idx = usize::ct_select(&0, &i, is_valid);
@@ -259,6 +259,8 @@ impl_ffi_hash_func!(ffi_sha2_224, SHA224, 28, TYPE_ID_SHA2);
 impl_ffi_hash_func!(ffi_sha2_256, SHA256, 32, TYPE_ID_SHA2);
 impl_ffi_hash_func!(ffi_sha2_384, SHA384, 48, TYPE_ID_SHA2);
 impl_ffi_hash_func!(ffi_sha2_512, SHA512, 64, TYPE_ID_SHA2);
+impl_ffi_hash_func!(ffi_sha2_512_224, SHA512_224, 28, TYPE_ID_SHA2);
+impl_ffi_hash_func!(ffi_sha2_512_256, SHA512_256, 32, TYPE_ID_SHA2);
 
 // SHA3 ffi 엔드포인트 생성
 impl_ffi_hash_func!(ffi_sha3_224, SHA3_224, 28, TYPE_ID_SHA3);
@@ -0,0 +1,8 @@
+//! 얽힘 라이브러리 크레이트들이 공유하는 테스트 지원 유틸리티입니다.
+//!
+//! 라이브러리 코드는 감사(audit) 기능 플래그가 활성화된 경우에만 본 크레이트에 의존해야 하며,
+//! 일반 빌드에는 포함되지 않습니다.
+
+#![no_std]
+
+pub mod taint;
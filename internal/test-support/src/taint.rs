@@ -0,0 +1,161 @@
+//! Valgrind Memcheck 기반 비밀 값 오염(Taint) 추적 모듈입니다.
+//!
+//! 비밀 입력을 [`classify_secret`]으로 "초기화되지 않은(undefined)" 메모리로 표시하면,
+//! Memcheck는 그 값에서 파생된 모든 값을 추적하여 다음 상황을 오류로 보고합니다.
+//!
+//! - 오염된 값에 의존하는 조건 분기 (`Conditional jump or move depends on uninitialised value(s)`)
+//! - 오염된 값을 주소 계산(메모리 인덱스)에 사용하는 접근
+//! - 오염된 값을 시스템 호출 인자(예: 할당 크기)로 전달
+//!
+//! 따라서 상수-시간 구현이라면 비밀 입력으로 전체 연산을 수행해도 오류가 없어야 합니다.
+//! 결과를 검증하기 전에는 [`declassify`]로 오염 표시를 해제해야 하며, 그렇지 않으면 테스트의
+//! `assert`가 오류로 보고됩니다.
+//!
+//! Valgrind 밖에서 실행하면 모든 함수는 아무 동작도 하지 않으므로, 같은 테스트를 일반
+//! `cargo test`로도 실행할 수 있습니다.
+//!
+//! # Examples
+//! ```
+//! use entlib_native_test_support::taint::{classify_secret, declassify};
+//!
+//! let key = [0x42u8; 32];
+//! classify_secret(&key);
+//! // ... 비밀 키를 사용하는 상수-시간 연산 ...
+//! declassify(&key);
+//! assert_eq!(key[0], 0x42);
+//! ```
+
+use core::hint::black_box;
+use core::sync::atomic::{Ordering, compiler_fence};
+
+/// Memcheck 클라이언트 요청 코드 (`valgrind/memcheck.h`)
+const VALGRIND_MAKE_MEM_UNDEFINED: usize = 0x4d43_0001;
+const VALGRIND_MAKE_MEM_DEFINED: usize = 0x4d43_0002;
+/// 코어 클라이언트 요청 코드 (`valgrind/valgrind.h`)
+const VALGRIND_RUNNING_ON_VALGRIND: usize = 0x1001;
+
+/// Valgrind 클라이언트 요청을 발행합니다.
+///
+/// Valgrind 밖에서는 특수 명령어 시퀀스가 아무 효과 없는 회전(rotate) 연산으로 실행되어
+/// 기본값 0이 반환됩니다.
+#[cfg(target_arch = "x86_64")]
+#[inline(always)]
+fn client_request(request: usize, arg1: usize, arg2: usize) -> usize {
+    let args: [usize; 6] = [request, arg1, arg2, 0, 0, 0];
+    let mut result: usize = 0;
+
+    black_box(args.as_ptr());
+    compiler_fence(Ordering::SeqCst);
+
+    // SAFETY: `rdi`를 총 128비트 회전시켜 원래 값으로 되돌린 뒤 `xchg rbx, rbx`를 실행하는
+    //         Valgrind 매직 시퀀스입니다. 네이티브 실행 시 레지스터 외의 상태를 변경하지 않습니다.
+    unsafe {
+        core::arch::asm!(
+        "rol rdi, 3",
+        "rol rdi, 13",
+        "rol rdi, 61",
+        "rol rdi, 51",
+        "xchg rbx, rbx",
+        in("rax") args.as_ptr(),
+        inout("rdx") result,
+        out("rdi") _,
+        options(nostack)
+        );
+    }
+
+    compiler_fence(Ordering::SeqCst);
+    result
+}
+
+#[cfg(target_arch = "aarch64")]
+#[inline(always)]
+fn client_request(request: usize, arg1: usize, arg2: usize) -> usize {
+    let args: [usize; 6] = [request, arg1, arg2, 0, 0, 0];
+    let mut result: usize = 0;
+
+    black_box(args.as_ptr());
+    compiler_fence(Ordering::SeqCst);
+
+    // SAFETY: `x12`를 총 128비트 회전시켜 원래 값으로 되돌린 뒤 `orr x10, x10, x10`을 실행하는
+    //         Valgrind 매직 시퀀스입니다. 네이티브 실행 시 레지스터 외의 상태를 변경하지 않습니다.
+    unsafe {
+        core::arch::asm!(
+        "ror x12, x12, #3",
+        "ror x12, x12, #13",
+        "ror x12, x12, #51",
+        "ror x12, x12, #61",
+        "orr x10, x10, x10",
+        in("x4") args.as_ptr(),
+        inout("x3") result,
+        out("x12") _,
+        options(nostack)
+        );
+    }
+
+    compiler_fence(Ordering::SeqCst);
+    result
+}
+
+#[cfg(not(any(target_arch = "x86_64", target_arch = "aarch64")))]
+#[inline(always)]
+fn client_request(_request: usize, _arg1: usize, _arg2: usize) -> usize {
+    0
+}
+
+/// 현재 프로세스가 Valgrind 위에서 실행 중이면 `true`를 반환합니다.
+#[inline(always)]
+pub fn is_running_on_valgrind() -> bool {
+    client_request(VALGRIND_RUNNING_ON_VALGRIND, 0, 0) > 0
+}
+
+#[inline(always)]
+fn mark(request: usize, ptr: *const u8, len: usize) {
+    if len != 0 && is_running_on_valgrind() {
+        client_request(request, ptr as usize, len);
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+/// `data`를 비밀 값으로 표시합니다. 이후 이 값에 의존하는 분기나 메모리 인덱스는 오류로 보고됩니다.
+#[inline(always)]
+pub fn classify_secret(data: &[u8]) {
+    mark(VALGRIND_MAKE_MEM_UNDEFINED, data.as_ptr(), data.len());
+}
+
+/// `data`의 비밀 표시를 해제합니다. 공개되어도 되는 결과를 검증하기 전에 호출합니다.
+#[inline(always)]
+pub fn declassify(data: &[u8]) {
+    mark(VALGRIND_MAKE_MEM_DEFINED, data.as_ptr(), data.len());
+}
+
+/// 임의 타입 값의 메모리 표현 전체를 비밀 값으로 표시합니다.
+#[inline(always)]
+pub fn classify_value<T: ?Sized>(value: &T) {
+    mark(
+        VALGRIND_MAKE_MEM_UNDEFINED,
+        (value as *const T).cast(),
+        core::mem::size_of_val(value),
+    );
+}
+
+/// 임의 타입 값의 메모리 표현 전체에 대한 비밀 표시를 해제합니다.
+#[inline(always)]
+pub fn declassify_value<T: ?Sized>(value: &T) {
+    mark(
+        VALGRIND_MAKE_MEM_DEFINED,
+        (value as *const T).cast(),
+        core::mem::size_of_val(value),
+    );
+}
+
+/// 레지스터에 있을 수 있는 값을 메모리를 경유해 공개(Declassify)한 뒤 반환합니다.
+///
+/// 라이브러리 코드에서 설계상 공개되는 값(예: 검증 결과, 패딩 길이)을 분기에 사용하기 직전에
+/// 호출하여, 의도된 공개 지점이 오류로 보고되지 않도록 합니다.
+#[inline(always)]
+pub fn declassified<T: Copy>(value: T) -> T {
+    let slot = black_box(value);
+    declassify_value(&slot);
+    // SAFETY: `slot`은 유효하게 초기화된 지역 변수입니다.
+    unsafe { core::ptr::read_volatile(&slot) }
+}
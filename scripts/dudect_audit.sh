@@ -0,0 +1,39 @@
+#!/bin/bash
+# 전체 암호 스택에 대한 dudect 통계 검정을 수행하고 JSON 보고서를 생성합니다.
+#
+# 사용법: scripts/dudect_audit.sh [기준 보고서] [dudect_suite 추가 인자...]
+#   - 보고서는 target/dudect/report.json에 기록됩니다.
+#   - 기준 보고서가 주어지면 대상별 판정(name, verdict)만 추출하여 비교합니다.
+#     t 값은 실행 환경마다 달라지므로 비교 대상에서 제외합니다.
+set -euo pipefail
+
+BASELINE="${1:-}"
+if [ -n "$BASELINE" ]; then shift; fi
+
+OUT_DIR=./target/dudect
+REPORT="$OUT_DIR/report.json"
+mkdir -p "$OUT_DIR"
+
+STATUS=0
+cargo bench -p entlib-native-dudect --bench dudect_suite -- --output "$REPORT" "$@" || STATUS=$?
+if [ "$STATUS" -ge 2 ]; then
+    exit "$STATUS"
+fi
+cat "$REPORT"
+
+verdicts() {
+    grep -o '"name": "[^"]*", "verdict": "[^"]*"' "$1"
+}
+
+if [ -n "$BASELINE" ]; then
+    if ! diff -u <(verdicts "$BASELINE") <(verdicts "$REPORT"); then
+        echo "FAIL: 기준 보고서($BASELINE)와 판정이 다릅니다."
+        exit 1
+    fi
+fi
+
+if [ "$STATUS" -ne 0 ]; then
+    echo "FAIL: |t| >= 임계값인 대상이 존재합니다."
+    exit 1
+fi
+echo "PASS: 모든 대상의 |t|가 임계값 미만입니다."
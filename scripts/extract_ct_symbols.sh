@@ -21,7 +21,7 @@ fi
 # 대상 크레이트 네임스페이스 및 핵심 보안 트레이트 메소드 패턴 정의
 # 명시된 모든 ConstantTime 연산 포함
 PATTERN_PREFIX="audit_verify_"
-SECURE_PATTERNS="${PATTERN_PREFIX}(choice_from_mask_normalized|choice_not|choice_unwrap_u8|u64_ct_eq|u64_ct_is_ge|u64_ct_is_negative|u64_ct_is_zero|u64_ct_ne|u64_ct_select|u64_ct_swap)"
+SECURE_PATTERNS="${PATTERN_PREFIX}(choice_from_mask_normalized|choice_not|choice_unwrap_u8|u64_ct_eq|u64_ct_is_ge|u64_ct_is_gt|i64_ct_is_lt|u64_ct_min|i64_ct_max|u32_ct_div_rem_by_mldsa_gamma2|u64_ct_is_negative|u64_ct_is_zero|u64_ct_ne|u64_ct_select|u64_ct_swap|bytes32_ct_eq|bytes32_ct_select|bytes_ct_cmp|table16_ct_lookup|bytes16_ct_copy_if|bytes16_ct_memzero_if)"
 
 # 심볼 필터링 및 배열 저장
 # - 첫 번째 안내 문구 제거 및 공백으로 분리된 항목을 줄 단위로 변환
@@ -0,0 +1,31 @@
+#!/bin/bash
+# 비밀 입력 오염 추적 감사를 모든 대상 크레이트에 대해 Valgrind Memcheck로 실행합니다.
+#
+# 각 크레이트의 tests/taint_audit.rs는 valgrind_taint_audit 피처로 활성화되며,
+# 비밀 값에 의존하는 분기 또는 메모리 인덱스가 있으면 Memcheck 오류로 실패합니다.
+# cargo-valgrind 설치 필요: cargo install cargo-valgrind --locked
+set -euo pipefail
+
+CRATES=(
+    entlib-native-constant-time
+    entlib-native-hex
+    entlib-native-base64
+    entlib-native-sha2
+    entlib-native-sha3
+    entlib-native-hmac
+    entlib-native-hkdf
+)
+
+FAIL=0
+for crate in "${CRATES[@]}"; do
+    echo "[검증] $crate"
+    if ! cargo valgrind test -p "$crate" --test taint_audit --features valgrind_taint_audit -- --nocapture; then
+        echo "FAIL: $crate 에서 비밀 의존 분기 또는 메모리 접근이 탐지되었습니다."
+        FAIL=1
+    fi
+done
+
+if [ "$FAIL" -ne 0 ]; then
+    exit 1
+fi
+echo "PASS: 모든 크레이트의 오염 추적 감사를 통과했습니다."
@@ -16,6 +16,9 @@ EXIT_CODE=0
 TYPES=("u32" "u64")
 METHODS=("ct_nonzero" "ct_zero" "ct_negative" "ct_equal" "ct_not_equal" "ct_mux")
 
+# audit_mode 래퍼 심볼 (고정 크기로 단형화되어 루프가 완전히 전개되는 함수만 등록)
+AUDIT_SYMBOLS=("audit_verify_table16_ct_lookup" "audit_verify_bytes16_ct_copy_if" "audit_verify_bytes16_ct_memzero_if")
+
 # 아키텍처별 금지된 조건부 분기 명령어 (정규표현식)
 if [[ "$TARGET" == *"x86_64"* ]]; then
     FORBIDDEN_BRANCHES="\b(je|jz|jne|jnz|jg|jge|jl|jle|ja|jae|jb|jbe)\b"
@@ -47,6 +50,23 @@ for TYPE in "${TYPES[@]}"; do
     done
 done
 
+for SYMBOL in "${AUDIT_SYMBOLS[@]}"; do
+    echo "검사 중: $SYMBOL"
+
+    # 래퍼 심볼은 반드시 존재해야 하므로 추출 실패 자체를 검증 실패로 취급
+    if ! ASM_OUTPUT=$(cargo asm -p entlib-native-constant-time --lib --features audit_mode --release --target "$TARGET" --simplify "$SYMBOL" 2>/dev/null); then
+        echo "[오류] 심볼을 추출할 수 없습니다: $SYMBOL"
+        EXIT_CODE=1
+        continue
+    fi
+
+    if echo "$ASM_OUTPUT" | grep -qE "$FORBIDDEN_BRANCHES"; then
+        echo "[오류] 분기 명령어(timing leak)가 감지되었습니다: $SYMBOL"
+        echo "$ASM_OUTPUT" | grep -E -C 2 "$FORBIDDEN_BRANCHES"
+        EXIT_CODE=1
+    fi
+done
+
 if [ $EXIT_CODE -eq 0 ]; then
     echo "[$TARGET] 어셈블리 검증 통과: 분기문이 존재하지 않습니다."
 else